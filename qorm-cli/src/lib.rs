@@ -0,0 +1,16 @@
+//! qorm command line tool, runs migrations, dumps schema and renders query descriptions.
+//! commands work against sqlite database file.
+//!
+//! ```sh
+//! qorm --database app.db migrate new create_todo
+//! qorm --database app.db migrate up --dry-run
+//! qorm --database app.db migrate status
+//! qorm --database app.db schema dump
+//! qorm render query.toml --dialect postgres
+//! ```
+pub mod migrate;
+pub mod render;
+pub mod schema;
+
+/// command result, errors are printed by the binary
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
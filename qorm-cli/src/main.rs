@@ -0,0 +1,122 @@
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::{Parser, Subcommand};
+use qorm::{sqlite::rusqlite::Connection, Dialect};
+use qorm_cli::{migrate, render, schema, Result};
+
+#[derive(Parser)]
+#[command(
+    name = "qorm",
+    version,
+    about = "qorm migrations, schema dump and query rendering"
+)]
+struct Cli {
+    /// sqlite database file
+    #[arg(long, global = true, env = "QORM_DATABASE", default_value = "qorm.db")]
+    database: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// run versioned migrations
+    Migrate {
+        /// migration directory
+        #[arg(long, default_value = "migrations")]
+        dir: PathBuf,
+        #[command(subcommand)]
+        command: MigrateCommand,
+    },
+    /// inspect database schema
+    Schema {
+        #[command(subcommand)]
+        command: SchemaCommand,
+    },
+    /// print sql and binds of json or toml query description
+    Render {
+        /// query description, `.toml` files are read as toml and json otherwise
+        file: PathBuf,
+        /// mysql, postgres or sqlite
+        #[arg(long, default_value = "sqlite")]
+        dialect: Dialect,
+        /// format sql on multiple lines
+        #[arg(long)]
+        pretty: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum MigrateCommand {
+    /// apply pending migrations
+    Up {
+        /// print statements without running them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// roll back applied migrations
+    Down {
+        /// number of migrations to roll back
+        #[arg(long, default_value_t = 1)]
+        steps: usize,
+        /// print statements without running them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// show applied and pending migrations
+    Status,
+    /// create up and down files of next migration
+    New { name: String },
+}
+
+#[derive(Subcommand)]
+enum SchemaCommand {
+    /// print create statements of database
+    Dump,
+}
+
+fn run(cli: Cli) -> Result<String> {
+    match cli.command {
+        Command::Migrate { dir, command } => match command {
+            MigrateCommand::Up { dry_run } => {
+                migrate::up(&mut Connection::open(&cli.database)?, &dir, dry_run)
+            }
+            MigrateCommand::Down { steps, dry_run } => {
+                migrate::down(&mut Connection::open(&cli.database)?, &dir, steps, dry_run)
+            }
+            MigrateCommand::Status => migrate::status(&mut Connection::open(&cli.database)?, &dir),
+            // creates files only, database is not opened
+            MigrateCommand::New { name } => Ok(migrate::new(&dir, &name)?
+                .iter()
+                .map(|f| format!("created {}", f.display()))
+                .collect::<Vec<String>>()
+                .join("\n")),
+        },
+        Command::Schema {
+            command: SchemaCommand::Dump,
+        } => schema::dump(&mut Connection::open(&cli.database)?),
+        Command::Render {
+            file,
+            dialect,
+            pretty,
+        } => {
+            let toml = file.extension().is_some_and(|f| f == "toml");
+            render::render(&fs::read_to_string(&file)?, toml, dialect, pretty)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(output) => {
+            if !output.is_empty() {
+                println!("{}", output);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
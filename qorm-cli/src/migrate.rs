@@ -0,0 +1,137 @@
+use std::{fs, path::Path, path::PathBuf};
+
+use qorm::{
+    migrate::Migrator, migrate_item::Migration, sqlite::rusqlite::Connection, Dialect, Error,
+};
+
+use crate::Result;
+
+/// load migrations from directory, `<version>_<name>.up.sql` (or `<version>_<name>.sql`)
+/// with optional `<version>_<name>.down.sql`
+pub fn load(dir: &Path) -> Result<Migrator> {
+    let mut migrator = Migrator::new(Dialect::Sqlite);
+    let mut entries = fs::read_dir(dir)
+        .map_err(|err| {
+            format!(
+                "can not read migration directory {}: {}",
+                dir.display(),
+                err
+            )
+        })?
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|f| f.file_name());
+    for entry in entries {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if !file_name.ends_with(".sql") || file_name.ends_with(".down.sql") {
+            continue;
+        }
+        let mut migration = Migration::from_file(&file_name, &fs::read_to_string(entry.path())?)?;
+        let stem = file_name
+            .trim_end_matches(".sql")
+            .trim_end_matches(".up")
+            .to_string();
+        let down = dir.join(format!("{}.down.sql", stem));
+        if down.exists() {
+            migration.down_sql(&fs::read_to_string(down)?);
+        }
+        migrator.migration(&migration);
+    }
+    migrator.validate()?;
+    Ok(migrator)
+}
+
+/// apply pending migrations, or print them on dry run
+pub fn up(conn: &mut Connection, dir: &Path, dry_run: bool) -> Result<String> {
+    let migrator = load(dir)?;
+    if dry_run {
+        return Ok(migrator.dry_run(conn)?);
+    }
+    let versions = migrator.up(conn)?;
+    Ok(summary("applied", &migrator, &versions))
+}
+
+/// roll back last `steps` migrations, or print them on dry run
+pub fn down(conn: &mut Connection, dir: &Path, steps: usize, dry_run: bool) -> Result<String> {
+    let migrator = load(dir)?;
+    if dry_run {
+        return Ok(migrator.dry_run_down(conn, steps)?);
+    }
+    let versions = migrator.down(conn, steps)?;
+    Ok(summary("rolled back", &migrator, &versions))
+}
+
+fn summary(action: &str, migrator: &Migrator, versions: &[i64]) -> String {
+    if versions.is_empty() {
+        return "nothing to do".to_string();
+    }
+    versions
+        .iter()
+        .map(|version| {
+            let migration = migrator
+                .migrations()
+                .iter()
+                .find(|f| f.version == *version)
+                .unwrap();
+            format!("{} {} {}", action, migration.version, migration.name)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// state of every migration: `applied`, `pending`, `changed` (edited after it was applied)
+/// or `missing` (applied but its file is gone)
+pub fn status(conn: &mut Connection, dir: &Path) -> Result<String> {
+    let migrator = load(dir)?;
+    let applied = migrator.applied(conn)?;
+    let mut lines = vec![];
+    for migration in migrator.migrations() {
+        let checksum = migration.checksum(Dialect::Sqlite)?;
+        let state = match applied.iter().find(|f| f.version == migration.version) {
+            Some(item) if item.checksum != checksum => "changed",
+            Some(_) => "applied",
+            None => "pending",
+        };
+        lines.push(format!(
+            "{} {} {}",
+            migration.version, migration.name, state
+        ));
+    }
+    for item in applied.iter() {
+        if !migrator
+            .migrations()
+            .iter()
+            .any(|f| f.version == item.version)
+        {
+            lines.push(format!("{} {} missing", item.version, item.name));
+        }
+    }
+    lines.sort_by_key(|f| f.split(' ').next().unwrap().parse::<i64>().unwrap());
+    Ok(lines.join("\n"))
+}
+
+/// create empty up and down files for next version, returns created paths
+pub fn new(dir: &Path, name: &str) -> Result<Vec<PathBuf>> {
+    if name.is_empty() || !name.chars().all(|f| f.is_ascii_alphanumeric() || f == '_') {
+        return Err(Error::Invalid(format!(
+            "migration name {} must only contain letters, digits and _",
+            name
+        ))
+        .into());
+    }
+    fs::create_dir_all(dir)?;
+    let version = load(dir)?
+        .migrations()
+        .last()
+        .map(|f| f.version + 1)
+        .unwrap_or(1);
+    let mut paths = vec![];
+    for (suffix, content) in [
+        ("up", format!("-- {} up\n", name)),
+        ("down", format!("-- {} down\n", name)),
+    ] {
+        let path = dir.join(format!("{:04}_{}.{}.sql", version, name, suffix));
+        fs::write(&path, content)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
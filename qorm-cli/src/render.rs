@@ -0,0 +1,246 @@
+use qorm::{
+    format::format_sql, where_item::Or, Bind, Delete, Dialect, Error, Insert, Select, Update,
+};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::Result;
+
+/// query description read from json or toml, `type` is `select`, `insert`, `update` or `delete`
+/// ```toml
+/// type = "select"
+/// table = "user"
+/// alias = "u"
+/// columns = ["u.id", "u.name"]
+/// order_by = ["u.id DESC"]
+/// limit = 10
+///
+/// [[joins]]
+/// type = "LEFT"
+/// table = "role r"
+/// on = "r.id = u.role_id"
+///
+/// [[where]]
+/// column = "u.name"
+/// operator = "LIKE"
+/// value = "foo%"
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum QuerySpec {
+    Select(SelectSpec),
+    Insert(InsertSpec),
+    Update(UpdateSpec),
+    Delete(DeleteSpec),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SelectSpec {
+    pub table: String,
+    pub alias: Option<String>,
+    #[serde(default)]
+    pub distinct: bool,
+    #[serde(default)]
+    pub columns: Vec<String>,
+    #[serde(default)]
+    pub joins: Vec<JoinSpec>,
+    #[serde(default, rename = "where")]
+    pub wheres: Vec<ConditionSpec>,
+    #[serde(default)]
+    pub where_or: Vec<Vec<ConditionSpec>>,
+    #[serde(default)]
+    pub group_by: Vec<String>,
+    #[serde(default)]
+    pub order_by: Vec<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JoinSpec {
+    #[serde(rename = "type")]
+    pub join_type: Option<String>,
+    pub table: String,
+    pub on: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConditionSpec {
+    pub column: String,
+    pub operator: String,
+    #[serde(default)]
+    pub value: Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InsertSpec {
+    pub table: String,
+    pub values: Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateSpec {
+    pub table: String,
+    pub set: Map<String, Value>,
+    #[serde(default, rename = "where")]
+    pub wheres: Vec<ConditionSpec>,
+    #[serde(default)]
+    pub where_or: Vec<Vec<ConditionSpec>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeleteSpec {
+    pub table: String,
+    #[serde(default, rename = "where")]
+    pub wheres: Vec<ConditionSpec>,
+    #[serde(default)]
+    pub where_or: Vec<Vec<ConditionSpec>>,
+}
+
+/// json/toml value as bind, integers out of `i32` range are `BigInt`
+fn to_bind(value: &Value) -> Result<Bind> {
+    Ok(match value {
+        Value::Null => Bind::Null,
+        Value::Bool(value) => Bind::Bool(*value),
+        Value::Number(number) => match (number.as_i64(), number.as_f64()) {
+            (Some(value), _) => match i32::try_from(value) {
+                Ok(value) => Bind::Int(value),
+                Err(_) => Bind::BigInt(value),
+            },
+            (None, Some(value)) => Bind::Float(value),
+            _ => return Err(Error::Invalid(format!("unsupported number {}", number)).into()),
+        },
+        Value::String(value) => Bind::String(value.clone()),
+        _ => return Err(Error::Invalid(format!("unsupported bind value {}", value)).into()),
+    })
+}
+
+fn to_pairs(values: &Map<String, Value>) -> Result<Vec<(&str, Bind)>> {
+    values
+        .iter()
+        .map(|(column, value)| Ok((column.as_str(), to_bind(value)?)))
+        .collect()
+}
+
+fn to_or(conditions: &[ConditionSpec]) -> Result<Vec<Or<'_>>> {
+    conditions
+        .iter()
+        .map(|f| {
+            Ok(Or {
+                column: &f.column,
+                operator: &f.operator,
+                value: to_bind(&f.value)?,
+            })
+        })
+        .collect()
+}
+
+impl QuerySpec {
+    /// parse description, `toml` selects toml syntax and json otherwise
+    pub fn parse(source: &str, toml: bool) -> Result<Self> {
+        Ok(match toml {
+            true => toml::from_str(source)?,
+            false => serde_json::from_str(source)?,
+        })
+    }
+
+    /// generated sql query and its binds on dialect
+    pub fn build(&self, dialect: Dialect) -> Result<(String, Vec<Bind>)> {
+        Ok(match self {
+            QuerySpec::Select(spec) => {
+                let mut builder = Select::new(&spec.table, spec.alias.as_deref(), None);
+                builder.dialect(dialect);
+                if spec.distinct {
+                    builder.distinct();
+                }
+                for column in spec.columns.iter() {
+                    builder.select(column);
+                }
+                for join in spec.joins.iter() {
+                    builder.join(join.join_type.as_deref(), &join.table, &join.on);
+                }
+                for condition in spec.wheres.iter() {
+                    builder.wheres(
+                        &condition.column,
+                        &condition.operator,
+                        to_bind(&condition.value)?,
+                    );
+                }
+                for conditions in spec.where_or.iter() {
+                    builder.where_or(to_or(conditions)?);
+                }
+                if !spec.order_by.is_empty() {
+                    builder.order_by(spec.order_by.iter().map(|f| f.as_str()).collect());
+                }
+                if !spec.group_by.is_empty() {
+                    builder.group_by(spec.group_by.iter().map(|f| f.as_str()).collect());
+                }
+                if let Some(limit) = spec.limit {
+                    builder.limit(limit);
+                }
+                if let Some(offset) = spec.offset {
+                    builder.offset(offset);
+                }
+                builder.validate()?;
+                builder.to_sql_with_bind()
+            }
+            QuerySpec::Insert(spec) => {
+                let mut builder = Insert::new(&spec.table, None);
+                builder.dialect(dialect).values(to_pairs(&spec.values)?);
+                builder.to_sql_with_bind()
+            }
+            QuerySpec::Update(spec) => {
+                let mut builder = Update::new(&spec.table, None);
+                builder.dialect(dialect).set(to_pairs(&spec.set)?);
+                for condition in spec.wheres.iter() {
+                    builder.wheres(
+                        &condition.column,
+                        &condition.operator,
+                        to_bind(&condition.value)?,
+                    );
+                }
+                for conditions in spec.where_or.iter() {
+                    builder.where_or(to_or(conditions)?);
+                }
+                builder.to_sql_with_bind()
+            }
+            QuerySpec::Delete(spec) => {
+                let mut builder = Delete::new(&spec.table, None);
+                builder.dialect(dialect);
+                for condition in spec.wheres.iter() {
+                    builder.wheres(
+                        &condition.column,
+                        &condition.operator,
+                        to_bind(&condition.value)?,
+                    );
+                }
+                for conditions in spec.where_or.iter() {
+                    builder.where_or(to_or(conditions)?);
+                }
+                builder.to_sql_with_bind()
+            }
+        })
+    }
+}
+
+/// render description as sql followed by one `-- bind` comment per bind
+pub fn render(source: &str, toml: bool, dialect: Dialect, pretty: bool) -> Result<String> {
+    let (sql, binds) = QuerySpec::parse(source, toml)?.build(dialect)?;
+    let mut result = match pretty {
+        true => format_sql(&sql, None),
+        false => sql,
+    };
+    result.push(';');
+    for (idx, bind) in binds.iter().enumerate() {
+        result.push_str(
+            format!("\n-- bind {}: {}", idx + 1, bind.to_literal(Some(dialect))).as_str(),
+        );
+    }
+    Ok(result)
+}
@@ -0,0 +1,22 @@
+use qorm::{migrate::MIGRATION_TABLE, sqlite::rusqlite::Connection, Bind, Executor, FromRow};
+
+use crate::Result;
+
+#[derive(FromRow)]
+struct SchemaRow {
+    sql: String,
+}
+
+/// `CREATE` statements of every table, index, view and trigger, migration table is excluded
+pub fn dump(conn: &mut Connection) -> Result<String> {
+    let rows: Vec<SchemaRow> = conn.fetch_all(&mut (
+        "SELECT sql FROM sqlite_master WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%' AND tbl_name != ? ORDER BY CASE type WHEN 'table' THEN 0 ELSE 1 END, name"
+            .to_string(),
+        vec![Bind::String(MIGRATION_TABLE.to_string())],
+    ))?;
+    Ok(rows
+        .iter()
+        .map(|f| format!("{};", f.sql))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
@@ -0,0 +1,107 @@
+use syn::{Attribute, LitInt, LitStr};
+
+/// struct level `#[qorm(...)]` attributes
+#[derive(Default)]
+pub struct ContainerAttr {
+    pub table: Option<String>,
+    pub skip_none: bool,
+    pub schema: bool,
+}
+
+/// field level `#[qorm(...)]` attributes
+#[derive(Default)]
+pub struct FieldAttr {
+    pub column: Option<String>,
+    pub skip: bool,
+    pub skip_none: bool,
+    pub primary_key: bool,
+    pub generated: bool,
+    pub index: Option<usize>,
+    pub unique: bool,
+    pub indexed: bool,
+    pub column_type: Option<String>,
+    pub default: Option<String>,
+}
+
+impl ContainerAttr {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|f| f.path().is_ident("qorm")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("table") {
+                    result.table = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("skip_none") {
+                    result.skip_none = true;
+                    Ok(())
+                } else if meta.path.is_ident("schema") {
+                    result.schema = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported qorm attribute"))
+                }
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+impl FieldAttr {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|f| f.path().is_ident("qorm")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("column") {
+                    result.column = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    result.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip_none") {
+                    result.skip_none = true;
+                    Ok(())
+                } else if meta.path.is_ident("primary_key") {
+                    result.primary_key = true;
+                    Ok(())
+                } else if meta.path.is_ident("generated") {
+                    result.generated = true;
+                    Ok(())
+                } else if meta.path.is_ident("index") {
+                    result.index = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("unique") {
+                    result.unique = true;
+                    Ok(())
+                } else if meta.path.is_ident("indexed") {
+                    result.indexed = true;
+                    Ok(())
+                } else if meta.path.is_ident("column_type") {
+                    result.column_type = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    result.default = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported qorm attribute"))
+                }
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+/// `UserProfile` -> `user_profile`
+pub fn to_snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (idx, char) in name.chars().enumerate() {
+        if char.is_uppercase() {
+            if idx != 0 {
+                result.push('_');
+            }
+            result.extend(char.to_lowercase());
+        } else {
+            result.push(char);
+        }
+    }
+    result
+}
@@ -0,0 +1,46 @@
+//! derive macros for qorm, enabled with qorm `derive` feature.
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attr;
+mod model;
+mod row;
+mod table;
+
+/// derive table descriptor with typed column constants, column definitions are generated with `#[qorm(schema)]`
+///
+/// see `qorm::table::TableSchema`
+#[proc_macro_derive(Table, attributes(qorm))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    table::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// derive `qorm::insert_item::Insertable`, fields marked `#[qorm(generated)]` are omitted
+#[proc_macro_derive(Insertable, attributes(qorm))]
+pub fn derive_insertable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    model::expand_insertable(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// derive `qorm::update_item::Changeset`, fields marked `#[qorm(primary_key)]` are returned by `primary_key`
+#[proc_macro_derive(Changeset, attributes(qorm))]
+pub fn derive_changeset(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    model::expand_changeset(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// derive `qorm::row::FromRow`, fields are decoded by column name or `#[qorm(index = n)]`
+#[proc_macro_derive(FromRow, attributes(qorm))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    row::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
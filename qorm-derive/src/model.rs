@@ -0,0 +1,79 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::{
+    attr::ContainerAttr,
+    table::{columns, is_option, ColumnField},
+};
+
+/// push `(column, Bind)` of field into `values`
+fn push_value(field: &ColumnField, skip_none: bool) -> TokenStream {
+    let ident = &field.ident;
+    let column = &field.column;
+    if is_option(&field.ty) && (skip_none || field.attr.skip_none) {
+        quote! {
+            if let ::std::option::Option::Some(value) = &self.#ident {
+                values.push((#column, ::qorm::Bind::from(::std::clone::Clone::clone(value))));
+            }
+        }
+    } else {
+        quote! {
+            values.push((#column, ::qorm::Bind::from(::std::clone::Clone::clone(&self.#ident))));
+        }
+    }
+}
+
+pub fn expand_insertable(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let container = ContainerAttr::parse(&input.attrs)?;
+    let values = columns(&input)?
+        .iter()
+        .filter(|f| !f.attr.generated)
+        .map(|f| push_value(f, container.skip_none))
+        .collect::<Vec<TokenStream>>();
+
+    Ok(quote! {
+        impl #impl_generics ::qorm::insert_item::Insertable for #name #ty_generics #where_clause {
+            fn insert_values(&self) -> ::std::vec::Vec<(&'static str, ::qorm::Bind)> {
+                let mut values = ::std::vec::Vec::new();
+                #(#values)*
+                values
+            }
+        }
+    })
+}
+
+pub fn expand_changeset(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let container = ContainerAttr::parse(&input.attrs)?;
+    let columns = columns(&input)?;
+    let values = columns
+        .iter()
+        .filter(|f| !f.attr.primary_key && !f.attr.generated)
+        .map(|f| push_value(f, container.skip_none))
+        .collect::<Vec<TokenStream>>();
+    let keys = columns
+        .iter()
+        .filter(|f| f.attr.primary_key)
+        .map(|f| push_value(f, false))
+        .collect::<Vec<TokenStream>>();
+
+    Ok(quote! {
+        impl #impl_generics ::qorm::update_item::Changeset for #name #ty_generics #where_clause {
+            fn changeset(&self) -> ::std::vec::Vec<(&'static str, ::qorm::Bind)> {
+                let mut values = ::std::vec::Vec::new();
+                #(#values)*
+                values
+            }
+
+            fn primary_key(&self) -> ::std::vec::Vec<(&'static str, ::qorm::Bind)> {
+                let mut values = ::std::vec::Vec::new();
+                #(#keys)*
+                values
+            }
+        }
+    })
+}
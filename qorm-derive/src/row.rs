@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::table::fields;
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = fields(&input)?;
+
+    let columns = fields
+        .iter()
+        .filter(|f| !f.attr.skip)
+        .map(|f| f.column.clone())
+        .collect::<Vec<String>>();
+    let values = fields.iter().map(|f| {
+        let ident = &f.ident;
+        let column = &f.column;
+        if f.attr.skip {
+            quote! { #ident: ::std::default::Default::default() }
+        } else if let Some(index) = f.attr.index {
+            quote! { #ident: ::qorm::row::Row::get_index(row, #index)? }
+        } else {
+            quote! { #ident: ::qorm::row::Row::get(row, #column)? }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::qorm::row::FromRow for #name #ty_generics #where_clause {
+            const COLUMNS: &'static [&'static str] = &[#(#columns),*];
+
+            fn from_row<R: ::qorm::row::Row>(row: &R) -> ::std::result::Result<Self, ::qorm::Error> {
+                ::std::result::Result::Ok(Self {
+                    #(#values),*
+                })
+            }
+        }
+    })
+}
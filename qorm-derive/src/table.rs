@@ -0,0 +1,177 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Type};
+
+use crate::attr::{to_snake_case, ContainerAttr, FieldAttr};
+
+/// column of derived struct
+pub struct ColumnField {
+    pub ident: syn::Ident,
+    pub ty: syn::Type,
+    pub column: String,
+    pub attr: FieldAttr,
+}
+
+/// named fields of struct, fields marked `#[qorm(skip)]` are excluded
+pub fn columns(input: &DeriveInput) -> syn::Result<Vec<ColumnField>> {
+    Ok(fields(input)?
+        .into_iter()
+        .filter(|f| !f.attr.skip)
+        .collect())
+}
+
+/// every named field of struct
+pub fn fields(input: &DeriveInput) -> syn::Result<Vec<ColumnField>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "qorm derive requires struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "qorm derive requires struct with named fields",
+            ))
+        }
+    };
+    let mut result = vec![];
+    for field in fields {
+        let attr = FieldAttr::parse(&field.attrs)?;
+        let ident = field.ident.clone().unwrap();
+        let column = attr
+            .column
+            .clone()
+            .unwrap_or(ident.to_string().trim_start_matches("r#").to_string());
+        result.push(ColumnField {
+            ident,
+            ty: field.ty.clone(),
+            column,
+            attr,
+        });
+    }
+    Ok(result)
+}
+
+/// `Option<T>` field type
+pub fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|f| f.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// table name of derived struct, default is snake case of struct name
+pub fn table_name(input: &DeriveInput) -> syn::Result<String> {
+    let attr = ContainerAttr::parse(&input.attrs)?;
+    Ok(attr
+        .table
+        .unwrap_or(to_snake_case(input.ident.to_string().as_str())))
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let table = table_name(&input)?;
+    let schema = ContainerAttr::parse(&input.attrs)?.schema;
+    let columns = columns(&input)?;
+
+    let consts = columns.iter().map(|f| {
+        let const_name = format_ident!(
+            "{}",
+            f.ident
+                .to_string()
+                .trim_start_matches("r#")
+                .to_uppercase()
+        );
+        let column = &f.column;
+        let doc = format!("`{}.{}` column", table, column);
+        (
+            quote! {
+                #[doc = #doc]
+                pub const #const_name: ::qorm::table::Column = ::qorm::table::Column::new(#table, #column);
+            },
+            const_name,
+        )
+    });
+    let const_defs = consts.clone().map(|(def, _)| def);
+    let const_names = consts.map(|(_, name)| name);
+    // Field types need `SqlType` only when column definitions are asked for
+    let column_defs = match schema {
+        true => {
+            let column_defs = columns.iter().map(column_def);
+            quote! {
+                fn column_defs() -> ::std::vec::Vec<::qorm::create_table_item::ColumnDef> {
+                    ::std::vec![#(#column_defs),*]
+                }
+            }
+        }
+        false => quote! {},
+    };
+    let indexes = columns.iter().filter(|f| f.attr.indexed).map(|f| {
+        let index_name = format!("{}_{}_idx", table, f.column);
+        let column = &f.column;
+        quote! {
+            ::qorm::CreateIndex::new(#index_name, #table).column(#column).clone()
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#const_defs)*
+        }
+
+        impl #impl_generics ::qorm::table::TableSchema for #name #ty_generics #where_clause {
+            const TABLE: &'static str = #table;
+            const COLUMNS: &'static [::qorm::table::Column] = &[#(Self::#const_names),*];
+
+            #column_defs
+
+            fn indexes() -> ::std::vec::Vec<::qorm::CreateIndex> {
+                ::std::vec![#(#indexes),*]
+            }
+        }
+    })
+}
+
+/// column definition of field, type is read from `qorm::table::SqlType` unless `#[qorm(column_type = "...")]` is set.
+/// `Option` fields are nullable, primary key with `#[qorm(generated)]` is auto increment
+fn column_def(field: &ColumnField) -> TokenStream {
+    let column = &field.column;
+    let ty = &field.ty;
+    let column_type = match &field.attr.column_type {
+        Some(raw) => quote! { ::qorm::create_table_item::ColumnType::Custom(#raw.to_string()) },
+        None => quote! { <#ty as ::qorm::table::SqlType>::column_type() },
+    };
+    let mut modifiers = vec![];
+    if field.attr.primary_key {
+        modifiers.push(quote! { column.primary_key(); });
+        if field.attr.generated {
+            modifiers.push(quote! { column.auto_increment(); });
+        }
+    } else if !is_option(ty) {
+        modifiers.push(quote! { column.not_null(); });
+    }
+    if field.attr.unique {
+        modifiers.push(quote! { column.unique(); });
+    }
+    if let Some(raw) = &field.attr.default {
+        modifiers.push(quote! { column.default_raw(#raw); });
+    }
+    quote! {
+        {
+            let mut column = ::qorm::create_table_item::ColumnDef::new(#column, #column_type);
+            #(#modifiers)*
+            column
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{
+    alter_table_item::{AlterOperation, Constraint},
+    create_table_item::{ColumnDef, ColumnType},
+    dialect::{quote_identifier, Dialect},
+    error::Error,
+    expr::Expr,
+    Bind,
+};
+
+/// sql alter table builder
+#[derive(Clone, Debug)]
+pub struct AlterTable {
+    pub table_name: String,
+    dialect: Option<Dialect>,
+    pub(crate) operations: Vec<AlterOperation>,
+}
+
+impl AlterTable {
+    /// Initialize AlterTable builder, operations are rendered comma separated
+    /// ```rust
+    /// use qorm::{create_table_item::{ColumnDef, ColumnType}, AlterTable, Bind, Dialect};
+    ///
+    /// let mut builder = AlterTable::new("todo");
+    /// builder
+    ///     .dialect(Dialect::Postgres)
+    ///     .add_column(ColumnDef::new("note", ColumnType::Text).default(Bind::from("")))
+    ///     .rename_column("name", "title")
+    ///     .set_not_null("title");
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     r#"ALTER TABLE "todo" ADD COLUMN "note" TEXT DEFAULT '', RENAME COLUMN "name" TO "title", ALTER COLUMN "title" SET NOT NULL"#
+    /// );
+    /// ```
+    pub fn new(table_name: &str) -> Self {
+        Self {
+            table_name: table_name.to_string(),
+            dialect: None,
+            operations: vec![],
+        }
+    }
+
+    /// set sql dialect, used for quoting, syntax and validation
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// `ADD COLUMN definition`
+    pub fn add_column(&mut self, column: &ColumnDef) -> &mut Self {
+        self.operations
+            .push(AlterOperation::AddColumn(column.clone()));
+        self
+    }
+
+    /// `DROP COLUMN name`
+    pub fn drop_column(&mut self, name: &str) -> &mut Self {
+        self.operations
+            .push(AlterOperation::DropColumn(name.to_string()));
+        self
+    }
+
+    /// `RENAME COLUMN from TO to`
+    pub fn rename_column(&mut self, from: &str, to: &str) -> &mut Self {
+        self.operations.push(AlterOperation::RenameColumn(
+            from.to_string(),
+            to.to_string(),
+        ));
+        self
+    }
+
+    /// change column type, `ALTER COLUMN name TYPE type`, postgres only.
+    /// mysql requires full column definition, use [`AlterTable::modify_column`]
+    /// ```rust
+    /// use qorm::{create_table_item::ColumnType, AlterTable, Dialect};
+    ///
+    /// let mut builder = AlterTable::new("todo");
+    /// builder.alter_type("point", ColumnType::BigInt);
+    /// assert_eq!(builder.to_sql(), r#"ALTER TABLE todo ALTER COLUMN point TYPE BIGINT"#);
+    /// ```
+    pub fn alter_type(&mut self, name: &str, column_type: ColumnType) -> &mut Self {
+        self.operations
+            .push(AlterOperation::AlterType(name.to_string(), column_type));
+        self
+    }
+
+    /// redefine column, `MODIFY COLUMN definition`, mysql only
+    /// ```rust
+    /// use qorm::{create_table_item::{ColumnDef, ColumnType}, AlterTable, Dialect};
+    ///
+    /// let mut builder = AlterTable::new("todo");
+    /// builder
+    ///     .dialect(Dialect::MySql)
+    ///     .modify_column(ColumnDef::new("point", ColumnType::BigInt).not_null());
+    /// assert_eq!(builder.to_sql(), "ALTER TABLE `todo` MODIFY COLUMN `point` BIGINT NOT NULL");
+    /// ```
+    pub fn modify_column(&mut self, column: &ColumnDef) -> &mut Self {
+        self.operations
+            .push(AlterOperation::ModifyColumn(column.clone()));
+        self
+    }
+
+    /// `ALTER COLUMN name SET DEFAULT value`, value is inlined as dialect literal
+    pub fn set_default(&mut self, name: &str, value: Bind) -> &mut Self {
+        self.operations.push(AlterOperation::SetDefault(
+            name.to_string(),
+            Expr::value(value),
+        ));
+        self
+    }
+
+    /// `ALTER COLUMN name SET DEFAULT raw`, e.g. `CURRENT_TIMESTAMP`
+    pub fn set_default_raw(&mut self, name: &str, raw: &str) -> &mut Self {
+        self.operations
+            .push(AlterOperation::SetDefault(name.to_string(), Expr::raw(raw)));
+        self
+    }
+
+    /// `ALTER COLUMN name DROP DEFAULT`
+    pub fn drop_default(&mut self, name: &str) -> &mut Self {
+        self.operations
+            .push(AlterOperation::DropDefault(name.to_string()));
+        self
+    }
+
+    /// `ALTER COLUMN name SET NOT NULL`, postgres only
+    pub fn set_not_null(&mut self, name: &str) -> &mut Self {
+        self.operations
+            .push(AlterOperation::SetNotNull(name.to_string()));
+        self
+    }
+
+    /// `ALTER COLUMN name DROP NOT NULL`, postgres only
+    pub fn drop_not_null(&mut self, name: &str) -> &mut Self {
+        self.operations
+            .push(AlterOperation::DropNotNull(name.to_string()));
+        self
+    }
+
+    /// `ADD CONSTRAINT name constraint`
+    /// ```rust
+    /// use qorm::{alter_table_item::Constraint, create_table_item::ForeignKey, AlterTable, Dialect};
+    ///
+    /// let mut builder = AlterTable::new("todo");
+    /// builder.dialect(Dialect::MySql).add_constraint(
+    ///     "todo_user_fk",
+    ///     Constraint::ForeignKey(ForeignKey::new(vec!["user_id"], "user", vec!["id"])),
+    /// );
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     "ALTER TABLE `todo` ADD CONSTRAINT `todo_user_fk` FOREIGN KEY (`user_id`) REFERENCES `user` (`id`)"
+    /// );
+    /// ```
+    pub fn add_constraint(&mut self, name: &str, constraint: Constraint) -> &mut Self {
+        self.operations
+            .push(AlterOperation::AddConstraint(name.to_string(), constraint));
+        self
+    }
+
+    /// `DROP CONSTRAINT name`
+    pub fn drop_constraint(&mut self, name: &str) -> &mut Self {
+        self.operations
+            .push(AlterOperation::DropConstraint(name.to_string()));
+        self
+    }
+
+    /// check builder against its dialect, sqlite supports single `ADD COLUMN`, `DROP COLUMN`
+    /// or `RENAME COLUMN` per statement
+    /// ```rust
+    /// use qorm::{AlterTable, Bind, Dialect, Error};
+    ///
+    /// let mut builder = AlterTable::new("todo");
+    /// builder.dialect(Dialect::Sqlite).set_default("point", Bind::Int(0));
+    /// assert_eq!(
+    ///     builder.validate(),
+    ///     Err(Error::Unsupported {
+    ///         feature: "SET DEFAULT".to_string(),
+    ///         dialect: Dialect::Sqlite,
+    ///     })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.operations.is_empty() {
+            return Err(Error::Invalid(format!(
+                "ALTER TABLE {} requires at least one operation",
+                self.table_name
+            )));
+        }
+        for operation in self.operations.iter() {
+            self.validate_operation(operation)?;
+        }
+        if self.dialect == Some(Dialect::Sqlite) && self.operations.len() > 1 {
+            return Err(Error::Unsupported {
+                feature: "multiple ALTER TABLE operations".to_string(),
+                dialect: Dialect::Sqlite,
+            });
+        }
+        Ok(())
+    }
+
+    fn validate_operation(&self, operation: &AlterOperation) -> Result<(), Error> {
+        let unsupported = || Error::Unsupported {
+            feature: operation.feature().to_string(),
+            dialect: self.dialect.unwrap(),
+        };
+        match (self.dialect, operation) {
+            (_, AlterOperation::AddColumn(column)) => {
+                column.validate(self.dialect)?;
+                if self.dialect == Some(Dialect::Sqlite) && (column.primary_key || column.unique) {
+                    return Err(Error::Unsupported {
+                        feature: "ADD COLUMN with PRIMARY KEY or UNIQUE".to_string(),
+                        dialect: Dialect::Sqlite,
+                    });
+                }
+                if self.dialect == Some(Dialect::Sqlite)
+                    && column.not_null
+                    && column.default.is_none()
+                {
+                    return Err(Error::Unsupported {
+                        feature: "ADD COLUMN NOT NULL without DEFAULT".to_string(),
+                        dialect: Dialect::Sqlite,
+                    });
+                }
+                Ok(())
+            }
+            (Some(Dialect::MySql), AlterOperation::ModifyColumn(column)) => {
+                column.validate(self.dialect)
+            }
+            (_, AlterOperation::DropColumn(_)) | (_, AlterOperation::RenameColumn(_, _)) => Ok(()),
+            (Some(Dialect::Sqlite), _) => Err(unsupported()),
+            (Some(Dialect::MySql), AlterOperation::AlterType(_, _))
+            | (Some(Dialect::MySql), AlterOperation::SetNotNull(_))
+            | (Some(Dialect::MySql), AlterOperation::DropNotNull(_)) => Err(unsupported()),
+            (Some(_), AlterOperation::ModifyColumn(_)) => Err(unsupported()),
+            (None, AlterOperation::ModifyColumn(_)) => Err(Error::Invalid(
+                "MODIFY COLUMN requires mysql dialect".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn parse_operation(&self, operation: &AlterOperation) -> String {
+        let quote = |name: &str| quote_identifier(self.dialect, name);
+        match operation {
+            AlterOperation::AddColumn(column) => {
+                let mut sql = format!("ADD COLUMN {}", column.to_sql(self.dialect));
+                // mysql ignores inline column references
+                if let (Some(Dialect::MySql), Some(references)) =
+                    (self.dialect, column.references.as_ref())
+                {
+                    sql.push_str(format!(", ADD {}", references.to_sql(self.dialect)).as_str());
+                }
+                sql
+            }
+            AlterOperation::DropColumn(name) => format!("DROP COLUMN {}", quote(name)),
+            AlterOperation::RenameColumn(from, to) => {
+                format!("RENAME COLUMN {} TO {}", quote(from), quote(to))
+            }
+            AlterOperation::AlterType(name, column_type) => format!(
+                "ALTER COLUMN {} TYPE {}",
+                quote(name),
+                column_type.to_sql(self.dialect)
+            ),
+            AlterOperation::ModifyColumn(column) => {
+                format!("MODIFY COLUMN {}", column.to_sql(self.dialect))
+            }
+            AlterOperation::SetDefault(name, value) => format!(
+                "ALTER COLUMN {} SET DEFAULT {}",
+                quote(name),
+                value.to_sql(self.dialect, &mut |bind| bind.to_literal(self.dialect))
+            ),
+            AlterOperation::DropDefault(name) => {
+                format!("ALTER COLUMN {} DROP DEFAULT", quote(name))
+            }
+            AlterOperation::SetNotNull(name) => {
+                format!("ALTER COLUMN {} SET NOT NULL", quote(name))
+            }
+            AlterOperation::DropNotNull(name) => {
+                format!("ALTER COLUMN {} DROP NOT NULL", quote(name))
+            }
+            AlterOperation::AddConstraint(name, constraint) => format!(
+                "ADD CONSTRAINT {} {}",
+                quote(name),
+                constraint.to_sql(self.dialect)
+            ),
+            AlterOperation::DropConstraint(name) => format!("DROP CONSTRAINT {}", quote(name)),
+        }
+    }
+
+    /// validate builder then get generated sql query
+    pub fn try_to_sql(&self) -> Result<String, Error> {
+        self.validate()?;
+        Ok(self.to_sql())
+    }
+
+    /// get generated sql query
+    ///
+    /// builder is not validated, use [`AlterTable::try_to_sql`] to get [`AlterTable::validate`] error
+    pub fn to_sql(&self) -> String {
+        let operations = self
+            .operations
+            .iter()
+            .map(|f| self.parse_operation(f))
+            .collect::<Vec<String>>();
+        format!(
+            "ALTER TABLE {} {}",
+            quote_identifier(self.dialect, &self.table_name),
+            operations.join(", ")
+        )
+    }
+}
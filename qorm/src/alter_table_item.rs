@@ -0,0 +1,75 @@
+use crate::{
+    create_table_item::{quote_list, ColumnDef, ColumnType, ForeignKey},
+    dialect::Dialect,
+    expr::Expr,
+};
+
+/// Constraint
+///
+/// table constraint added with [`crate::AlterTable::add_constraint`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Constraint {
+    /// `PRIMARY KEY (columns)`
+    PrimaryKey(Vec<String>),
+    /// `UNIQUE (columns)`
+    Unique(Vec<String>),
+    /// `CHECK (raw)`
+    Check(String),
+    /// `FOREIGN KEY (columns) REFERENCES table (columns)`
+    ForeignKey(ForeignKey),
+}
+
+impl Constraint {
+    /// get generated constraint
+    /// ```rust
+    /// use qorm::{alter_table_item::Constraint, Dialect};
+    ///
+    /// let constraint = Constraint::Unique(vec!["user_id".to_string(), "name".to_string()]);
+    /// assert_eq!(constraint.to_sql(Some(Dialect::MySql)), "UNIQUE (`user_id`, `name`)");
+    /// ```
+    pub fn to_sql(&self, dialect: Option<Dialect>) -> String {
+        match self {
+            Constraint::PrimaryKey(columns) => {
+                format!("PRIMARY KEY ({})", quote_list(dialect, columns))
+            }
+            Constraint::Unique(columns) => format!("UNIQUE ({})", quote_list(dialect, columns)),
+            Constraint::Check(raw) => format!("CHECK ({})", raw),
+            Constraint::ForeignKey(foreign_key) => foreign_key.to_sql(dialect),
+        }
+    }
+}
+
+/// single ALTER TABLE operation
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum AlterOperation {
+    AddColumn(ColumnDef),
+    DropColumn(String),
+    RenameColumn(String, String),
+    AlterType(String, ColumnType),
+    ModifyColumn(ColumnDef),
+    SetDefault(String, Expr),
+    DropDefault(String),
+    SetNotNull(String),
+    DropNotNull(String),
+    AddConstraint(String, Constraint),
+    DropConstraint(String),
+}
+
+impl AlterOperation {
+    /// operation name used on unsupported error
+    pub(crate) fn feature(&self) -> &'static str {
+        match self {
+            AlterOperation::AddColumn(_) => "ADD COLUMN",
+            AlterOperation::DropColumn(_) => "DROP COLUMN",
+            AlterOperation::RenameColumn(_, _) => "RENAME COLUMN",
+            AlterOperation::AlterType(_, _) => "ALTER COLUMN TYPE",
+            AlterOperation::ModifyColumn(_) => "MODIFY COLUMN",
+            AlterOperation::SetDefault(_, _) => "SET DEFAULT",
+            AlterOperation::DropDefault(_) => "DROP DEFAULT",
+            AlterOperation::SetNotNull(_) => "SET NOT NULL",
+            AlterOperation::DropNotNull(_) => "DROP NOT NULL",
+            AlterOperation::AddConstraint(_, _) => "ADD CONSTRAINT",
+            AlterOperation::DropConstraint(_) => "DROP CONSTRAINT",
+        }
+    }
+}
@@ -1,8 +1,129 @@
+use crate::dialect::Dialect;
+
+/// placeholder used while rendering debug sql, replaced by bind literal
+pub(crate) const DEBUG_PLACEHOLDER: &str = "\u{1}%d\u{1}";
+
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Bind {
     Null,
     String(String),
     Int(i32),
     Bool(bool),
     Raw(String),
+    BigInt(i64),
+    Float(f64),
+    Bytes(Vec<u8>),
+}
+
+impl From<String> for Bind {
+    fn from(value: String) -> Self {
+        Bind::String(value)
+    }
+}
+
+impl From<&str> for Bind {
+    fn from(value: &str) -> Self {
+        Bind::String(value.to_string())
+    }
+}
+
+impl From<i32> for Bind {
+    fn from(value: i32) -> Self {
+        Bind::Int(value)
+    }
+}
+
+impl From<i64> for Bind {
+    fn from(value: i64) -> Self {
+        Bind::BigInt(value)
+    }
+}
+
+impl From<f64> for Bind {
+    fn from(value: f64) -> Self {
+        Bind::Float(value)
+    }
+}
+
+impl From<Vec<u8>> for Bind {
+    fn from(value: Vec<u8>) -> Self {
+        Bind::Bytes(value)
+    }
+}
+
+impl From<bool> for Bind {
+    fn from(value: bool) -> Self {
+        Bind::Bool(value)
+    }
+}
+
+impl<T> From<Option<T>> for Bind
+where
+    Bind: From<T>,
+{
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Bind::from(value),
+            None => Bind::Null,
+        }
+    }
+}
+
+impl Bind {
+    /// sql literal of bind for dialect, used by debug rendering
+    /// ```rust
+    /// use qorm::{Bind, Dialect};
+    ///
+    /// assert_eq!(Bind::from("it's").to_literal(None), "'it''s'");
+    /// assert_eq!(Bind::Bool(true).to_literal(Some(Dialect::Sqlite)), "1");
+    /// assert_eq!(Bind::Bytes(vec![222, 173]).to_literal(Some(Dialect::Postgres)), "'\\xdead'");
+    /// assert_eq!(Bind::Bytes(vec![222, 173]).to_literal(Some(Dialect::MySql)), "X'dead'");
+    /// ```
+    pub fn to_literal(&self, dialect: Option<Dialect>) -> String {
+        match self {
+            Bind::Null => "NULL".to_string(),
+            Bind::String(value) | Bind::Raw(value) => {
+                let mut value = value.replace('\'', "''");
+                if dialect == Some(Dialect::MySql) {
+                    value = value.replace('\\', "\\\\");
+                }
+                format!("'{}'", value)
+            }
+            Bind::Int(value) => value.to_string(),
+            Bind::BigInt(value) => value.to_string(),
+            Bind::Float(value) => match value.is_finite() {
+                true => format!("{:?}", value),
+                false => format!("'{}'", value),
+            },
+            Bind::Bool(value) => match (dialect, value) {
+                (Some(Dialect::Sqlite), true) => "1".to_string(),
+                (Some(Dialect::Sqlite), false) => "0".to_string(),
+                (_, true) => "TRUE".to_string(),
+                (_, false) => "FALSE".to_string(),
+            },
+            Bind::Bytes(value) => {
+                let hex: String = value.iter().map(|f| format!("{:02x}", f)).collect();
+                match dialect {
+                    Some(Dialect::Postgres) => format!("'\\x{}'", hex),
+                    _ => format!("X'{}'", hex),
+                }
+            }
+        }
+    }
+}
+
+/// replace debug placeholders of sql with bind literals
+pub(crate) fn debug_sql(sql: &str, binds: &[Bind], dialect: Option<Dialect>) -> String {
+    let mut result = "-- debug sql with inlined binds, not for execution\n".to_string();
+    let mut parts = sql.split('\u{1}');
+    result.push_str(parts.next().unwrap_or_default());
+    while let (Some(index), Some(rest)) = (parts.next(), parts.next()) {
+        match index.parse::<usize>().ok().and_then(|f| binds.get(f)) {
+            Some(bind) => result.push_str(&bind.to_literal(dialect)),
+            None => result.push('?'),
+        }
+        result.push_str(rest);
+    }
+    result
 }
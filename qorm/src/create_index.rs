@@ -0,0 +1,320 @@
+use crate::{
+    create_index_item::{IndexMethod, IndexPart},
+    dialect::{quote_identifier, Dialect},
+    error::Error,
+    where_item::{Condition, Or},
+    Bind,
+};
+
+/// sql create index builder
+#[derive(Clone, Debug)]
+pub struct CreateIndex {
+    pub index_name: String,
+    pub table_name: String,
+    dialect: Option<Dialect>,
+    unique: bool,
+    concurrently: bool,
+    if_not_exists: bool,
+    method: Option<IndexMethod>,
+    parts: Vec<IndexPart>,
+    where_and: Option<Vec<Condition>>,
+    where_or: Option<Vec<Vec<Condition>>>,
+}
+
+impl CreateIndex {
+    /// Initialize CreateIndex builder
+    /// ```rust
+    /// use qorm::{create_index_item::IndexMethod, Bind, CreateIndex, Dialect};
+    ///
+    /// let mut builder = CreateIndex::new("todo_user_id_idx", "todo");
+    /// builder
+    ///     .dialect(Dialect::Postgres)
+    ///     .concurrently()
+    ///     .if_not_exists()
+    ///     .using(IndexMethod::BTree)
+    ///     .column("user_id")
+    ///     .column("created_at");
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     r#"CREATE INDEX CONCURRENTLY IF NOT EXISTS "todo_user_id_idx" ON "todo" USING BTREE ("user_id", "created_at")"#
+    /// );
+    /// ```
+    pub fn new(index_name: &str, table_name: &str) -> Self {
+        Self {
+            index_name: index_name.to_string(),
+            table_name: table_name.to_string(),
+            dialect: None,
+            unique: false,
+            concurrently: false,
+            if_not_exists: false,
+            method: None,
+            parts: vec![],
+            where_and: None,
+            where_or: None,
+        }
+    }
+
+    /// set sql dialect, used for quoting, syntax and validation
+    /// ```rust
+    /// use qorm::{create_index_item::IndexMethod, CreateIndex, Dialect};
+    ///
+    /// let mut builder = CreateIndex::new("todo_name_idx", "todo");
+    /// builder
+    ///     .dialect(Dialect::MySql)
+    ///     .unique()
+    ///     .using(IndexMethod::Hash)
+    ///     .column("name");
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     "CREATE UNIQUE INDEX `todo_name_idx` ON `todo` (`name`) USING HASH"
+    /// );
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// `UNIQUE`
+    pub fn unique(&mut self) -> &mut Self {
+        self.unique = true;
+        self
+    }
+
+    /// `CONCURRENTLY`, build index without locking writes. postgres only
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// `IF NOT EXISTS`, not supported on mysql
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// index method, `USING method`. not supported on sqlite
+    pub fn using(&mut self, method: IndexMethod) -> &mut Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// add indexed column
+    pub fn column(&mut self, name: &str) -> &mut Self {
+        self.parts.push(IndexPart::Column(name.to_string()));
+        self
+    }
+
+    /// add indexed expression, rendered as is inside parentheses
+    /// ```rust
+    /// use qorm::{CreateIndex, Dialect};
+    ///
+    /// let mut builder = CreateIndex::new("user_email_idx", "user");
+    /// builder
+    ///     .dialect(Dialect::Sqlite)
+    ///     .unique()
+    ///     .expression("lower(email)");
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     r#"CREATE UNIQUE INDEX "user_email_idx" ON "user" ((lower(email)))"#
+    /// );
+    /// ```
+    pub fn expression(&mut self, raw: &str) -> &mut Self {
+        self.parts.push(IndexPart::Expr(raw.to_string()));
+        self
+    }
+
+    /// partial index where and, value is inlined as dialect literal. not supported on mysql
+    /// ```rust
+    /// use qorm::{Bind, CreateIndex, Dialect};
+    ///
+    /// let mut builder = CreateIndex::new("todo_open_idx", "todo");
+    /// builder
+    ///     .dialect(Dialect::Sqlite)
+    ///     .column("user_id")
+    ///     .wheres("is_done", "=", Bind::Bool(false))
+    ///     .wheres("deleted_at", "IS", Bind::Null);
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     r#"CREATE INDEX "todo_open_idx" ON "todo" ("user_id") WHERE is_done = 0 AND deleted_at IS NULL"#
+    /// );
+    /// ```
+    pub fn wheres(&mut self, column: &str, operator: &str, value: Bind) -> &mut Self {
+        self.where_and.get_or_insert_with(Vec::new).push(Condition {
+            column: column.to_string(),
+            operator: operator.to_string(),
+            value,
+        });
+        self
+    }
+
+    /// partial index where or, value is inlined as dialect literal. not supported on mysql
+    /// ```rust
+    /// use qorm::{where_item::Or, Bind, CreateIndex};
+    ///
+    /// let mut builder = CreateIndex::new("todo_active_idx", "todo");
+    /// builder.column("user_id").where_or(vec![
+    ///     Or {
+    ///         column: "status",
+    ///         operator: "=",
+    ///         value: Bind::from("open"),
+    ///     },
+    ///     Or {
+    ///         column: "point",
+    ///         operator: ">",
+    ///         value: Bind::Int(10),
+    ///     },
+    /// ]);
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     r#"CREATE INDEX todo_active_idx ON todo (user_id) WHERE ( status = 'open' OR point > 10)"#
+    /// );
+    /// ```
+    pub fn where_or(&mut self, wheres: Vec<Or>) -> &mut Self {
+        let wheres = wheres
+            .iter()
+            .map(|f| Condition {
+                column: f.column.to_string(),
+                operator: f.operator.to_string(),
+                value: f.value.clone(),
+            })
+            .collect();
+        self.where_or.get_or_insert_with(Vec::new).push(wheres);
+        self
+    }
+
+    fn parse_condition(&self, item: &Condition) -> String {
+        match item.value {
+            Bind::Null => format!(" {} {} NULL", item.column, item.operator),
+            _ => format!(
+                " {} {} {}",
+                item.column,
+                item.operator,
+                item.value.to_literal(self.dialect)
+            ),
+        }
+    }
+
+    fn parse_where(&self, sql: &mut String) {
+        if self.where_and.is_none() && self.where_or.is_none() {
+            return;
+        }
+        sql.push_str(" WHERE");
+        if let Some(where_and) = &self.where_and {
+            let conditions = where_and
+                .iter()
+                .map(|f| self.parse_condition(f))
+                .collect::<Vec<String>>();
+            sql.push_str(conditions.join(" AND").as_str());
+        }
+        if let Some(where_or) = &self.where_or {
+            for (idx, or_vec) in where_or.iter().enumerate() {
+                if idx == 0 && self.where_and.is_none() {
+                    sql.push_str(" (");
+                } else {
+                    sql.push_str(" AND (");
+                }
+                let conditions = or_vec
+                    .iter()
+                    .map(|f| self.parse_condition(f))
+                    .collect::<Vec<String>>();
+                sql.push_str(conditions.join(" OR").as_str());
+                sql.push(')');
+            }
+        }
+    }
+
+    /// check builder against its dialect
+    /// ```rust
+    /// use qorm::{create_index_item::IndexMethod, CreateIndex, Dialect, Error};
+    ///
+    /// let mut builder = CreateIndex::new("todo_tags_idx", "todo");
+    /// builder
+    ///     .dialect(Dialect::MySql)
+    ///     .using(IndexMethod::Gin)
+    ///     .column("tags");
+    /// assert_eq!(
+    ///     builder.validate(),
+    ///     Err(Error::Unsupported {
+    ///         feature: "index method GIN".to_string(),
+    ///         dialect: Dialect::MySql,
+    ///     })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.parts.is_empty() {
+            return Err(Error::Invalid(format!(
+                "CREATE INDEX {} requires at least one column or expression",
+                self.index_name
+            )));
+        }
+        let dialect = match self.dialect {
+            Some(dialect) => dialect,
+            None => return Ok(()),
+        };
+        let unsupported = |feature: String| Error::Unsupported { feature, dialect };
+        if self.concurrently && dialect != Dialect::Postgres {
+            return Err(unsupported("CREATE INDEX CONCURRENTLY".to_string()));
+        }
+        if self.if_not_exists && dialect == Dialect::MySql {
+            return Err(unsupported("CREATE INDEX IF NOT EXISTS".to_string()));
+        }
+        if let Some(method) = self.method {
+            if !method.supported(self.dialect) {
+                return Err(unsupported(format!("index method {}", method.to_sql())));
+            }
+        }
+        if (self.where_and.is_some() || self.where_or.is_some()) && dialect == Dialect::MySql {
+            return Err(unsupported("partial index".to_string()));
+        }
+        Ok(())
+    }
+
+    /// validate builder then get generated sql query
+    pub fn try_to_sql(&self) -> Result<String, Error> {
+        self.validate()?;
+        Ok(self.to_sql())
+    }
+
+    /// get generated sql query
+    ///
+    /// builder is not validated, use [`CreateIndex::try_to_sql`] to get [`CreateIndex::validate`] error
+    pub fn to_sql(&self) -> String {
+        let mut sql = "CREATE".to_string();
+        if self.unique {
+            sql.push_str(" UNIQUE");
+        }
+        sql.push_str(" INDEX");
+        if self.concurrently {
+            sql.push_str(" CONCURRENTLY");
+        }
+        if self.if_not_exists {
+            sql.push_str(" IF NOT EXISTS");
+        }
+        sql.push_str(
+            format!(
+                " {} ON {}",
+                quote_identifier(self.dialect, &self.index_name),
+                quote_identifier(self.dialect, &self.table_name)
+            )
+            .as_str(),
+        );
+        let parts = self
+            .parts
+            .iter()
+            .map(|f| f.to_sql(self.dialect))
+            .collect::<Vec<String>>()
+            .join(", ");
+        match (self.dialect, self.method) {
+            // mysql index type is an index option after key parts
+            (Some(Dialect::MySql), Some(method)) => {
+                sql.push_str(format!(" ({}) USING {}", parts, method.to_sql()).as_str())
+            }
+            (_, Some(method)) => {
+                sql.push_str(format!(" USING {} ({})", method.to_sql(), parts).as_str())
+            }
+            (_, None) => sql.push_str(format!(" ({})", parts).as_str()),
+        }
+        self.parse_where(&mut sql);
+        sql
+    }
+}
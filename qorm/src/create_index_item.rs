@@ -0,0 +1,59 @@
+use crate::dialect::{quote_identifier, Dialect};
+
+/// IndexMethod
+///
+/// index access method set with [`crate::CreateIndex::using`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IndexMethod {
+    /// `BTREE`, postgres and mysql
+    BTree,
+    /// `HASH`, postgres and mysql
+    Hash,
+    /// `GIN`, postgres only
+    Gin,
+    /// `GIST`, postgres only
+    Gist,
+}
+
+impl IndexMethod {
+    /// get generated index method
+    /// ```rust
+    /// use qorm::create_index_item::IndexMethod;
+    ///
+    /// assert_eq!(IndexMethod::Gin.to_sql(), "GIN");
+    /// ```
+    pub fn to_sql(&self) -> &'static str {
+        match self {
+            IndexMethod::BTree => "BTREE",
+            IndexMethod::Hash => "HASH",
+            IndexMethod::Gin => "GIN",
+            IndexMethod::Gist => "GIST",
+        }
+    }
+
+    /// whether the method can be used on the dialect
+    pub(crate) fn supported(&self, dialect: Option<Dialect>) -> bool {
+        match dialect {
+            Some(Dialect::Sqlite) => false,
+            Some(Dialect::MySql) => matches!(self, IndexMethod::BTree | IndexMethod::Hash),
+            _ => true,
+        }
+    }
+}
+
+/// single indexed column or expression
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum IndexPart {
+    Column(String),
+    Expr(String),
+}
+
+impl IndexPart {
+    pub(crate) fn to_sql(&self, dialect: Option<Dialect>) -> String {
+        match self {
+            IndexPart::Column(name) => quote_identifier(dialect, name),
+            // expression must be wrapped on every dialect
+            IndexPart::Expr(raw) => format!("({})", raw),
+        }
+    }
+}
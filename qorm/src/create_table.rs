@@ -0,0 +1,221 @@
+use crate::{
+    create_table_item::{quote_list, ColumnDef, ForeignKey},
+    dialect::{quote_identifier, Dialect},
+    error::Error,
+};
+
+/// sql create table builder
+#[derive(Clone, Debug)]
+pub struct CreateTable {
+    pub table_name: String,
+    dialect: Option<Dialect>,
+    if_not_exists: bool,
+    columns: Vec<ColumnDef>,
+    primary_key: Option<Vec<String>>,
+    unique: Option<Vec<Vec<String>>>,
+    check: Option<Vec<String>>,
+    foreign_key: Option<Vec<ForeignKey>>,
+}
+
+impl CreateTable {
+    /// Initialize CreateTable builder
+    /// ```rust
+    /// use qorm::{
+    ///     create_table_item::{ColumnDef, ColumnType, ReferentialAction},
+    ///     Bind, CreateTable, Dialect,
+    /// };
+    ///
+    /// let mut builder = CreateTable::new("todo");
+    /// builder
+    ///     .dialect(Dialect::Postgres)
+    ///     .if_not_exists()
+    ///     .column(ColumnDef::new("id", ColumnType::BigInt).primary_key().auto_increment())
+    ///     .column(ColumnDef::new("name", ColumnType::Varchar(255)).not_null())
+    ///     .column(ColumnDef::new("is_done", ColumnType::Boolean).not_null().default(Bind::Bool(false)))
+    ///     .column(
+    ///         ColumnDef::new("user_id", ColumnType::BigInt)
+    ///             .references("user", "id")
+    ///             .on_delete(ReferentialAction::Cascade),
+    ///     )
+    ///     .unique(vec!["user_id", "name"]);
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     r#"CREATE TABLE IF NOT EXISTS "todo" ("id" BIGSERIAL PRIMARY KEY, "name" VARCHAR(255) NOT NULL, "is_done" BOOLEAN NOT NULL DEFAULT FALSE, "user_id" BIGINT REFERENCES "user" ("id") ON DELETE CASCADE, UNIQUE ("user_id", "name"))"#
+    /// );
+    /// ```
+    pub fn new(table_name: &str) -> Self {
+        Self {
+            table_name: table_name.to_string(),
+            dialect: None,
+            if_not_exists: false,
+            columns: vec![],
+            primary_key: None,
+            unique: None,
+            check: None,
+            foreign_key: None,
+        }
+    }
+
+    /// set sql dialect, used for quoting, type mapping and validation
+    /// ```rust
+    /// use qorm::{create_table_item::{ColumnDef, ColumnType}, CreateTable, Dialect};
+    ///
+    /// let mut builder = CreateTable::new("todo");
+    /// builder
+    ///     .dialect(Dialect::MySql)
+    ///     .column(ColumnDef::new("id", ColumnType::BigInt).primary_key().auto_increment())
+    ///     .column(ColumnDef::new("user_id", ColumnType::BigInt).references("user", "id"));
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     "CREATE TABLE `todo` (`id` BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY, `user_id` BIGINT, FOREIGN KEY (`user_id`) REFERENCES `user` (`id`))"
+    /// );
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// `IF NOT EXISTS`
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// add column definition
+    pub fn column(&mut self, column: &ColumnDef) -> &mut Self {
+        self.columns.push(column.clone());
+        self
+    }
+
+    /// table primary key, `PRIMARY KEY (columns)`
+    /// ```rust
+    /// use qorm::{create_table_item::{ColumnDef, ColumnType}, CreateTable};
+    ///
+    /// let mut builder = CreateTable::new("todo_tag");
+    /// builder
+    ///     .column(ColumnDef::new("todo_id", ColumnType::BigInt).not_null())
+    ///     .column(ColumnDef::new("tag_id", ColumnType::BigInt).not_null())
+    ///     .primary_key(vec!["todo_id", "tag_id"]);
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     r#"CREATE TABLE todo_tag (todo_id BIGINT NOT NULL, tag_id BIGINT NOT NULL, PRIMARY KEY (todo_id, tag_id))"#
+    /// );
+    /// ```
+    pub fn primary_key(&mut self, columns: Vec<&str>) -> &mut Self {
+        self.primary_key = Some(columns.iter().map(|f| f.to_string()).collect());
+        self
+    }
+
+    /// table unique constraint, `UNIQUE (columns)`
+    pub fn unique(&mut self, columns: Vec<&str>) -> &mut Self {
+        let columns = columns.iter().map(|f| f.to_string()).collect();
+        self.unique.get_or_insert_with(Vec::new).push(columns);
+        self
+    }
+
+    /// table check constraint, `CHECK (raw)`
+    pub fn check(&mut self, raw: &str) -> &mut Self {
+        self.check
+            .get_or_insert_with(Vec::new)
+            .push(raw.to_string());
+        self
+    }
+
+    /// table foreign key, `FOREIGN KEY (columns) REFERENCES table (columns)`
+    pub fn foreign_key(&mut self, foreign_key: &ForeignKey) -> &mut Self {
+        self.foreign_key
+            .get_or_insert_with(Vec::new)
+            .push(foreign_key.clone());
+        self
+    }
+
+    /// check builder against its dialect
+    /// ```rust
+    /// use qorm::{create_table_item::{ColumnDef, ColumnType}, CreateTable, Dialect, Error};
+    ///
+    /// let mut builder = CreateTable::new("todo");
+    /// builder
+    ///     .dialect(Dialect::Sqlite)
+    ///     .column(ColumnDef::new("id", ColumnType::BigInt).identity(true));
+    /// assert_eq!(
+    ///     builder.validate(),
+    ///     Err(Error::Unsupported {
+    ///         feature: "GENERATED AS IDENTITY".to_string(),
+    ///         dialect: Dialect::Sqlite,
+    ///     })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.columns.is_empty() {
+            return Err(Error::Invalid(format!(
+                "CREATE TABLE {} requires at least one column",
+                self.table_name
+            )));
+        }
+        for column in self.columns.iter() {
+            column.validate(self.dialect)?;
+        }
+        let column_primary_key = self.columns.iter().filter(|f| f.primary_key).count();
+        if column_primary_key > 1 || (column_primary_key == 1 && self.primary_key.is_some()) {
+            return Err(Error::Invalid(format!(
+                "table {} has multiple primary keys",
+                self.table_name
+            )));
+        }
+        Ok(())
+    }
+
+    /// validate builder then get generated sql query
+    pub fn try_to_sql(&self) -> Result<String, Error> {
+        self.validate()?;
+        Ok(self.to_sql())
+    }
+
+    /// get generated sql query
+    ///
+    /// builder is not validated, use [`CreateTable::try_to_sql`] to get [`CreateTable::validate`] error
+    pub fn to_sql(&self) -> String {
+        let mut sql = "CREATE TABLE".to_string();
+        if self.if_not_exists {
+            sql.push_str(" IF NOT EXISTS");
+        }
+        sql.push_str(format!(" {} (", quote_identifier(self.dialect, &self.table_name)).as_str());
+
+        let mut definitions = vec![];
+        for column in self.columns.iter() {
+            definitions.push(column.to_sql(self.dialect));
+        }
+        if let Some(primary_key) = &self.primary_key {
+            definitions.push(format!(
+                "PRIMARY KEY ({})",
+                quote_list(self.dialect, primary_key)
+            ));
+        }
+        if let Some(unique) = &self.unique {
+            for columns in unique {
+                definitions.push(format!("UNIQUE ({})", quote_list(self.dialect, columns)));
+            }
+        }
+        if let Some(check) = &self.check {
+            for raw in check {
+                definitions.push(format!("CHECK ({})", raw));
+            }
+        }
+        // mysql ignores inline column references
+        if self.dialect == Some(Dialect::MySql) {
+            for column in self.columns.iter() {
+                if let Some(references) = &column.references {
+                    definitions.push(references.to_sql(self.dialect));
+                }
+            }
+        }
+        if let Some(foreign_key) = &self.foreign_key {
+            for item in foreign_key {
+                definitions.push(item.to_sql(self.dialect));
+            }
+        }
+        sql.push_str(definitions.join(", ").as_str());
+        sql.push(')');
+        sql
+    }
+}
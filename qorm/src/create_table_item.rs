@@ -0,0 +1,481 @@
+use crate::{
+    dialect::{quote_identifier, Dialect},
+    error::Error,
+    expr::Expr,
+    Bind,
+};
+
+/// column type, mapped to dialect specific type name
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    SmallInt,
+    Integer,
+    BigInt,
+    Real,
+    Double,
+    /// `DECIMAL(precision, scale)`
+    Decimal(u32, u32),
+    Boolean,
+    Char(u32),
+    Varchar(u32),
+    Text,
+    Bytes,
+    Date,
+    Time,
+    Timestamp,
+    /// timestamp with time zone
+    TimestampTz,
+    Json,
+    Uuid,
+    /// raw type name, rendered as is
+    Custom(String),
+}
+
+impl ColumnType {
+    /// dialect specific type name, postgres names are used when dialect is empty
+    /// ```rust
+    /// use qorm::{create_table_item::ColumnType, Dialect};
+    ///
+    /// assert_eq!(ColumnType::Double.to_sql(Some(Dialect::Postgres)), "DOUBLE PRECISION");
+    /// assert_eq!(ColumnType::Bytes.to_sql(Some(Dialect::MySql)), "BLOB");
+    /// assert_eq!(ColumnType::Uuid.to_sql(Some(Dialect::Sqlite)), "TEXT");
+    /// ```
+    pub fn to_sql(&self, dialect: Option<Dialect>) -> String {
+        let dialect = dialect.unwrap_or(Dialect::Postgres);
+        match (self, dialect) {
+            (ColumnType::SmallInt, _) => "SMALLINT".to_string(),
+            (ColumnType::Integer, Dialect::MySql) => "INT".to_string(),
+            (ColumnType::Integer, _) => "INTEGER".to_string(),
+            (ColumnType::BigInt, _) => "BIGINT".to_string(),
+            (ColumnType::Real, Dialect::MySql) => "FLOAT".to_string(),
+            (ColumnType::Real, _) => "REAL".to_string(),
+            (ColumnType::Double, Dialect::Postgres) => "DOUBLE PRECISION".to_string(),
+            (ColumnType::Double, Dialect::MySql) => "DOUBLE".to_string(),
+            (ColumnType::Double, Dialect::Sqlite) => "REAL".to_string(),
+            (ColumnType::Decimal(precision, scale), Dialect::MySql) => {
+                format!("DECIMAL({}, {})", precision, scale)
+            }
+            (ColumnType::Decimal(precision, scale), _) => {
+                format!("NUMERIC({}, {})", precision, scale)
+            }
+            (ColumnType::Boolean, _) => "BOOLEAN".to_string(),
+            (ColumnType::Char(length), _) => format!("CHAR({})", length),
+            (ColumnType::Varchar(length), _) => format!("VARCHAR({})", length),
+            (ColumnType::Text, _) => "TEXT".to_string(),
+            (ColumnType::Bytes, Dialect::Postgres) => "BYTEA".to_string(),
+            (ColumnType::Bytes, _) => "BLOB".to_string(),
+            (ColumnType::Date, _) => "DATE".to_string(),
+            (ColumnType::Time, _) => "TIME".to_string(),
+            (ColumnType::Timestamp, Dialect::MySql) => "DATETIME".to_string(),
+            (ColumnType::Timestamp, _) => "TIMESTAMP".to_string(),
+            (ColumnType::TimestampTz, Dialect::Postgres) => "TIMESTAMPTZ".to_string(),
+            (ColumnType::TimestampTz, _) => "TIMESTAMP".to_string(),
+            (ColumnType::Json, Dialect::Sqlite) => "TEXT".to_string(),
+            (ColumnType::Json, _) => "JSON".to_string(),
+            (ColumnType::Uuid, Dialect::Postgres) => "UUID".to_string(),
+            (ColumnType::Uuid, Dialect::MySql) => "CHAR(36)".to_string(),
+            (ColumnType::Uuid, Dialect::Sqlite) => "TEXT".to_string(),
+            (ColumnType::Custom(name), _) => name.clone(),
+        }
+    }
+
+    fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            ColumnType::SmallInt | ColumnType::Integer | ColumnType::BigInt
+        )
+    }
+}
+
+/// foreign key `ON DELETE` / `ON UPDATE` action
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReferentialAction {
+    Cascade,
+    SetNull,
+    SetDefault,
+    Restrict,
+    NoAction,
+}
+
+impl ReferentialAction {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+            ReferentialAction::SetDefault => "SET DEFAULT",
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::NoAction => "NO ACTION",
+        }
+    }
+}
+
+/// ForeignKey
+///
+/// foreign key of column ([`ColumnDef::references`]) or table ([`crate::CreateTable::foreign_key`])
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForeignKey {
+    pub(crate) columns: Vec<String>,
+    pub(crate) table: String,
+    pub(crate) references: Vec<String>,
+    pub(crate) on_delete: Option<ReferentialAction>,
+    pub(crate) on_update: Option<ReferentialAction>,
+}
+
+impl ForeignKey {
+    /// Initialize foreign key of `columns` referencing `table (references)`
+    /// ```rust
+    /// use qorm::create_table_item::{ForeignKey, ReferentialAction};
+    ///
+    /// let foreign_key = ForeignKey::new(vec!["user_id"], "user", vec!["id"])
+    ///     .on_delete(ReferentialAction::Cascade)
+    ///     .clone();
+    /// assert_eq!(
+    ///     foreign_key.to_sql(None),
+    ///     r#"FOREIGN KEY (user_id) REFERENCES user (id) ON DELETE CASCADE"#
+    /// );
+    /// ```
+    pub fn new(columns: Vec<&str>, table: &str, references: Vec<&str>) -> Self {
+        Self {
+            columns: columns.iter().map(|f| f.to_string()).collect(),
+            table: table.to_string(),
+            references: references.iter().map(|f| f.to_string()).collect(),
+            on_delete: None,
+            on_update: None,
+        }
+    }
+
+    /// `ON DELETE` action
+    pub fn on_delete(&mut self, action: ReferentialAction) -> &mut Self {
+        self.on_delete = Some(action);
+        self
+    }
+
+    /// `ON UPDATE` action
+    pub fn on_update(&mut self, action: ReferentialAction) -> &mut Self {
+        self.on_update = Some(action);
+        self
+    }
+
+    /// `REFERENCES table (columns) ON ...` part
+    pub(crate) fn references_sql(&self, dialect: Option<Dialect>) -> String {
+        let mut sql = format!(
+            "REFERENCES {} ({})",
+            quote_identifier(dialect, &self.table),
+            quote_list(dialect, &self.references)
+        );
+        if let Some(action) = &self.on_delete {
+            sql.push_str(format!(" ON DELETE {}", action.to_sql()).as_str());
+        }
+        if let Some(action) = &self.on_update {
+            sql.push_str(format!(" ON UPDATE {}", action.to_sql()).as_str());
+        }
+        sql
+    }
+
+    /// get generated table constraint
+    pub fn to_sql(&self, dialect: Option<Dialect>) -> String {
+        format!(
+            "FOREIGN KEY ({}) {}",
+            quote_list(dialect, &self.columns),
+            self.references_sql(dialect)
+        )
+    }
+}
+
+/// generated column value
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Generated {
+    /// `GENERATED ALWAYS|BY DEFAULT AS IDENTITY`
+    Identity { always: bool },
+    /// `GENERATED ALWAYS AS (expr) STORED|VIRTUAL`
+    Expr { expr: String, stored: bool },
+}
+
+/// ColumnDef
+///
+/// column definition used by [`crate::CreateTable::column`]
+/// ```rust
+/// use qorm::{create_table_item::{ColumnDef, ColumnType}, Bind, Dialect};
+///
+/// let column = ColumnDef::new("is_done", ColumnType::Boolean)
+///     .not_null()
+///     .default(Bind::Bool(false))
+///     .clone();
+/// assert_eq!(
+///     column.to_sql(Some(Dialect::Sqlite)),
+///     r#""is_done" BOOLEAN NOT NULL DEFAULT 0"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnDef {
+    pub(crate) name: String,
+    pub(crate) column_type: ColumnType,
+    pub(crate) not_null: bool,
+    pub(crate) default: Option<Expr>,
+    pub(crate) primary_key: bool,
+    pub(crate) auto_increment: bool,
+    pub(crate) unique: bool,
+    pub(crate) check: Option<String>,
+    pub(crate) references: Option<ForeignKey>,
+    pub(crate) generated: Option<Generated>,
+}
+
+impl ColumnDef {
+    /// Initialize nullable column
+    pub fn new(name: &str, column_type: ColumnType) -> Self {
+        Self {
+            name: name.to_string(),
+            column_type,
+            not_null: false,
+            default: None,
+            primary_key: false,
+            auto_increment: false,
+            unique: false,
+            check: None,
+            references: None,
+            generated: None,
+        }
+    }
+
+    /// column name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// column type
+    pub fn column_type(&self) -> &ColumnType {
+        &self.column_type
+    }
+
+    /// `NOT NULL`
+    pub fn not_null(&mut self) -> &mut Self {
+        self.not_null = true;
+        self
+    }
+
+    /// `DEFAULT value`, value is inlined as dialect literal
+    pub fn default(&mut self, value: Bind) -> &mut Self {
+        self.default = Some(Expr::value(value));
+        self
+    }
+
+    /// `DEFAULT raw`, e.g. `CURRENT_TIMESTAMP`
+    pub fn default_raw(&mut self, raw: &str) -> &mut Self {
+        self.default = Some(Expr::raw(raw));
+        self
+    }
+
+    /// `PRIMARY KEY`
+    pub fn primary_key(&mut self) -> &mut Self {
+        self.primary_key = true;
+        self
+    }
+
+    /// auto increment integer column, `SERIAL` on postgres, `AUTO_INCREMENT` on mysql
+    /// and `INTEGER PRIMARY KEY AUTOINCREMENT` on sqlite
+    /// ```rust
+    /// use qorm::{create_table_item::{ColumnDef, ColumnType}, Dialect};
+    ///
+    /// let mut column = ColumnDef::new("id", ColumnType::BigInt);
+    /// column.primary_key().auto_increment();
+    /// assert_eq!(column.to_sql(Some(Dialect::Postgres)), r#""id" BIGSERIAL PRIMARY KEY"#);
+    /// assert_eq!(
+    ///     column.to_sql(Some(Dialect::MySql)),
+    ///     "`id` BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY"
+    /// );
+    /// assert_eq!(
+    ///     column.to_sql(Some(Dialect::Sqlite)),
+    ///     r#""id" INTEGER PRIMARY KEY AUTOINCREMENT"#
+    /// );
+    /// ```
+    pub fn auto_increment(&mut self) -> &mut Self {
+        self.auto_increment = true;
+        self
+    }
+
+    /// `UNIQUE`
+    pub fn unique(&mut self) -> &mut Self {
+        self.unique = true;
+        self
+    }
+
+    /// `CHECK (raw)`
+    pub fn check(&mut self, raw: &str) -> &mut Self {
+        self.check = Some(raw.to_string());
+        self
+    }
+
+    /// `REFERENCES table (column)`
+    /// ```rust
+    /// use qorm::create_table_item::{ColumnDef, ColumnType, ReferentialAction};
+    ///
+    /// let mut column = ColumnDef::new("user_id", ColumnType::BigInt);
+    /// column
+    ///     .references("user", "id")
+    ///     .on_delete(ReferentialAction::SetNull);
+    /// assert_eq!(
+    ///     column.to_sql(None),
+    ///     r#"user_id BIGINT REFERENCES user (id) ON DELETE SET NULL"#
+    /// );
+    /// ```
+    pub fn references(&mut self, table: &str, column: &str) -> &mut Self {
+        self.references = Some(ForeignKey::new(
+            vec![self.name.as_str()],
+            table,
+            vec![column],
+        ));
+        self
+    }
+
+    /// `ON DELETE` action of [`ColumnDef::references`]
+    pub fn on_delete(&mut self, action: ReferentialAction) -> &mut Self {
+        if let Some(references) = self.references.as_mut() {
+            references.on_delete(action);
+        }
+        self
+    }
+
+    /// `ON UPDATE` action of [`ColumnDef::references`]
+    pub fn on_update(&mut self, action: ReferentialAction) -> &mut Self {
+        if let Some(references) = self.references.as_mut() {
+            references.on_update(action);
+        }
+        self
+    }
+
+    /// identity column, `GENERATED ALWAYS AS IDENTITY` or `GENERATED BY DEFAULT AS IDENTITY`,
+    /// supported on postgres only
+    pub fn identity(&mut self, always: bool) -> &mut Self {
+        self.generated = Some(Generated::Identity { always });
+        self
+    }
+
+    /// generated column, `GENERATED ALWAYS AS (raw) STORED` or `VIRTUAL`
+    pub fn generated(&mut self, raw: &str, stored: bool) -> &mut Self {
+        self.generated = Some(Generated::Expr {
+            expr: raw.to_string(),
+            stored,
+        });
+        self
+    }
+
+    /// check column definition against dialect
+    pub fn validate(&self, dialect: Option<Dialect>) -> Result<(), Error> {
+        if self.auto_increment && !self.column_type.is_integer() {
+            return Err(Error::Invalid(format!(
+                "auto increment column {} requires integer type",
+                self.name
+            )));
+        }
+        if self.auto_increment && self.generated.is_some() {
+            return Err(Error::Invalid(format!(
+                "auto increment column {} can not be generated",
+                self.name
+            )));
+        }
+        if self.default.is_some() && self.generated.is_some() {
+            return Err(Error::Invalid(format!(
+                "generated column {} can not have default",
+                self.name
+            )));
+        }
+        match (dialect, &self.generated) {
+            (Some(Dialect::MySql), Some(Generated::Identity { .. }))
+            | (Some(Dialect::Sqlite), Some(Generated::Identity { .. })) => {
+                return Err(Error::Unsupported {
+                    feature: "GENERATED AS IDENTITY".to_string(),
+                    dialect: dialect.unwrap(),
+                })
+            }
+            (Some(Dialect::Postgres), Some(Generated::Expr { stored: false, .. })) => {
+                return Err(Error::Unsupported {
+                    feature: "VIRTUAL generated column".to_string(),
+                    dialect: Dialect::Postgres,
+                })
+            }
+            _ => {}
+        }
+        if dialect == Some(Dialect::Sqlite) && self.auto_increment && !self.primary_key {
+            return Err(Error::Invalid(format!(
+                "AUTOINCREMENT column {} must be primary key",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// get generated column definition, column is not validated (see [`ColumnDef::validate`])
+    pub fn to_sql(&self, dialect: Option<Dialect>) -> String {
+        let mut sql = quote_identifier(dialect, &self.name);
+        // Type
+        let column_type = match (dialect, self.auto_increment, &self.column_type) {
+            (Some(Dialect::Postgres) | None, true, ColumnType::SmallInt) => {
+                "SMALLSERIAL".to_string()
+            }
+            (Some(Dialect::Postgres) | None, true, ColumnType::Integer) => "SERIAL".to_string(),
+            (Some(Dialect::Postgres) | None, true, ColumnType::BigInt) => "BIGSERIAL".to_string(),
+            // sqlite requires exactly INTEGER PRIMARY KEY
+            (Some(Dialect::Sqlite), true, _) => "INTEGER".to_string(),
+            (_, _, column_type) => column_type.to_sql(dialect),
+        };
+        sql.push_str(format!(" {}", column_type).as_str());
+        // Generated
+        match &self.generated {
+            Some(Generated::Identity { always: true }) => {
+                sql.push_str(" GENERATED ALWAYS AS IDENTITY")
+            }
+            Some(Generated::Identity { always: false }) => {
+                sql.push_str(" GENERATED BY DEFAULT AS IDENTITY")
+            }
+            Some(Generated::Expr { expr, stored }) => sql.push_str(
+                format!(
+                    " GENERATED ALWAYS AS ({}) {}",
+                    expr,
+                    if *stored { "STORED" } else { "VIRTUAL" }
+                )
+                .as_str(),
+            ),
+            None => {}
+        }
+        // mysql primary key column is implicitly not null, keep it explicit next to AUTO_INCREMENT
+        let mysql_auto_increment = dialect == Some(Dialect::MySql) && self.auto_increment;
+        if self.not_null || mysql_auto_increment {
+            sql.push_str(" NOT NULL");
+        }
+        if let Some(default) = &self.default {
+            let value = default.to_sql(dialect, &mut |bind| bind.to_literal(dialect));
+            sql.push_str(format!(" DEFAULT {}", value).as_str());
+        }
+        if mysql_auto_increment {
+            sql.push_str(" AUTO_INCREMENT");
+        }
+        if self.primary_key {
+            sql.push_str(" PRIMARY KEY");
+        }
+        if dialect == Some(Dialect::Sqlite) && self.auto_increment {
+            sql.push_str(" AUTOINCREMENT");
+        }
+        if self.unique {
+            sql.push_str(" UNIQUE");
+        }
+        if let Some(check) = &self.check {
+            sql.push_str(format!(" CHECK ({})", check).as_str());
+        }
+        // mysql parses but ignores inline references, it's rendered as table constraint instead
+        if let Some(references) = &self.references {
+            if dialect != Some(Dialect::MySql) {
+                sql.push_str(format!(" {}", references.references_sql(dialect)).as_str());
+            }
+        }
+        sql
+    }
+}
+
+/// quoted, comma separated identifiers
+pub(crate) fn quote_list(dialect: Option<Dialect>, identifiers: &[String]) -> String {
+    identifiers
+        .iter()
+        .map(|f| quote_identifier(dialect, f))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
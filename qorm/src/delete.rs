@@ -1,24 +1,31 @@
-use crate::{delete_item::DeleteConfig, where_item::Or, Bind};
+use crate::{
+    bind::{debug_sql, DEBUG_PLACEHOLDER},
+    delete_item::DeleteConfig,
+    dialect::{quote_marked, Dialect},
+    error::Error,
+    scope::{condition_raws, guard, validate_raw, Scope},
+    table::TableSchema,
+    where_item::{Condition, Or},
+    Bind,
+};
 
 fn is_index(pattern: String) -> bool {
     pattern.contains("%d")
 }
 
-#[derive(Clone, Debug)]
-struct WhereInternal {
-    pub column: String,
-    pub operator: String,
-    pub value: Bind,
-}
-
 /// sql delete builder
+#[derive(Clone)]
 pub struct Delete {
     pub table_name: String,
     config: DeleteConfig,
+    custom_config: bool,
+    dialect: Option<Dialect>,
     binds: Vec<Bind>,
     bind_index: Option<i32>,
-    where_and: Option<Vec<WhereInternal>>,
-    where_or: Option<Vec<Vec<WhereInternal>>>,
+    where_and: Option<Vec<Condition>>,
+    where_or: Option<Vec<Vec<Condition>>>,
+    scope: Option<Scope>,
+    unscoped: bool,
 }
 
 impl Delete {
@@ -55,6 +62,7 @@ impl Delete {
     /// }
     /// ```
     pub fn new(table_name: &str, config: Option<DeleteConfig>) -> Self {
+        let custom_config = config.is_some();
         let config_select = match config {
             Some(data) => data,
             None => DeleteConfig {
@@ -66,8 +74,12 @@ impl Delete {
         Self {
             table_name: table_name.to_string(),
             config: config_select.clone(),
+            custom_config,
+            dialect: None,
             where_and: None,
             where_or: None,
+            scope: None,
+            unscoped: false,
             binds: vec![],
             bind_index: match bind_index {
                 true => Some(config_select.start.unwrap()),
@@ -76,6 +88,60 @@ impl Delete {
         }
     }
 
+    /// Initialize Delete builder from [`TableSchema`]
+    /// ```rust
+    /// use qorm::{table::{Column, TableSchema}, Bind, Delete};
+    ///
+    /// struct User;
+    ///
+    /// impl User {
+    ///     const ID: Column = Column::new("user", "id");
+    /// }
+    ///
+    /// impl TableSchema for User {
+    ///     const TABLE: &'static str = "user";
+    ///     const COLUMNS: &'static [Column] = &[Self::ID];
+    /// }
+    ///
+    /// let mut builder = Delete::from_table::<User>(None);
+    /// builder.wheres(&User::ID, "=", Bind::Int(1));
+    /// assert_eq!(builder.to_sql(), "DELETE FROM user WHERE id = ?");
+    /// ```
+    pub fn from_table<T: TableSchema>(config: Option<DeleteConfig>) -> Self {
+        Self::new(T::TABLE, config)
+    }
+
+    /// set sql dialect, placeholder is switched to dialect default (see [`Dialect::placeholder`])
+    /// unless config was passed on initialize
+    /// ```rust
+    /// use qorm::{Bind, Dialect, Delete};
+    ///
+    /// let mut builder = Delete::new("user", None);
+    /// builder.dialect(Dialect::Postgres);
+    /// builder.wheres("id", "=", Bind::Int(1));
+    /// assert_eq!(builder.to_sql(), "DELETE FROM user WHERE id = $1");
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        // Placeholder of config passed on initialize is kept, default one follows dialect
+        if !self.custom_config {
+            let (placeholder, start) = dialect.placeholder();
+            self.bind_index = match is_index(placeholder.clone()) {
+                true => start,
+                false => None,
+            };
+            self.config.placeholder = placeholder;
+            self.config.start = start;
+        }
+        self.dialect = Some(dialect);
+        self
+    }
+
+    fn reset_bind_index(&mut self) {
+        if self.bind_index.is_some() {
+            self.bind_index = self.config.start;
+        }
+    }
+
     fn gen_bind_key(&mut self) -> String {
         if self.bind_index.is_some() {
             let index = self.bind_index.unwrap();
@@ -104,19 +170,11 @@ impl Delete {
     /// }
     /// ```
     pub fn wheres(&mut self, column: &str, operator: &str, value: Bind) -> &mut Self {
-        if self.where_and.is_none() {
-            self.where_and = Some(vec![WhereInternal {
-                column: column.to_string(),
-                operator: operator.to_string(),
-                value,
-            }])
-        } else {
-            self.where_and.as_mut().unwrap().push(WhereInternal {
-                column: column.to_string(),
-                operator: operator.to_string(),
-                value,
-            });
-        }
+        self.where_and.get_or_insert_with(Vec::new).push(Condition {
+            column: column.to_string(),
+            operator: operator.to_string(),
+            value,
+        });
         self
     }
 
@@ -170,27 +228,16 @@ impl Delete {
     /// }
     /// ```
     pub fn where_or(&mut self, wheres: Vec<Or>) -> &mut Self {
-        if self.where_or.is_none() {
-            self.where_or = Some(vec![wheres
+        self.where_or.get_or_insert_with(Vec::new).push(
+            wheres
                 .iter()
-                .map(|f| WhereInternal {
+                .map(|f| Condition {
                     column: f.column.to_string(),
                     operator: f.operator.to_string(),
                     value: f.value.clone(),
                 })
-                .collect()]);
-        } else {
-            self.where_or.as_mut().unwrap().push(
-                wheres
-                    .iter()
-                    .map(|f| WhereInternal {
-                        column: f.column.to_string(),
-                        operator: f.operator.to_string(),
-                        value: f.value.clone(),
-                    })
-                    .collect(),
-            );
-        }
+                .collect(),
+        );
         self
     }
 
@@ -228,6 +275,48 @@ impl Delete {
         }
     }
 
+    /// add conditions of [`Scope`] filters on render, ignored when builder is [`Delete::unscoped`]
+    /// ```rust
+    /// use qorm::{scope::Scope, Bind, Delete};
+    ///
+    /// let mut scope = Scope::new();
+    /// scope.filter("todo", "tenant_id", "=", Bind::Int(7));
+    ///
+    /// let mut builder = Delete::new("todo", None);
+    /// builder.wheres("id", "=", Bind::Int(1)).scope(&scope);
+    /// assert_eq!(builder.to_sql(), "DELETE FROM todo WHERE tenant_id = ? AND id = ?");
+    /// ```
+    pub fn scope(&mut self, scope: &Scope) -> &mut Self {
+        if !self.unscoped {
+            self.scope = Some(scope.clone());
+        }
+        self
+    }
+
+    /// remove scope and ignore scope applied later, for admin query
+    pub fn unscoped(&mut self) -> &mut Self {
+        self.unscoped = true;
+        self.scope = None;
+        self
+    }
+
+    /// where conditions with scope conditions of table first
+    fn scoped_where(&self) -> Option<Vec<Condition>> {
+        let mut where_and = match &self.scope {
+            Some(scope) => scope.conditions(&self.table_name, None),
+            None => return self.where_and.clone(),
+        };
+        // Raw subquery can not be scoped, match no row instead of rows outside scope
+        if self.validate().is_err() {
+            where_and.insert(0, guard());
+        }
+        where_and.extend(self.where_and.clone().unwrap_or_default());
+        match where_and.is_empty() {
+            true => None,
+            false => Some(where_and),
+        }
+    }
+
     /// get generated sql query
     /// ```rust
     /// use qorm::{Bind, Delete};
@@ -237,6 +326,10 @@ impl Delete {
     /// ```
     pub fn to_sql(&mut self) -> String {
         self.binds = vec![];
+        self.reset_bind_index();
+        // Scope conditions are rendered as leading where conditions
+        let where_and = self.where_and.clone();
+        self.where_and = self.scoped_where();
         // DELETE
         let mut sql = format!("DELETE FROM {}", self.table_name).to_string();
 
@@ -253,7 +346,8 @@ impl Delete {
         }
         self.parse_where_or(&mut sql);
 
-        sql
+        self.where_and = where_and;
+        quote_marked(self.dialect, sql)
     }
 
     /// get generated sql query and it's bind
@@ -274,4 +368,58 @@ impl Delete {
         let sql = self.to_sql();
         (sql, self.binds.clone())
     }
+
+    /// check builder, scoped builder fails on raw subquery in where column,
+    /// scope filters are not applied inside it
+    /// ```rust
+    /// use qorm::{scope::Scope, Bind, Delete};
+    ///
+    /// let mut scope = Scope::new();
+    /// scope.filter("todo", "tenant_id", "=", Bind::Int(7));
+    ///
+    /// let mut builder = Delete::new("todo", None);
+    /// builder.wheres("EXISTS (SELECT 1 FROM tag WHERE tag.todo_id = todo.id)", "=", Bind::Bool(true));
+    /// assert!(builder.validate().is_ok());
+    /// builder.scope(&scope);
+    /// assert!(builder.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.scope.is_none() {
+            return Ok(());
+        }
+        let mut raws = vec![self.table_name.clone()];
+        raws.extend(condition_raws(self.where_and.iter().flatten()));
+        raws.extend(condition_raws(self.where_or.iter().flatten().flatten()));
+        validate_raw(raws)
+    }
+
+    /// validate builder then get generated sql query and it's bind
+    pub fn try_to_sql(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        self.validate()?;
+        Ok(self.to_sql_with_bind())
+    }
+
+    /// get generated sql query with binds inlined as dialect literals, for debugging only,
+    /// output starts with comment marking it as not for execution
+    /// ```rust
+    /// use qorm::{Bind, Delete};
+    ///
+    /// let mut builder = Delete::new("todo", None);
+    /// builder.wheres("id", "=", Bind::Int(1));
+    /// assert_eq!(
+    ///     builder.to_debug_sql(),
+    ///     "-- debug sql with inlined binds, not for execution\nDELETE FROM todo WHERE id = 1"
+    /// );
+    /// ```
+    pub fn to_debug_sql(&mut self) -> String {
+        let config = self.config.clone();
+        let bind_index = self.bind_index;
+        self.config.placeholder = DEBUG_PLACEHOLDER.to_string();
+        self.config.start = Some(0);
+        self.bind_index = Some(0);
+        let (sql, binds) = self.to_sql_with_bind();
+        self.config = config;
+        self.bind_index = bind_index;
+        debug_sql(&sql, &binds, self.dialect)
+    }
 }
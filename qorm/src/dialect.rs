@@ -0,0 +1,123 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::Error;
+
+/// sql dialect, used to validate dialect specific syntax
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Dialect {
+    MySql,
+    Postgres,
+    Sqlite,
+}
+
+impl Dialect {
+    /// default placeholder of dialect and its start index, `$1` on postgres and `?` otherwise
+    /// ```rust
+    /// use qorm::Dialect;
+    ///
+    /// assert_eq!(Dialect::Postgres.placeholder(), ("$%d".to_string(), Some(1)));
+    /// assert_eq!(Dialect::MySql.placeholder(), ("?".to_string(), Some(1)));
+    /// ```
+    pub fn placeholder(&self) -> (String, Option<i32>) {
+        match self {
+            Dialect::Postgres => ("$%d".to_string(), Some(1)),
+            Dialect::MySql | Dialect::Sqlite => ("?".to_string(), Some(1)),
+        }
+    }
+
+    /// quote identifier, dot separated parts are quoted separately and `*` is kept as is
+    /// ```rust
+    /// use qorm::Dialect;
+    ///
+    /// assert_eq!(Dialect::Postgres.quote_identifier("u.name"), r#""u"."name""#);
+    /// assert_eq!(Dialect::MySql.quote_identifier("u.*"), "`u`.*");
+    /// assert_eq!(Dialect::Sqlite.quote_identifier(r#"na"me"#), r#""na""me""#);
+    /// ```
+    pub fn quote_identifier(&self, identifier: &str) -> String {
+        quote_identifier(Some(*self), identifier)
+    }
+}
+
+/// quote identifier for optional dialect, ansi double quote is a string literal on mysql.
+/// when dialect is empty plain name part is kept as is and any other part is quoted with ansi double quote
+pub(crate) fn quote_identifier(dialect: Option<Dialect>, identifier: &str) -> String {
+    identifier
+        .split('.')
+        .map(|part| match part {
+            "*" => part.to_string(),
+            _ if dialect.is_none() && is_plain(part) => part.to_string(),
+            _ => quote_part(dialect, part),
+        })
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+/// `[A-Za-z_][A-Za-z0-9_]*`
+fn is_plain(part: &str) -> bool {
+    part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// quote single identifier part, ansi double quote when dialect is empty
+fn quote_part(dialect: Option<Dialect>, part: &str) -> String {
+    let quote = match dialect {
+        Some(Dialect::MySql) => "`",
+        _ => "\"",
+    };
+    format!(
+        "{}{}{}",
+        quote,
+        part.replace(quote, format!("{}{}", quote, quote).as_str()),
+        quote
+    )
+}
+
+/// wraps identifier read quoted from parsed sql, it's quoted with builder dialect on render
+pub(crate) const IDENTIFIER_MARK: char = '\u{2}';
+
+/// quote identifiers wrapped in [`IDENTIFIER_MARK`] with dialect
+pub(crate) fn quote_marked(dialect: Option<Dialect>, sql: String) -> String {
+    if !sql.contains(IDENTIFIER_MARK) {
+        return sql;
+    }
+    sql.split(IDENTIFIER_MARK)
+        .enumerate()
+        .map(|(idx, part)| match idx % 2 {
+            1 => quote_part(dialect, part),
+            _ => part.to_string(),
+        })
+        .collect()
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dialect::MySql => write!(f, "MySql"),
+            Dialect::Postgres => write!(f, "Postgres"),
+            Dialect::Sqlite => write!(f, "Sqlite"),
+        }
+    }
+}
+
+/// parse dialect name, case insensitive
+/// ```rust
+/// use qorm::Dialect;
+///
+/// assert_eq!("postgres".parse::<Dialect>(), Ok(Dialect::Postgres));
+/// assert_eq!("MySql".parse::<Dialect>(), Ok(Dialect::MySql));
+/// assert!("oracle".parse::<Dialect>().is_err());
+/// ```
+impl FromStr for Dialect {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "mysql" => Ok(Dialect::MySql),
+            "postgres" | "postgresql" => Ok(Dialect::Postgres),
+            "sqlite" => Ok(Dialect::Sqlite),
+            _ => Err(Error::Invalid(format!("unknown dialect {}", value))),
+        }
+    }
+}
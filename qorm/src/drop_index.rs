@@ -0,0 +1,141 @@
+use crate::{
+    dialect::{quote_identifier, Dialect},
+    error::Error,
+};
+
+/// sql drop index builder
+#[derive(Clone, Debug)]
+pub struct DropIndex {
+    pub index_name: String,
+    dialect: Option<Dialect>,
+    table_name: Option<String>,
+    if_exists: bool,
+    concurrently: bool,
+    cascade: bool,
+}
+
+impl DropIndex {
+    /// Initialize DropIndex builder
+    /// ```rust
+    /// use qorm::{Dialect, DropIndex};
+    ///
+    /// let mut builder = DropIndex::new("todo_user_id_idx");
+    /// builder.dialect(Dialect::Postgres).concurrently().if_exists();
+    /// assert_eq!(
+    ///     builder.to_sql(),
+    ///     r#"DROP INDEX CONCURRENTLY IF EXISTS "todo_user_id_idx""#
+    /// );
+    /// ```
+    pub fn new(index_name: &str) -> Self {
+        Self {
+            index_name: index_name.to_string(),
+            dialect: None,
+            table_name: None,
+            if_exists: false,
+            concurrently: false,
+            cascade: false,
+        }
+    }
+
+    /// set sql dialect, used for quoting, syntax and validation
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// table owning the index, `ON table`. required on mysql, ignored on other dialects
+    /// ```rust
+    /// use qorm::{Dialect, DropIndex};
+    ///
+    /// let mut builder = DropIndex::new("todo_user_id_idx");
+    /// builder.dialect(Dialect::MySql).table("todo");
+    /// assert_eq!(builder.to_sql(), "DROP INDEX `todo_user_id_idx` ON `todo`");
+    /// ```
+    pub fn table(&mut self, table_name: &str) -> &mut Self {
+        self.table_name = Some(table_name.to_string());
+        self
+    }
+
+    /// `IF EXISTS`, not supported on mysql
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// `CONCURRENTLY`, postgres only
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// `CASCADE`, drop dependent objects. postgres only
+    pub fn cascade(&mut self) -> &mut Self {
+        self.cascade = true;
+        self
+    }
+
+    /// check builder against its dialect
+    /// ```rust
+    /// use qorm::{Dialect, DropIndex, Error};
+    ///
+    /// let mut builder = DropIndex::new("todo_user_id_idx");
+    /// builder.dialect(Dialect::MySql);
+    /// assert_eq!(
+    ///     builder.validate(),
+    ///     Err(Error::Invalid("DROP INDEX todo_user_id_idx requires table on mysql".to_string()))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        let dialect = match self.dialect {
+            Some(dialect) => dialect,
+            None => return Ok(()),
+        };
+        let unsupported = |feature: &str| Error::Unsupported {
+            feature: feature.to_string(),
+            dialect,
+        };
+        if dialect == Dialect::MySql && self.table_name.is_none() {
+            return Err(Error::Invalid(format!(
+                "DROP INDEX {} requires table on mysql",
+                self.index_name
+            )));
+        }
+        if self.if_exists && dialect == Dialect::MySql {
+            return Err(unsupported("DROP INDEX IF EXISTS"));
+        }
+        if self.concurrently && dialect != Dialect::Postgres {
+            return Err(unsupported("DROP INDEX CONCURRENTLY"));
+        }
+        if self.cascade && dialect != Dialect::Postgres {
+            return Err(unsupported("DROP INDEX CASCADE"));
+        }
+        Ok(())
+    }
+
+    /// validate builder then get generated sql query
+    pub fn try_to_sql(&self) -> Result<String, Error> {
+        self.validate()?;
+        Ok(self.to_sql())
+    }
+
+    /// get generated sql query
+    ///
+    /// builder is not validated, use [`DropIndex::try_to_sql`] to get [`DropIndex::validate`] error
+    pub fn to_sql(&self) -> String {
+        let mut sql = "DROP INDEX".to_string();
+        if self.concurrently {
+            sql.push_str(" CONCURRENTLY");
+        }
+        if self.if_exists {
+            sql.push_str(" IF EXISTS");
+        }
+        sql.push_str(format!(" {}", quote_identifier(self.dialect, &self.index_name)).as_str());
+        if let (Some(Dialect::MySql), Some(table_name)) = (self.dialect, &self.table_name) {
+            sql.push_str(format!(" ON {}", quote_identifier(self.dialect, table_name)).as_str());
+        }
+        if self.cascade {
+            sql.push_str(" CASCADE");
+        }
+        sql
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    dialect::{quote_identifier, Dialect},
+    error::Error,
+};
+
+/// sql drop table builder
+#[derive(Clone, Debug)]
+pub struct DropTable {
+    pub table_name: String,
+    dialect: Option<Dialect>,
+    if_exists: bool,
+    cascade: bool,
+}
+
+impl DropTable {
+    /// Initialize DropTable builder
+    /// ```rust
+    /// use qorm::{Dialect, DropTable};
+    ///
+    /// let mut builder = DropTable::new("todo");
+    /// builder.dialect(Dialect::Postgres).if_exists().cascade();
+    /// assert_eq!(builder.to_sql(), r#"DROP TABLE IF EXISTS "todo" CASCADE"#);
+    /// ```
+    pub fn new(table_name: &str) -> Self {
+        Self {
+            table_name: table_name.to_string(),
+            dialect: None,
+            if_exists: false,
+            cascade: false,
+        }
+    }
+
+    /// set sql dialect, used for quoting and validation
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// `IF EXISTS`
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// `CASCADE`, drop dependent objects. not supported on sqlite
+    pub fn cascade(&mut self) -> &mut Self {
+        self.cascade = true;
+        self
+    }
+
+    /// check builder against its dialect
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.cascade && self.dialect == Some(Dialect::Sqlite) {
+            return Err(Error::Unsupported {
+                feature: "DROP TABLE CASCADE".to_string(),
+                dialect: Dialect::Sqlite,
+            });
+        }
+        Ok(())
+    }
+
+    /// validate builder then get generated sql query
+    pub fn try_to_sql(&self) -> Result<String, Error> {
+        self.validate()?;
+        Ok(self.to_sql())
+    }
+
+    /// get generated sql query
+    ///
+    /// builder is not validated, use [`DropTable::try_to_sql`] to get [`DropTable::validate`] error
+    pub fn to_sql(&self) -> String {
+        let mut sql = "DROP TABLE".to_string();
+        if self.if_exists {
+            sql.push_str(" IF EXISTS");
+        }
+        sql.push_str(format!(" {}", quote_identifier(self.dialect, &self.table_name)).as_str());
+        if self.cascade {
+            sql.push_str(" CASCADE");
+        }
+        sql
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt;
+
+use crate::dialect::Dialect;
+
+/// qorm error
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// syntax is not supported by the builder dialect
+    Unsupported { feature: String, dialect: Dialect },
+    /// builder state can not be rendered into valid sql
+    Invalid(String),
+    /// row value can not be decoded
+    Decode(String),
+    /// query returns no row
+    RowNotFound,
+    /// database driver error
+    Database(String),
+    /// applied migration was edited, its checksum no longer matches the recorded one
+    Checksum { version: i64, name: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unsupported { feature, dialect } => {
+                write!(f, "{} is not supported on {}", feature, dialect)
+            }
+            Error::Invalid(message) => write!(f, "{}", message),
+            Error::Decode(message) => write!(f, "{}", message),
+            Error::RowNotFound => write!(f, "no row returned by query"),
+            Error::Database(message) => write!(f, "{}", message),
+            Error::Checksum { version, name } => write!(
+                f,
+                "migration {} {} was changed after it was applied",
+                version, name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,65 @@
+use crate::{error::Error, query::Query, row::FromRow, transaction::Transaction};
+
+/// Executor
+///
+/// run qorm builders on database connection, implemented by `sqlite` feature and [`crate::Transaction`].
+/// `sqlx`, `postgres` and `mysql` features render statements for async drivers and do not implement it,
+/// transaction there is run with [`crate::transaction_item::TransactionState`]
+pub trait Executor {
+    /// execute query, returns number of affected rows
+    fn execute<Q: Query>(&mut self, query: &mut Q) -> Result<u64, Error>;
+
+    /// fetch every row
+    fn fetch_all<T: FromRow, Q: Query>(&mut self, query: &mut Q) -> Result<Vec<T>, Error>;
+
+    /// fetch first row, if any
+    fn fetch_optional<T: FromRow, Q: Query>(&mut self, query: &mut Q) -> Result<Option<T>, Error>;
+
+    /// fetch first row, [`Error::RowNotFound`] when query returns no row
+    fn fetch_one<T: FromRow, Q: Query>(&mut self, query: &mut Q) -> Result<T, Error> {
+        match self.fetch_optional(query)? {
+            Some(row) => Ok(row),
+            None => Err(Error::RowNotFound),
+        }
+    }
+
+    /// begin transaction, see [`Transaction`]
+    fn begin(&mut self) -> Result<Transaction<'_, Self>, Error>
+    where
+        Self: Sized,
+    {
+        Transaction::begin(self, 0)
+    }
+
+    /// run closure inside transaction (or savepoint when called on transaction),
+    /// commit when closure returns `Ok` and rollback when it returns `Err`.
+    /// named apart from `rusqlite::Connection::transaction`, so it's callable as method
+    /// ```rust
+    /// use qorm::{Bind, Error, Executor, Insert};
+    ///
+    /// fn create_user<E: Executor>(conn: &mut E) -> Result<u64, Error> {
+    ///     conn.run_transaction(|tx| {
+    ///         let mut builder = Insert::new("user", None);
+    ///         builder.values(vec![("username", Bind::String("foo".to_string()))]);
+    ///         tx.execute(&mut builder)
+    ///     })
+    /// }
+    /// ```
+    fn run_transaction<T, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        Self: Sized,
+        F: FnOnce(&mut Transaction<'_, Self>) -> Result<T, Error>,
+    {
+        let mut tx = self.begin()?;
+        match f(&mut tx) {
+            Ok(value) => {
+                tx.commit()?;
+                Ok(value)
+            }
+            Err(err) => {
+                tx.rollback()?;
+                Err(err)
+            }
+        }
+    }
+}
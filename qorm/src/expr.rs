@@ -0,0 +1,131 @@
+use crate::{
+    dialect::{quote_identifier, Dialect},
+    Bind,
+};
+
+/// Expr
+///
+/// sql expression with quoted identifiers and bound values
+/// ```rust
+/// use qorm::{expr::Expr, Bind, Select};
+///
+/// let mut builder = Select::new("todo", Some("t"), None);
+/// builder.select_as(
+///     Expr::coalesce(vec![Expr::column("t.name"), Expr::value(Bind::String("untitled".to_string()))]),
+///     "name",
+/// );
+/// let (sql, binds) = builder.to_sql_with_bind();
+/// assert_eq!(sql, r#"SELECT COALESCE(t.name, ?) AS name FROM todo t"#);
+/// assert_eq!(binds, vec![Bind::String("untitled".to_string())]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expr {
+    /// raw sql, rendered as is
+    Raw(String),
+    /// column identifier, quoted per dialect
+    Column(String),
+    /// bound value
+    Value(Bind),
+    /// function call
+    Function {
+        name: String,
+        distinct: bool,
+        args: Vec<Expr>,
+    },
+}
+
+impl Expr {
+    /// raw sql expression
+    pub fn raw(raw: &str) -> Self {
+        Expr::Raw(raw.to_string())
+    }
+
+    /// column identifier
+    pub fn column(column: &str) -> Self {
+        Expr::Column(column.to_string())
+    }
+
+    /// bound value
+    pub fn value(value: Bind) -> Self {
+        Expr::Value(value)
+    }
+
+    fn function(name: &str, distinct: bool, args: Vec<Expr>) -> Self {
+        Expr::Function {
+            name: name.to_string(),
+            distinct,
+            args,
+        }
+    }
+
+    /// `COUNT(*)`
+    pub fn count_all() -> Self {
+        Self::function("COUNT", false, vec![Expr::raw("*")])
+    }
+
+    /// `COUNT(expr)`
+    pub fn count(expr: Expr) -> Self {
+        Self::function("COUNT", false, vec![expr])
+    }
+
+    /// `COUNT(DISTINCT expr)`
+    pub fn count_distinct(expr: Expr) -> Self {
+        Self::function("COUNT", true, vec![expr])
+    }
+
+    /// `SUM(expr)`
+    pub fn sum(expr: Expr) -> Self {
+        Self::function("SUM", false, vec![expr])
+    }
+
+    /// `AVG(expr)`
+    pub fn avg(expr: Expr) -> Self {
+        Self::function("AVG", false, vec![expr])
+    }
+
+    /// `MIN(expr)`
+    pub fn min(expr: Expr) -> Self {
+        Self::function("MIN", false, vec![expr])
+    }
+
+    /// `MAX(expr)`
+    pub fn max(expr: Expr) -> Self {
+        Self::function("MAX", false, vec![expr])
+    }
+
+    /// `COALESCE(expr, ...)`
+    pub fn coalesce(exprs: Vec<Expr>) -> Self {
+        Self::function("COALESCE", false, exprs)
+    }
+
+    /// render expression, `bind` is called for every bound value and returns its placeholder
+    pub(crate) fn to_sql(
+        &self,
+        dialect: Option<Dialect>,
+        bind: &mut dyn FnMut(&Bind) -> String,
+    ) -> String {
+        match self {
+            Expr::Raw(raw) => raw.clone(),
+            Expr::Column(column) => quote_identifier(dialect, column),
+            Expr::Value(Bind::Null) => "NULL".to_string(),
+            Expr::Value(value) => bind(value),
+            Expr::Function {
+                name,
+                distinct,
+                args,
+            } => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.to_sql(dialect, bind))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                if *distinct {
+                    format!("{}(DISTINCT {})", name, args)
+                } else {
+                    format!("{}({})", name, args)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,366 @@
+/// FormatConfig
+///
+/// config of [`format_sql`]
+#[derive(Clone)]
+pub struct FormatConfig {
+    /// indentation of one level
+    pub indent: String,
+    /// upper-case keywords
+    pub uppercase: bool,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            indent: "  ".to_string(),
+            uppercase: true,
+        }
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "ALL",
+    "AND",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CONFLICT",
+    "CROSS",
+    "CURRENT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FOLLOWING",
+    "FOR",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "GROUPS",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LATERAL",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LOCKED",
+    "NATURAL",
+    "NO",
+    "NOT",
+    "NOTHING",
+    "NOWAIT",
+    "NULL",
+    "OF",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "PRECEDING",
+    "RANGE",
+    "RECURSIVE",
+    "RETURNING",
+    "RIGHT",
+    "ROW",
+    "ROWS",
+    "SELECT",
+    "SET",
+    "SHARE",
+    "SKIP",
+    "THEN",
+    "TRUE",
+    "UNBOUNDED",
+    "UNION",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+const JOIN_PREFIX: &[&str] = &[
+    "LEFT", "RIGHT", "INNER", "OUTER", "FULL", "CROSS", "NATURAL",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Comment(String),
+    Open,
+    Close,
+    Comma,
+    Other(String),
+}
+
+impl Token {
+    /// upper-cased keyword, `None` when token is not keyword
+    fn keyword(&self) -> Option<String> {
+        match self {
+            Token::Word(word) => {
+                let upper = word.to_uppercase();
+                match KEYWORDS.contains(&upper.as_str()) {
+                    true => Some(upper),
+                    false => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// split sql into tokens, each token is paired with whether it was preceded by whitespace
+fn tokenize(sql: &str) -> Vec<(Token, bool)> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = vec![];
+    let mut space = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        let token = if c.is_whitespace() {
+            space = true;
+            i += 1;
+            continue;
+        } else if c == '-' && chars.get(i + 1) == Some(&'-') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            Token::Comment(
+                chars[start..i]
+                    .iter()
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string(),
+            )
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i = (i + 2).min(chars.len());
+            Token::Comment(chars[start..i].iter().collect())
+        } else if c == '\'' || c == '"' || c == '`' {
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == c {
+                    // doubled quote is escaped quote
+                    if chars.get(i + 1) == Some(&c) {
+                        i += 2;
+                        continue;
+                    }
+                    i += 1;
+                    break;
+                }
+                i += 1;
+            }
+            Token::Quoted(chars[start..i].iter().collect())
+        } else if c == '(' {
+            i += 1;
+            Token::Open
+        } else if c == ')' {
+            i += 1;
+            Token::Close
+        } else if c == ',' {
+            i += 1;
+            Token::Comma
+        } else if is_word_char(c) {
+            while i < chars.len() && is_word_char(chars[i]) {
+                i += 1;
+            }
+            Token::Word(chars[start..i].iter().collect())
+        } else {
+            i += 1;
+            while c != '?'
+                && i < chars.len()
+                && !chars[i].is_whitespace()
+                && !is_word_char(chars[i])
+                && !"()',\"`?".contains(chars[i])
+            {
+                i += 1;
+            }
+            Token::Other(chars[start..i].iter().collect())
+        };
+        tokens.push((token, space));
+        space = false;
+    }
+    tokens
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$' || c == '.'
+}
+
+/// keyword starting new clause line
+fn is_clause(keyword: &str, prev: Option<&str>, next: Option<&str>) -> bool {
+    match keyword {
+        "SELECT" | "WHERE" | "HAVING" | "LIMIT" | "OFFSET" | "WINDOW" | "VALUES" | "SET"
+        | "RETURNING" | "UNION" | "INTERSECT" | "EXCEPT" | "WITH" | "INSERT" | "DELETE" => true,
+        "FROM" => prev != Some("DELETE"),
+        "UPDATE" => !matches!(prev, Some("FOR") | Some("KEY") | Some("DO")),
+        "GROUP" | "ORDER" => next == Some("BY"),
+        "JOIN" => !prev.is_some_and(|f| JOIN_PREFIX.contains(&f)),
+        "FOR" => matches!(
+            next,
+            Some("UPDATE") | Some("SHARE") | Some("NO") | Some("KEY")
+        ),
+        _ if JOIN_PREFIX.contains(&keyword) => {
+            matches!(next, Some("JOIN") | Some("OUTER"))
+                && !prev.is_some_and(|f| JOIN_PREFIX.contains(&f))
+        }
+        _ => false,
+    }
+}
+
+struct Writer<'a> {
+    config: &'a FormatConfig,
+    sql: String,
+    line_start: bool,
+}
+
+impl Writer<'_> {
+    fn newline(&mut self, level: usize) {
+        if self.line_start {
+            // already on empty line, only reset its indentation
+            let start = self.sql.rfind('\n').map(|f| f + 1).unwrap_or(0);
+            self.sql.truncate(start);
+        } else {
+            self.sql.push('\n');
+        }
+        self.sql.push_str(&self.config.indent.repeat(level));
+        self.line_start = true;
+    }
+
+    fn push(&mut self, value: &str, space: bool) {
+        if space && !self.line_start {
+            self.sql.push(' ');
+        }
+        self.sql.push_str(value);
+        self.line_start = false;
+    }
+}
+
+/// format sql query, each clause is placed on its own line, `AND`/`OR` of where clause and
+/// subqueries/CTEs are indented and keywords are upper-cased. tokens are never reordered
+/// so placeholder order is kept.
+/// ```rust
+/// use qorm::{format::format_sql, Bind, Select};
+///
+/// let mut builder = Select::new("todo", Some("t"), None);
+/// builder
+///     .select("t.id")
+///     .select("(select count(*) from comment c where c.todo_id = t.id) as total")
+///     .join(Some("LEFT"), "user u", "u.id = t.created_by")
+///     .wheres("t.is_done", "=", Bind::Bool(false))
+///     .wheres("t.created_by", "=", Bind::Int(1));
+/// assert_eq!(
+///     format_sql(&builder.to_sql(), None),
+///     "SELECT t.id, (
+///   SELECT count(*)
+///   FROM comment c
+///   WHERE c.todo_id = t.id
+/// ) AS total
+/// FROM todo t
+/// LEFT JOIN user u ON u.id = t.created_by
+/// WHERE t.is_done = ?
+///   AND t.created_by = ?"
+/// );
+/// ```
+pub fn format_sql(sql: &str, config: Option<FormatConfig>) -> String {
+    let config = config.unwrap_or_default();
+    let tokens = tokenize(sql);
+    let mut writer = Writer {
+        config: &config,
+        sql: String::new(),
+        line_start: true,
+    };
+    // true when parenthesis opens subquery
+    let mut stack: Vec<bool> = vec![];
+    let mut level = 0;
+    let mut between = false;
+    let mut prev: Option<&Token> = None;
+    let mut force_space = false;
+    for (idx, (token, space)) in tokens.iter().enumerate() {
+        let keyword = token.keyword();
+        let prev_keyword = prev.and_then(|f| f.keyword());
+        let next_keyword = tokens.get(idx + 1).and_then(|(f, _)| f.keyword());
+        let clause_level = stack.last().is_none_or(|f| *f);
+        let mut space = (*space || force_space) && prev != Some(&Token::Open);
+        force_space = false;
+        match token {
+            Token::Comment(comment) => {
+                if !writer.line_start {
+                    writer.newline(level);
+                }
+                writer.push(comment, false);
+                writer.newline(level);
+                continue;
+            }
+            Token::Open => {
+                let subquery = matches!(next_keyword.as_deref(), Some("SELECT") | Some("WITH"));
+                writer.push("(", space);
+                stack.push(subquery);
+                if subquery {
+                    level += 1;
+                }
+            }
+            Token::Close => match stack.pop() {
+                Some(true) => {
+                    level -= 1;
+                    writer.newline(level);
+                    writer.push(")", false);
+                }
+                _ => writer.push(")", false),
+            },
+            Token::Comma => {
+                writer.push(",", false);
+                force_space = true;
+            }
+            Token::Word(word) => {
+                let keyword = keyword.as_deref();
+                if let Some(keyword) = keyword {
+                    if clause_level
+                        && is_clause(keyword, prev_keyword.as_deref(), next_keyword.as_deref())
+                    {
+                        writer.newline(level);
+                        space = false;
+                    } else if clause_level && matches!(keyword, "AND" | "OR") && !between {
+                        writer.newline(level + 1);
+                        space = false;
+                    }
+                    match keyword {
+                        "BETWEEN" => between = true,
+                        "AND" => between = false,
+                        _ => {}
+                    }
+                }
+                match (keyword, config.uppercase) {
+                    (Some(keyword), true) => writer.push(keyword, space),
+                    _ => writer.push(word, space),
+                }
+            }
+            Token::Quoted(value) | Token::Other(value) => writer.push(value, space),
+        }
+        prev = Some(token);
+    }
+    writer.sql
+}
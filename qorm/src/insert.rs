@@ -1,4 +1,10 @@
-use crate::{insert_item::InsertConfig, table::Table, Bind};
+use crate::{
+    bind::{debug_sql, DEBUG_PLACEHOLDER},
+    dialect::{quote_marked, Dialect},
+    insert_item::InsertConfig,
+    table::{Table, TableSchema},
+    Bind,
+};
 
 fn is_index(pattern: String) -> bool {
     pattern.contains("%d")
@@ -7,9 +13,12 @@ fn is_index(pattern: String) -> bool {
 /// Insert
 ///
 /// qorm sql insert builder
+#[derive(Clone)]
 pub struct Insert {
     pub table_name: Table,
     config: InsertConfig,
+    custom_config: bool,
+    dialect: Option<Dialect>,
     bind_index: Option<i32>,
     binds: Vec<Bind>,
     insert_values: Option<Vec<(String, Bind)>>,
@@ -69,6 +78,7 @@ impl Insert {
     /// }
     /// ```
     pub fn new(table_name: &str, config: Option<InsertConfig>) -> Self {
+        let custom_config = config.is_some();
         let config_select = match config {
             Some(data) => data,
             None => InsertConfig {
@@ -83,6 +93,8 @@ impl Insert {
                 alias: None,
             },
             config: config_select.clone(),
+            custom_config,
+            dialect: None,
             bind_index: match bind_index {
                 true => Some(config_select.start.unwrap()),
                 false => None,
@@ -92,6 +104,60 @@ impl Insert {
         }
     }
 
+    /// Initialize Insert builder from [`TableSchema`]
+    /// ```rust
+    /// use qorm::{table::{Column, TableSchema}, Bind, Insert};
+    ///
+    /// struct User;
+    ///
+    /// impl User {
+    ///     const NAME: Column = Column::new("user", "name");
+    /// }
+    ///
+    /// impl TableSchema for User {
+    ///     const TABLE: &'static str = "user";
+    ///     const COLUMNS: &'static [Column] = &[Self::NAME];
+    /// }
+    ///
+    /// let mut builder = Insert::from_table::<User>(None);
+    /// builder.values(vec![(&User::NAME, Bind::String("foo".to_string()))]);
+    /// assert_eq!(builder.to_sql(), "INSERT INTO user (name) VALUES (?)");
+    /// ```
+    pub fn from_table<T: TableSchema>(config: Option<InsertConfig>) -> Self {
+        Self::new(T::TABLE, config)
+    }
+
+    /// set sql dialect, placeholder is switched to dialect default (see [`Dialect::placeholder`])
+    /// unless config was passed on initialize
+    /// ```rust
+    /// use qorm::{Bind, Dialect, Insert};
+    ///
+    /// let mut builder = Insert::new("user", None);
+    /// builder.dialect(Dialect::Postgres);
+    /// builder.values(vec![("username", Bind::String("foo".to_string()))]);
+    /// assert_eq!(builder.to_sql(), "INSERT INTO user (username) VALUES ($1)");
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        // Placeholder of config passed on initialize is kept, default one follows dialect
+        if !self.custom_config {
+            let (placeholder, start) = dialect.placeholder();
+            self.bind_index = match is_index(placeholder.clone()) {
+                true => start,
+                false => None,
+            };
+            self.config.placeholder = placeholder;
+            self.config.start = start;
+        }
+        self.dialect = Some(dialect);
+        self
+    }
+
+    fn reset_bind_index(&mut self) {
+        if self.bind_index.is_some() {
+            self.bind_index = self.config.start;
+        }
+    }
+
     fn gen_bind_key(&mut self) -> String {
         if self.bind_index.is_some() {
             let index = self.bind_index.unwrap();
@@ -150,10 +216,11 @@ impl Insert {
     /// how to use see [`Insert::new`]
     pub fn to_sql(&mut self) -> String {
         self.binds = vec![];
+        self.reset_bind_index();
         // Insert
         let mut sql = format!("INSERT INTO {}", self.table_name.name);
         self.parse_values(&mut sql);
-        sql
+        quote_marked(self.dialect, sql)
     }
 
     /// get generated sql query and it's bind
@@ -163,4 +230,28 @@ impl Insert {
         let sql = self.to_sql();
         (sql, self.binds.clone())
     }
+
+    /// get generated sql query with binds inlined as dialect literals, for debugging only,
+    /// output starts with comment marking it as not for execution
+    /// ```rust
+    /// use qorm::{Bind, Insert};
+    ///
+    /// let mut builder = Insert::new("todo", None);
+    /// builder.values(vec![("name", Bind::from("Foo")), ("note", Bind::Null)]);
+    /// assert_eq!(
+    ///     builder.to_debug_sql(),
+    ///     "-- debug sql with inlined binds, not for execution\nINSERT INTO todo (name,note) VALUES ('Foo',NULL)"
+    /// );
+    /// ```
+    pub fn to_debug_sql(&mut self) -> String {
+        let config = self.config.clone();
+        let bind_index = self.bind_index;
+        self.config.placeholder = DEBUG_PLACEHOLDER.to_string();
+        self.config.start = Some(0);
+        self.bind_index = Some(0);
+        let (sql, binds) = self.to_sql_with_bind();
+        self.config = config;
+        self.bind_index = bind_index;
+        debug_sql(&sql, &binds, self.dialect)
+    }
 }
@@ -1,5 +1,32 @@
+use crate::Bind;
+
 #[derive(Clone)]
 pub struct InsertConfig {
     pub placeholder: String,
     pub start: Option<i32>,
 }
+
+/// Insertable
+///
+/// struct that can be turned into insert column/bind pairs, usually generated with `#[derive(Insertable)]` (`derive` feature)
+/// ```rust
+/// use qorm::{insert_item::Insertable, Bind, Insert};
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// impl Insertable for User {
+///     fn insert_values(&self) -> Vec<(&'static str, Bind)> {
+///         vec![("name", Bind::from(self.name.clone()))]
+///     }
+/// }
+///
+/// let user = User { name: "foo".to_string() };
+/// let mut builder = Insert::new("user", None);
+/// builder.values(user.insert_values());
+/// assert_eq!(builder.to_sql(), "INSERT INTO user (name) VALUES (?)");
+/// ```
+pub trait Insertable {
+    fn insert_values(&self) -> Vec<(&'static str, Bind)>;
+}
@@ -1,4 +1,3 @@
-#![allow(clippy::needless_doctest_main)]
 //! simple sql query builder in rust.
 //!
 //! ## Installation
@@ -56,8 +55,8 @@
 //!     AND user.id = ?
 //!     AND user.is_done IS NOT NULL
 //!     AND ( user.id = ? OR user.is_active = ?)
-//!     ORDER BY user.id ASC, role.id DESC
 //!     GROUP BY user.id
+//!     ORDER BY user.id ASC, role.id DESC
 //!     LIMIT 5
 //!     OFFSET 10
 //! ```
@@ -123,8 +122,8 @@
 //!     AND user.id = $2
 //!     AND user.is_done IS NOT NULL
 //!     AND ( user.id = $3 OR user.is_active = $4)
-//!     ORDER BY user.id ASC, role.id DESC
 //!     GROUP BY user.id
+//!     ORDER BY user.id ASC, role.id DESC
 //!     LIMIT 5
 //!     OFFSET 10
 //! ```
@@ -143,21 +142,84 @@
 //! - sql insert builder [`Insert`]
 //! - sql update builder [`Update`]
 //! - sql delete builder [`Delete`]
+//! - sql create table builder [`CreateTable`]
+//! - sql alter, drop and rename table builder [`AlterTable`], [`DropTable`], [`RenameTable`]
+//! - sql create and drop index builder [`CreateIndex`], [`DropIndex`]
+//! - table definition [`table::TableSchema`]
+//! - row decoding [`row::FromRow`]
+//! - query executor [`Executor`], sqlx integration `qorm::sqlx` (`sqlx` feature), postgres integration `qorm::postgres` (`postgres` feature),
+//!   mysql integration `qorm::mysql` (`mysql` feature)
+//! - transaction and savepoint [`Transaction`], [`transaction_item::TransactionState`] for async drivers
+//! - pretty-printed sql [`format::format_sql`]
+//! - schema migration runner [`migrate::Migrator`]
+//! - schema diffing [`schema::SchemaDiff`]
+//! - sql parser into builders [`parse::parse`]
+//! - tenant scoping and global filters [`scope::Scope`]
+//! - serializable select query [`select_item::SelectStatement`] (`serde` feature)
 //!
+pub mod alter_table;
+pub mod alter_table_item;
 pub mod bind;
+pub mod create_index;
+pub mod create_index_item;
+pub mod create_table;
+pub mod create_table_item;
 pub mod delete;
 pub mod delete_item;
+pub mod dialect;
+pub mod drop_index;
+pub mod drop_table;
+pub mod error;
+pub mod executor;
+pub mod expr;
+pub mod format;
 pub mod insert;
 pub mod insert_item;
+pub mod migrate;
+pub mod migrate_item;
+#[cfg(feature = "mysql")]
+pub mod mysql;
+pub mod parse;
+pub mod parse_item;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod query;
+pub mod rename_table;
+pub mod row;
+pub mod schema;
+pub mod schema_item;
+pub mod scope;
+pub mod scope_item;
 pub mod select;
 pub mod select_item;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
 pub mod table;
+pub mod transaction;
+pub mod transaction_item;
 pub mod update;
 pub mod update_item;
 pub mod where_item;
+pub mod window;
+pub mod window_item;
 
+pub use alter_table::AlterTable;
 pub use bind::Bind;
+pub use create_index::CreateIndex;
+pub use create_table::CreateTable;
 pub use delete::Delete;
+pub use dialect::Dialect;
+pub use drop_index::DropIndex;
+pub use drop_table::DropTable;
+pub use error::Error;
+pub use executor::Executor;
 pub use insert::Insert;
+pub use rename_table::RenameTable;
 pub use select::Select;
+pub use transaction::Transaction;
 pub use update::Update;
+
+#[cfg(feature = "derive")]
+pub use qorm_derive::{Changeset, FromRow, Insertable, Table};
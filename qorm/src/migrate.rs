@@ -0,0 +1,334 @@
+use crate::{
+    create_table_item::{ColumnDef, ColumnType},
+    dialect::Dialect,
+    error::Error,
+    executor::Executor,
+    migrate_item::{AppliedMigration, Migration, MigrationSource},
+    query::Query,
+    schema_item::NameRow,
+    Bind, CreateTable, Delete, Insert, Select,
+};
+
+/// table recording applied migrations
+pub const MIGRATION_TABLE: &str = "qorm_migrations";
+
+/// schema migration runner
+///
+/// migrations are applied on version order, each one inside its own transaction together with
+/// its record on [`MIGRATION_TABLE`], unless it is marked with [`Migration::no_transaction`].
+/// mysql commits DDL implicitly, so failed migration may be partially applied there.
+/// ```rust
+/// use qorm::{
+///     create_table_item::{ColumnDef, ColumnType},
+///     migrate::Migrator,
+///     migrate_item::Migration,
+///     query::Query,
+///     CreateTable, Dialect, DropTable, Error, Executor,
+/// };
+///
+/// fn create_todo() -> Vec<Box<dyn Query>> {
+///     let mut builder = CreateTable::new("todo");
+///     builder
+///         .column(ColumnDef::new("id", ColumnType::Integer).primary_key())
+///         .column(ColumnDef::new("name", ColumnType::Text).not_null());
+///     vec![Box::new(builder)]
+/// }
+///
+/// fn drop_todo() -> Vec<Box<dyn Query>> {
+///     vec![Box::new(DropTable::new("todo"))]
+/// }
+///
+/// fn migrate<E: Executor>(conn: &mut E) -> Result<Vec<i64>, Error> {
+///     let mut migrator = Migrator::new(Dialect::Sqlite);
+///     migrator
+///         .migration(Migration::new(1, "create_todo", create_todo).down(drop_todo))
+///         .migration(
+///             Migration::sql(2, "add_todo_index", "CREATE INDEX todo_name_idx ON todo (name);")
+///                 .down_sql("DROP INDEX todo_name_idx;"),
+///         );
+///     migrator.up(conn)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Migrator {
+    dialect: Dialect,
+    migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    /// Initialize Migrator, dialect is used to render builder migrations
+    pub fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            migrations: vec![],
+        }
+    }
+
+    /// register migration, migrations are sorted by version
+    pub fn migration(&mut self, migration: &Migration) -> &mut Self {
+        self.migrations.push(migration.clone());
+        self.migrations.sort_by_key(|f| f.version);
+        self
+    }
+
+    /// registered migrations on version order
+    pub fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
+
+    /// check registered migrations, versions must be positive and unique
+    /// ```rust
+    /// use qorm::{migrate::Migrator, migrate_item::Migration, Dialect, Error};
+    ///
+    /// let mut migrator = Migrator::new(Dialect::Sqlite);
+    /// migrator
+    ///     .migration(&Migration::sql(1, "create_todo", "CREATE TABLE todo (id INTEGER);"))
+    ///     .migration(&Migration::sql(1, "create_tag", "CREATE TABLE tag (id INTEGER);"));
+    /// assert_eq!(
+    ///     migrator.validate(),
+    ///     Err(Error::Invalid("migration version 1 is used by create_todo and create_tag".to_string()))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        for (idx, migration) in self.migrations.iter().enumerate() {
+            if migration.version <= 0 {
+                return Err(Error::Invalid(format!(
+                    "migration {} version must be positive",
+                    migration.name
+                )));
+            }
+            if idx > 0 && self.migrations[idx - 1].version == migration.version {
+                return Err(Error::Invalid(format!(
+                    "migration version {} is used by {} and {}",
+                    migration.version,
+                    self.migrations[idx - 1].name,
+                    migration.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// create migration table if it does not exist
+    pub fn setup<E: Executor>(&self, executor: &mut E) -> Result<(), Error> {
+        let mut builder = CreateTable::new(MIGRATION_TABLE);
+        builder
+            .dialect(self.dialect)
+            .if_not_exists()
+            .column(ColumnDef::new("version", ColumnType::BigInt).primary_key())
+            .column(ColumnDef::new("name", ColumnType::Varchar(255)).not_null())
+            .column(ColumnDef::new("checksum", ColumnType::Varchar(64)).not_null())
+            .column(
+                ColumnDef::new("applied_at", ColumnType::Timestamp)
+                    .not_null()
+                    .default_raw("CURRENT_TIMESTAMP"),
+            );
+        executor.execute(&mut builder)?;
+        Ok(())
+    }
+
+    /// applied migrations on version order, creates migration table if it does not exist
+    pub fn applied<E: Executor>(&self, executor: &mut E) -> Result<Vec<AppliedMigration>, Error> {
+        self.setup(executor)?;
+        self.read_applied(executor)
+    }
+
+    /// applied migrations without writing to database, empty when migration table does not exist
+    fn applied_readonly<E: Executor>(
+        &self,
+        executor: &mut E,
+    ) -> Result<Vec<AppliedMigration>, Error> {
+        let sql = match self.dialect {
+            Dialect::Sqlite => "SELECT name FROM sqlite_master WHERE type = 'table' AND name = ?",
+            Dialect::Postgres => "SELECT table_name AS name FROM information_schema.tables WHERE table_schema = current_schema() AND table_name = $1",
+            Dialect::MySql => "SELECT table_name AS name FROM information_schema.tables WHERE table_schema = DATABASE() AND table_name = ?",
+        };
+        let mut query = (sql.to_string(), vec![Bind::from(MIGRATION_TABLE)]);
+        match executor.fetch_optional::<NameRow, _>(&mut query)? {
+            Some(_) => self.read_applied(executor),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn read_applied<E: Executor>(&self, executor: &mut E) -> Result<Vec<AppliedMigration>, Error> {
+        let mut builder = Select::new(MIGRATION_TABLE, None, None);
+        builder
+            .dialect(self.dialect)
+            .select_row::<AppliedMigration>()
+            .order_by(vec!["version ASC"]);
+        executor.fetch_all(&mut builder)
+    }
+
+    /// pending migrations on version order.
+    ///
+    /// returns [`Error::Checksum`] when applied migration was edited and [`Error::Invalid`] when
+    /// applied migration is not registered
+    pub fn pending<E: Executor>(&self, executor: &mut E) -> Result<Vec<&Migration>, Error> {
+        let applied = self.applied(executor)?;
+        self.pending_of(&applied)
+    }
+
+    fn pending_of(&self, applied: &[AppliedMigration]) -> Result<Vec<&Migration>, Error> {
+        self.validate()?;
+        for item in applied.iter() {
+            let migration = self.find(item)?;
+            if migration.checksum(self.dialect)? != item.checksum {
+                return Err(Error::Checksum {
+                    version: item.version,
+                    name: item.name.clone(),
+                });
+            }
+        }
+        Ok(self
+            .migrations
+            .iter()
+            .filter(|f| !applied.iter().any(|applied| applied.version == f.version))
+            .collect())
+    }
+
+    fn find(&self, applied: &AppliedMigration) -> Result<&Migration, Error> {
+        self.migrations
+            .iter()
+            .find(|f| f.version == applied.version)
+            .ok_or_else(|| {
+                Error::Invalid(format!(
+                    "applied migration {} {} is not registered",
+                    applied.version, applied.name
+                ))
+            })
+    }
+
+    /// last applied migrations to roll back, newest first
+    fn rollback_targets(
+        &self,
+        applied: &[AppliedMigration],
+        steps: usize,
+    ) -> Result<Vec<&Migration>, Error> {
+        self.validate()?;
+        let mut targets = vec![];
+        for item in applied.iter().rev().take(steps) {
+            let migration = self.find(item)?;
+            if migration.down.is_none() {
+                return Err(Error::Invalid(format!(
+                    "migration {} {} has no down migration",
+                    migration.version, migration.name
+                )));
+            }
+            targets.push(migration);
+        }
+        Ok(targets)
+    }
+
+    /// apply pending migrations, returns applied versions
+    pub fn up<E: Executor>(&self, executor: &mut E) -> Result<Vec<i64>, Error> {
+        let pending = self.pending(executor)?;
+        let mut versions = vec![];
+        for migration in pending {
+            let mut record = Insert::new(MIGRATION_TABLE, None);
+            record.dialect(self.dialect).values(vec![
+                ("version", Bind::BigInt(migration.version)),
+                ("name", Bind::String(migration.name.clone())),
+                ("checksum", Bind::String(migration.checksum(self.dialect)?)),
+            ]);
+            self.run(executor, migration, &migration.up, &mut record)?;
+            versions.push(migration.version);
+        }
+        Ok(versions)
+    }
+
+    /// roll back last `steps` applied migrations, returns rolled back versions.
+    /// every target must have down migration, nothing is rolled back otherwise
+    pub fn down<E: Executor>(&self, executor: &mut E, steps: usize) -> Result<Vec<i64>, Error> {
+        let applied = self.applied(executor)?;
+        let targets = self.rollback_targets(&applied, steps)?;
+        let mut versions = vec![];
+        for migration in targets {
+            let mut record = Delete::new(MIGRATION_TABLE, None);
+            record
+                .dialect(self.dialect)
+                .wheres("version", "=", Bind::BigInt(migration.version));
+            self.run(
+                executor,
+                migration,
+                migration.down.as_ref().unwrap(),
+                &mut record,
+            )?;
+            versions.push(migration.version);
+        }
+        Ok(versions)
+    }
+
+    fn run<E: Executor, Q: Query>(
+        &self,
+        executor: &mut E,
+        migration: &Migration,
+        source: &MigrationSource,
+        record: &mut Q,
+    ) -> Result<(), Error> {
+        let statements = source.statements(self.dialect)?;
+        if !migration.transaction {
+            for mut statement in statements {
+                executor.execute(&mut statement)?;
+            }
+            executor.execute(record)?;
+            return Ok(());
+        }
+        executor.run_transaction(|tx| {
+            for mut statement in statements {
+                tx.execute(&mut statement)?;
+            }
+            tx.execute(record)?;
+            Ok(())
+        })
+    }
+
+    /// sql script `up` would run, without applying it. nothing is written to database,
+    /// missing migration table is read as no applied migration
+    /// ```rust
+    /// use qorm::{migrate::Migrator, migrate_item::Migration, Dialect, Error, Executor};
+    ///
+    /// fn print_pending<E: Executor>(conn: &mut E) -> Result<(), Error> {
+    ///     let mut migrator = Migrator::new(Dialect::Sqlite);
+    ///     migrator.migration(&Migration::sql(1, "create_tag", "CREATE TABLE tag (id INTEGER);"));
+    ///     // -- 1 create_tag
+    ///     // CREATE TABLE tag (id INTEGER);
+    ///     println!("{}", migrator.dry_run(conn)?);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn dry_run<E: Executor>(&self, executor: &mut E) -> Result<String, Error> {
+        let applied = self.applied_readonly(executor)?;
+        let pending = self.pending_of(&applied)?;
+        Ok(pending
+            .iter()
+            .map(|f| self.script(f, &f.up))
+            .collect::<Result<Vec<String>, Error>>()?
+            .join("\n"))
+    }
+
+    /// sql script `down` would run, without applying it, nothing is written to database
+    pub fn dry_run_down<E: Executor>(
+        &self,
+        executor: &mut E,
+        steps: usize,
+    ) -> Result<String, Error> {
+        let applied = self.applied_readonly(executor)?;
+        let targets = self.rollback_targets(&applied, steps)?;
+        Ok(targets
+            .iter()
+            .map(|f| self.script(f, f.down.as_ref().unwrap()))
+            .collect::<Result<Vec<String>, Error>>()?
+            .join("\n"))
+    }
+
+    fn script(&self, migration: &Migration, source: &MigrationSource) -> Result<String, Error> {
+        let mut sql = format!("-- {} {}\n", migration.version, migration.name);
+        for (statement, binds) in source.statements(self.dialect)? {
+            sql.push_str(format!("{};\n", statement).as_str());
+            if !binds.is_empty() {
+                sql.push_str(format!("-- binds: {:?}\n", binds).as_str());
+            }
+        }
+        Ok(sql)
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{
+    dialect::Dialect,
+    error::Error,
+    query::Query,
+    row::{FromRow, Row},
+    Bind,
+};
+
+/// migration written in rust, returns builders rendered with migrator dialect
+pub type MigrationFn = fn() -> Vec<Box<dyn Query>>;
+
+#[derive(Clone)]
+pub(crate) enum MigrationSource {
+    Builder(MigrationFn),
+    Sql(String),
+}
+
+impl MigrationSource {
+    /// rendered statements of the migration, fails when builder can not be rendered on dialect
+    pub(crate) fn statements(&self, dialect: Dialect) -> Result<Vec<(String, Vec<Bind>)>, Error> {
+        match self {
+            MigrationSource::Builder(f) => f()
+                .iter_mut()
+                .map(|query| {
+                    query.set_dialect(dialect);
+                    query.build()
+                })
+                .collect(),
+            MigrationSource::Sql(sql) => Ok(split_statements(sql, dialect)
+                .into_iter()
+                .map(|f| (f, vec![]))
+                .collect()),
+        }
+    }
+}
+
+/// Migration
+///
+/// single versioned migration registered with [`crate::migrate::Migrator::migration`]
+/// ```rust
+/// use qorm::{
+///     create_table_item::{ColumnDef, ColumnType},
+///     migrate_item::Migration,
+///     query::Query,
+///     CreateTable, DropTable,
+/// };
+///
+/// fn create_todo() -> Vec<Box<dyn Query>> {
+///     let mut builder = CreateTable::new("todo");
+///     builder
+///         .column(ColumnDef::new("id", ColumnType::BigInt).primary_key().auto_increment())
+///         .column(ColumnDef::new("name", ColumnType::Text).not_null());
+///     vec![Box::new(builder)]
+/// }
+///
+/// fn drop_todo() -> Vec<Box<dyn Query>> {
+///     vec![Box::new(DropTable::new("todo"))]
+/// }
+///
+/// let mut migration = Migration::new(1, "create_todo", create_todo);
+/// migration.down(drop_todo);
+/// ```
+#[derive(Clone)]
+pub struct Migration {
+    pub version: i64,
+    pub name: String,
+    pub(crate) up: MigrationSource,
+    pub(crate) down: Option<MigrationSource>,
+    pub(crate) transaction: bool,
+}
+
+impl Migration {
+    /// Initialize migration from rust function
+    pub fn new(version: i64, name: &str, up: MigrationFn) -> Self {
+        Self {
+            version,
+            name: name.to_string(),
+            up: MigrationSource::Builder(up),
+            down: None,
+            transaction: true,
+        }
+    }
+
+    /// Initialize migration from raw sql, statements are separated by `;`
+    /// ```rust
+    /// use qorm::migrate_item::Migration;
+    ///
+    /// let mut migration = Migration::sql(
+    ///     2,
+    ///     "add_todo_index",
+    ///     "CREATE INDEX todo_name_idx ON todo (name);",
+    /// );
+    /// migration.down_sql("DROP INDEX todo_name_idx;");
+    /// ```
+    pub fn sql(version: i64, name: &str, up: &str) -> Self {
+        Self {
+            version,
+            name: name.to_string(),
+            up: MigrationSource::Sql(up.to_string()),
+            down: None,
+            transaction: true,
+        }
+    }
+
+    /// Initialize migration from embedded sql file, version and name are read from
+    /// file name `<version>_<name>.sql` or `<version>_<name>.up.sql`.
+    /// file starting with `-- qorm:no-transaction` line runs without transaction (see [`Migration::no_transaction`])
+    /// ```rust
+    /// use qorm::migrate_item::Migration;
+    ///
+    /// // Migration::from_file("0003_create_tag.up.sql", include_str!("../migrations/0003_create_tag.up.sql"))
+    /// let migration = Migration::from_file(
+    ///     "migrations/0003_create_tag.up.sql",
+    ///     "CREATE TABLE tag (id INTEGER PRIMARY KEY);",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(migration.version, 3);
+    /// assert_eq!(migration.name, "create_tag");
+    /// ```
+    pub fn from_file(file_name: &str, up: &str) -> Result<Self, Error> {
+        let invalid = || {
+            Error::Invalid(format!(
+                "migration file {} must be named <version>_<name>.sql",
+                file_name
+            ))
+        };
+        let base = file_name.rsplit(['/', '\\']).next().unwrap_or(file_name);
+        let stem = base.strip_suffix(".sql").ok_or_else(invalid)?;
+        let stem = stem.strip_suffix(".up").unwrap_or(stem);
+        let (version, name) = stem.split_once('_').ok_or_else(invalid)?;
+        let version = version.parse::<i64>().map_err(|_| invalid())?;
+        if name.is_empty() {
+            return Err(invalid());
+        }
+        let mut migration = Self::sql(version, name, up);
+        if up.trim_start().starts_with("-- qorm:no-transaction") {
+            migration.no_transaction();
+        }
+        Ok(migration)
+    }
+
+    /// down migration from rust function
+    pub fn down(&mut self, down: MigrationFn) -> &mut Self {
+        self.down = Some(MigrationSource::Builder(down));
+        self
+    }
+
+    /// down migration from raw sql, statements are separated by `;`
+    pub fn down_sql(&mut self, down: &str) -> &mut Self {
+        self.down = Some(MigrationSource::Sql(down.to_string()));
+        self
+    }
+
+    /// run migration without transaction, needed by statements postgres refuses inside transaction
+    /// (e.g. `CREATE INDEX CONCURRENTLY`). failed migration is not rolled back and is not recorded
+    /// ```rust
+    /// use qorm::migrate_item::Migration;
+    ///
+    /// let mut migration = Migration::sql(
+    ///     3,
+    ///     "add_todo_name_index",
+    ///     "CREATE INDEX CONCURRENTLY todo_name_idx ON todo (name);",
+    /// );
+    /// migration.no_transaction();
+    /// ```
+    pub fn no_transaction(&mut self) -> &mut Self {
+        self.transaction = false;
+        self
+    }
+
+    /// checksum of up statements rendered on dialect, stored on migration table
+    /// ```rust
+    /// use qorm::{migrate_item::Migration, Dialect};
+    ///
+    /// let migration = Migration::sql(1, "create_tag", "CREATE TABLE tag (id INTEGER PRIMARY KEY);");
+    /// let edited = Migration::sql(1, "create_tag", "CREATE TABLE tag (id BIGINT PRIMARY KEY);");
+    /// assert_eq!(migration.checksum(Dialect::Sqlite).unwrap().len(), 16);
+    /// assert_ne!(migration.checksum(Dialect::Sqlite), edited.checksum(Dialect::Sqlite));
+    /// ```
+    pub fn checksum(&self, dialect: Dialect) -> Result<String, Error> {
+        // fnv-1a, stable across rust versions unlike std hasher
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for (sql, binds) in self.up.statements(dialect)? {
+            let mut content = sql;
+            for bind in binds {
+                content.push('\0');
+                content.push_str(bind.to_literal(Some(dialect)).as_str());
+            }
+            content.push('\n');
+            for byte in content.bytes() {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        }
+        Ok(format!("{:016x}", hash))
+    }
+}
+
+/// AppliedMigration
+///
+/// row of migration table
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub name: String,
+    pub checksum: String,
+}
+
+impl FromRow for AppliedMigration {
+    const COLUMNS: &'static [&'static str] = &["version", "name", "checksum"];
+
+    fn from_row<R: Row>(row: &R) -> Result<Self, Error> {
+        Ok(Self {
+            version: row.get("version")?,
+            name: row.get("name")?,
+            checksum: row.get("checksum")?,
+        })
+    }
+}
+
+/// split sql script on `;` outside of quotes, comments, dollar quoted bodies and trigger `BEGIN ... END` blocks,
+/// comment-only statements are dropped. backslash escapes quote on mysql and in postgres `E'...'` string
+pub(crate) fn split_statements(sql: &str, dialect: Dialect) -> Vec<String> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut statements = vec![];
+    let mut start = 0;
+    let mut content = false;
+    // Open `BEGIN`/`CASE` blocks, `;` inside trigger body does not end statement
+    let mut depth = 0;
+    let mut trigger = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '-' && chars.get(i + 1) == Some(&'-') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i = (i + 2).min(chars.len());
+            continue;
+        }
+        if c == '\'' || c == '"' || c == '`' {
+            let escape = c != '`'
+                && (dialect == Dialect::MySql
+                    || (dialect == Dialect::Postgres
+                        && c == '\''
+                        && i > 0
+                        && chars[i - 1].eq_ignore_ascii_case(&'e')
+                        && !chars.get(i.wrapping_sub(2)).is_some_and(|f| is_word(*f))));
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += match escape && chars[i] == '\\' {
+                    true => 2,
+                    false => 1,
+                };
+            }
+            i += 1;
+            content = true;
+            continue;
+        }
+        if c == '$' {
+            if let Some(tag) = dollar_tag(&chars[i..]) {
+                let body = i + tag.len();
+                i = (body..chars.len())
+                    .find(|f| chars[*f..].starts_with(&tag))
+                    .map_or(chars.len(), |f| f + tag.len());
+                content = true;
+                continue;
+            }
+        }
+        if is_word(c) {
+            let end = (i..chars.len())
+                .find(|f| !is_word(chars[*f]))
+                .unwrap_or(chars.len());
+            let word = chars[i..end].iter().collect::<String>().to_uppercase();
+            i = end;
+            match word.as_str() {
+                "TRIGGER" => trigger = true,
+                "BEGIN" if trigger => depth += 1,
+                "CASE" => depth += 1,
+                "END" if depth > 0 => {
+                    // mysql `END IF`, `END LOOP`, ... close statement blocks that are not counted,
+                    // `END CASE` closes counted `CASE`
+                    let next = (end..chars.len())
+                        .find(|f| !chars[*f].is_whitespace())
+                        .unwrap_or(chars.len());
+                    let next_end = (next..chars.len())
+                        .find(|f| !is_word(chars[*f]))
+                        .unwrap_or(chars.len());
+                    let next_word = chars[next..next_end]
+                        .iter()
+                        .collect::<String>()
+                        .to_uppercase();
+                    match next_word.as_str() {
+                        "IF" | "LOOP" | "WHILE" | "REPEAT" => i = next_end,
+                        "CASE" => {
+                            depth -= 1;
+                            i = next_end;
+                        }
+                        _ => depth -= 1,
+                    }
+                }
+                _ => {}
+            }
+            content = true;
+            continue;
+        }
+        if c == ';' && depth == 0 {
+            if content {
+                statements.push(
+                    chars[start..i]
+                        .iter()
+                        .collect::<String>()
+                        .trim()
+                        .to_string(),
+                );
+            }
+            start = i + 1;
+            content = false;
+            trigger = false;
+        } else if !c.is_whitespace() {
+            content = true;
+        }
+        i += 1;
+    }
+    if content {
+        statements.push(chars[start..].iter().collect::<String>().trim().to_string());
+    }
+    statements
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// opening tag of postgres dollar quote (`$$` or `$tag$`), `$1` placeholder is not a tag
+fn dollar_tag(chars: &[char]) -> Option<Vec<char>> {
+    if chars.get(1).is_some_and(|f| f.is_ascii_digit()) {
+        return None;
+    }
+    let end = (1..chars.len()).find(|f| !is_word(chars[*f]))?;
+    match chars[end] == '$' {
+        true => Some(chars[..=end].to_vec()),
+        false => None,
+    }
+}
@@ -0,0 +1,67 @@
+//! mysql backend, enabled with `mysql` feature
+//!
+//! [`Bind`] converts into [`Value`] of `mysql_common` (shared by `mysql` and `mysql_async` crate),
+//! and [`query`] renders builder with mysql dialect (backtick quoting, `?` placeholder).
+//! ```rust
+//! use qorm::{mysql::{mysql_common::params::Params, query}, Bind, Update};
+//!
+//! let mut builder = Update::new("user", None);
+//! builder.set(vec![("name", Bind::from("Foo"))]).wheres("id", "=", Bind::Int(1));
+//! let statement = query(&mut builder).unwrap();
+//! assert_eq!(statement.sql(), "UPDATE user SET name = ? WHERE id = ?");
+//! assert!(matches!(statement.params(), Params::Positional(values) if values.len() == 2));
+//! ```
+pub use mysql_common;
+use mysql_common::{params::Params, value::Value};
+
+use crate::{dialect::Dialect, error::Error, query::Query, Bind};
+
+/// Statement
+///
+/// generated sql and binds of builder, ready to be passed to mysql connection
+#[derive(Clone, Debug)]
+pub struct Statement {
+    sql: String,
+    binds: Vec<Bind>,
+}
+
+impl Statement {
+    /// generated sql query
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// generated binds
+    pub fn binds(&self) -> &[Bind] {
+        &self.binds
+    }
+
+    /// binds as positional mysql parameters, `Params::Empty` when there is no bind
+    pub fn params(&self) -> Params {
+        if self.binds.is_empty() {
+            return Params::Empty;
+        }
+        Params::Positional(self.binds.iter().cloned().map(Value::from).collect())
+    }
+}
+
+/// build mysql statement from qorm builder, builder is rendered on mysql and left unchanged,
+/// fails when builder can not be rendered on mysql
+pub fn query<Q: Query>(query: &mut Q) -> Result<Statement, Error> {
+    let (sql, binds) = query.build_for(Dialect::MySql)?;
+    Ok(Statement { sql, binds })
+}
+
+impl From<Bind> for Value {
+    fn from(bind: Bind) -> Self {
+        match bind {
+            Bind::Null => Value::NULL,
+            Bind::String(value) | Bind::Raw(value) => Value::Bytes(value.into_bytes()),
+            Bind::Int(value) => Value::Int(i64::from(value)),
+            Bind::BigInt(value) => Value::Int(value),
+            Bind::Bool(value) => Value::Int(i64::from(value)),
+            Bind::Float(value) => Value::Double(value),
+            Bind::Bytes(value) => Value::Bytes(value),
+        }
+    }
+}
@@ -0,0 +1,754 @@
+use crate::{
+    dialect::{quote_marked, Dialect},
+    error::Error,
+    expr::Expr,
+    parse_item::{parse_number, placeholder_error, render, tokenize, written, Statement, Token},
+    where_item::{Condition, Or},
+    Bind, Delete, Insert, Select, Update,
+};
+
+/// keywords ending select clause
+const CLAUSES: &[&str] = &[
+    "WHERE",
+    "GROUP",
+    "HAVING",
+    "WINDOW",
+    "ORDER",
+    "LIMIT",
+    "OFFSET",
+    "FOR",
+    "FETCH",
+    "UNION",
+    "INTERSECT",
+    "EXCEPT",
+    "RETURNING",
+];
+
+/// keywords starting join
+const JOINS: &[&str] = &["JOIN", "INNER", "LEFT", "RIGHT", "FULL", "CROSS", "NATURAL"];
+
+/// parse sql into equivalent qorm builder
+///
+/// supported subset:
+/// - `SELECT [DISTINCT | DISTINCT ON (...)] ... FROM table [alias] [JOIN ... ON ...]
+///   [WHERE ...] [GROUP BY ...] [ORDER BY ...] [LIMIT ...] [OFFSET ...] [FOR UPDATE ...]`
+/// - `INSERT INTO table (columns) VALUES (values)`
+/// - `UPDATE table SET column = value, ... [WHERE ...]`
+/// - `DELETE FROM table [WHERE ...]`
+///
+/// where clause is read as `AND` of conditions and `OR` groups, each condition compares
+/// expression with literal (`=`, `<>`, `!=`, `<`, `>`, `<=`, `>=`, `[NOT] LIKE`, `[NOT] ILIKE`,
+/// `IS [NOT]`). literals of conditions, insert and update values and select function arguments
+/// are extracted into binds, other expressions are kept as written and string literal in them
+/// returns [`Error::Invalid`]. subqueries, `HAVING`, `UNION` and placeholders return
+/// [`Error::Invalid`]. `"` quotes identifiers, except on mysql where it quotes strings. quoted
+/// identifier other than lowercase plain name is quoted with builder dialect on render.
+/// ```rust
+/// use qorm::{parse::parse, parse_item::Statement, Bind, Dialect};
+///
+/// let mut statement = parse(
+///     "SELECT t.id, t.name FROM todo t WHERE t.is_done = FALSE AND t.name LIKE 'a%' LIMIT 10",
+///     Dialect::MySql,
+/// )
+/// .unwrap();
+/// if let Statement::Select(builder) = &mut statement {
+///     builder.wheres("t.tenant_id", "=", Bind::Int(7)).limit(20);
+/// }
+/// statement.dialect(Dialect::Postgres);
+/// assert_eq!(
+///     statement.to_sql_with_bind(),
+///     (
+///         "SELECT t.id, t.name FROM todo t WHERE t.is_done = $1 AND t.name LIKE $2 AND t.tenant_id = $3 LIMIT 20".to_string(),
+///         vec![Bind::Bool(false), Bind::String("a%".to_string()), Bind::Int(7)]
+///     )
+/// );
+/// ```
+pub fn parse(sql: &str, dialect: Dialect) -> Result<Statement, Error> {
+    let mut parser = Parser {
+        tokens: tokenize(sql, dialect)?,
+        pos: 0,
+        dialect,
+    };
+    let statement = match parser.peek() {
+        Some(token) if token.is_keyword("SELECT") => Statement::Select(Box::new(parser.select()?)),
+        Some(token) if token.is_keyword("INSERT") => Statement::Insert(parser.insert()?),
+        Some(token) if token.is_keyword("UPDATE") => Statement::Update(parser.update()?),
+        Some(token) if token.is_keyword("DELETE") => Statement::Delete(parser.delete()?),
+        Some(token) => {
+            return Err(Error::Invalid(format!(
+                "unsupported statement starting with {}",
+                token
+            )))
+        }
+        None => return Err(Error::Invalid("empty sql".to_string())),
+    };
+    parser.eat_symbol(";");
+    if let Some(token) = parser.peek() {
+        return Err(Error::Invalid(format!("unexpected {}", token)));
+    }
+    Ok(statement)
+}
+
+/// parse select sql into [`Select`] builder, see [`parse`]
+/// ```rust
+/// use qorm::{parse::parse_select, Dialect};
+///
+/// let mut builder = parse_select(
+///     r#"SELECT COUNT(DISTINCT "t"."user_id") AS users FROM "todo" "t" WHERE "t"."point" > 10"#,
+///     Dialect::Postgres,
+/// )
+/// .unwrap();
+/// builder.dialect(Dialect::MySql);
+/// assert_eq!(builder.to_sql(), "SELECT COUNT(DISTINCT t.user_id) AS `users` FROM todo t WHERE t.point > ?");
+/// ```
+pub fn parse_select(sql: &str, dialect: Dialect) -> Result<Select, Error> {
+    match parse(sql, dialect)? {
+        Statement::Select(builder) => Ok(*builder),
+        _ => Err(Error::Invalid("expected SELECT statement".to_string())),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    dialect: Dialect,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        self.peek().is_some_and(|f| f.is_keyword(keyword))
+    }
+
+    fn next(&mut self) -> Result<Token, Error> {
+        let token = self
+            .peek()
+            .cloned()
+            .ok_or(Error::Invalid("unexpected end of sql".to_string()))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek_keyword(keyword) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        if self.peek().is_some_and(|f| f.is_symbol(symbol)) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expected(&self, expected: &str) -> Error {
+        match self.peek() {
+            Some(token) => Error::Invalid(format!("expected {}, found {}", expected, token)),
+            None => Error::Invalid(format!("expected {}, found end of sql", expected)),
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), Error> {
+        match self.eat_keyword(keyword) {
+            true => Ok(()),
+            false => Err(self.expected(keyword)),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), Error> {
+        match self.eat_symbol(symbol) {
+            true => Ok(()),
+            false => Err(self.expected(symbol)),
+        }
+    }
+
+    /// dotted identifier, e.g. `schema.table`
+    fn identifier(&mut self) -> Result<String, Error> {
+        let mut parts = vec![];
+        loop {
+            match self.peek() {
+                Some(token) if token.is_identifier() => parts.push(self.next()?.to_sql()?),
+                Some(token) if token.is_symbol("(") => {
+                    return Err(Error::Invalid("subquery is not supported".to_string()))
+                }
+                _ => return Err(self.expected("identifier")),
+            }
+            if !self.eat_symbol(".") {
+                return Ok(parts.join("."));
+            }
+        }
+    }
+
+    /// `[AS] alias`
+    fn alias(&mut self) -> Result<Option<String>, Error> {
+        if self.eat_keyword("AS") || self.peek().is_some_and(|f| f.is_identifier()) {
+            return Ok(Some(self.identifier()?));
+        }
+        Ok(None)
+    }
+
+    /// whether current token ends clause, join keywords are not followed by `(` (e.g. `LEFT(name, 1)`)
+    fn at_stop(&self, stops: &[&str]) -> bool {
+        match self.peek() {
+            None => true,
+            Some(token) if token.is_symbol(";") => true,
+            Some(token) => {
+                stops.iter().any(|f| token.is_keyword(f)) && {
+                    !JOINS.iter().any(|f| token.is_keyword(f))
+                        || !self
+                            .tokens
+                            .get(self.pos + 1)
+                            .is_some_and(|f| f.is_symbol("("))
+                }
+            }
+        }
+    }
+
+    /// tokens until stop keyword or `,` (when `comma`) outside parentheses
+    fn until(&mut self, stops: &[&str], comma: bool) -> Result<Vec<Token>, Error> {
+        let mut tokens = vec![];
+        let mut depth = 0;
+        while depth > 0 || !(self.at_stop(stops) || (comma && self.peek_is(","))) {
+            let token = self.next()?;
+            if token.is_symbol("(") {
+                depth += 1;
+            } else if token.is_symbol(")") {
+                if depth == 0 {
+                    self.pos -= 1;
+                    break;
+                }
+                depth -= 1;
+            } else if token.is_keyword("SELECT") {
+                return Err(Error::Invalid("subquery is not supported".to_string()));
+            }
+            tokens.push(token);
+        }
+        if tokens.is_empty() {
+            return Err(self.expected("expression"));
+        }
+        Ok(tokens)
+    }
+
+    fn peek_is(&self, symbol: &str) -> bool {
+        self.peek().is_some_and(|f| f.is_symbol(symbol))
+    }
+
+    /// comma separated expressions
+    fn list(&mut self, stops: &[&str]) -> Result<Vec<Vec<Token>>, Error> {
+        let mut items = vec![self.until(stops, true)?];
+        while self.eat_symbol(",") {
+            items.push(self.until(stops, true)?);
+        }
+        Ok(items)
+    }
+
+    fn integer(&mut self) -> Result<i64, Error> {
+        match self.next()? {
+            Token::Number(value) => value
+                .parse::<i64>()
+                .map_err(|_| Error::Invalid(format!("expected integer, found {}", value))),
+            token => Err(Error::Invalid(format!("expected integer, found {}", token))),
+        }
+    }
+
+    fn select(&mut self) -> Result<Select, Error> {
+        self.expect_keyword("SELECT")?;
+        let mut distinct = false;
+        let mut distinct_on = None;
+        if self.eat_keyword("DISTINCT") {
+            if self.eat_keyword("ON") {
+                self.expect_symbol("(")?;
+                distinct_on = Some(render_list(&self.list(&[])?)?);
+                self.expect_symbol(")")?;
+            } else {
+                distinct = true;
+            }
+        }
+        let items = self.list(&["FROM"])?;
+        if !self.eat_keyword("FROM") {
+            return Err(self.expected("FROM"));
+        }
+        let table = self.identifier()?;
+        let alias = self.alias()?;
+        if self.peek_is(",") {
+            return Err(Error::Invalid(
+                "multiple FROM tables are not supported, use JOIN".to_string(),
+            ));
+        }
+
+        let mut builder = Select::new(&table, alias.as_deref(), None);
+        builder.dialect(self.dialect);
+        if distinct {
+            builder.distinct();
+        }
+        if let Some(columns) = distinct_on {
+            builder.distinct_on(columns.iter().map(|f| f.as_str()).collect());
+        }
+        let single_star = items.len() == 1 && items[0].len() == 1 && items[0][0].is_symbol("*");
+        if !single_star {
+            for item in items.iter() {
+                let (expr, alias) = select_item(item)?;
+                builder.select_internal(expr, alias.as_deref());
+            }
+        }
+
+        while self
+            .peek()
+            .is_some_and(|f| JOINS.iter().any(|j| f.is_keyword(j)))
+        {
+            let mut join_type = vec![];
+            while !self.peek_keyword("JOIN") {
+                let token = self.next()?;
+                if !JOINS.iter().any(|f| token.is_keyword(f)) && !token.is_keyword("OUTER") {
+                    return Err(Error::Invalid(format!("expected JOIN, found {}", token)));
+                }
+                join_type.push(token.to_string().to_uppercase());
+            }
+            self.expect_keyword("JOIN")?;
+            let mut table = self.identifier()?;
+            if let Some(alias) = self.alias()? {
+                table = format!("{} {}", table, alias);
+            }
+            if !self.eat_keyword("ON") {
+                return Err(Error::Invalid(format!(
+                    "join {} requires ON condition",
+                    quote_marked(None, table)
+                )));
+            }
+            let on = render(&self.until(&[CLAUSES, JOINS].concat(), false)?)?;
+            let join_type = join_type.join(" ");
+            builder.join(
+                (!join_type.is_empty()).then_some(join_type.as_str()),
+                &table,
+                &on,
+            );
+        }
+
+        if self.eat_keyword("WHERE") {
+            let (where_and, where_or) = conditions(&self.until(CLAUSES, false)?)?;
+            for item in where_and.iter() {
+                builder.wheres(&item.column, &item.operator, item.value.clone());
+            }
+            for group in where_or.iter() {
+                builder.where_or(or_items(group));
+            }
+        }
+        if self.eat_keyword("GROUP") {
+            self.expect_keyword("BY")?;
+            let columns = render_list(&self.list(CLAUSES)?)?;
+            builder.group_by(columns.iter().map(|f| f.as_str()).collect());
+        }
+        if let Some(token) = self.peek() {
+            for clause in ["HAVING", "WINDOW"] {
+                if token.is_keyword(clause) {
+                    return Err(Error::Invalid(format!("{} is not supported", clause)));
+                }
+            }
+        }
+        if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            let columns = render_list(&self.list(CLAUSES)?)?;
+            builder.order_by(columns.iter().map(|f| f.as_str()).collect());
+        }
+        if self.eat_keyword("LIMIT") && !self.eat_keyword("ALL") {
+            let limit = self.integer()?;
+            // mysql `LIMIT offset, limit`
+            if self.eat_symbol(",") {
+                builder.offset(limit);
+                builder.limit(self.integer()?);
+            } else {
+                builder.limit(limit);
+            }
+        }
+        if self.eat_keyword("OFFSET") {
+            builder.offset(self.integer()?);
+            let _ = self.eat_keyword("ROWS") || self.eat_keyword("ROW");
+        }
+        if self.eat_keyword("FOR") {
+            self.lock(&mut builder)?;
+        }
+        Ok(builder)
+    }
+
+    /// row locking after `FOR`
+    fn lock(&mut self, builder: &mut Select) -> Result<(), Error> {
+        if self.eat_keyword("UPDATE") {
+            builder.for_update();
+        } else if self.eat_keyword("SHARE") {
+            builder.for_share();
+        } else if self.eat_keyword("NO") {
+            self.expect_keyword("KEY")?;
+            self.expect_keyword("UPDATE")?;
+            builder.for_no_key_update();
+        } else {
+            return Err(self.expected("UPDATE, SHARE or NO KEY UPDATE"));
+        }
+        if self.eat_keyword("OF") {
+            let mut tables = vec![self.identifier()?];
+            while self.eat_symbol(",") {
+                tables.push(self.identifier()?);
+            }
+            builder.of(tables.iter().map(|f| f.as_str()).collect());
+        }
+        if self.eat_keyword("SKIP") {
+            self.expect_keyword("LOCKED")?;
+            builder.skip_locked();
+        } else if self.eat_keyword("NOWAIT") {
+            builder.nowait();
+        }
+        Ok(())
+    }
+
+    fn insert(&mut self) -> Result<Insert, Error> {
+        self.expect_keyword("INSERT")?;
+        self.expect_keyword("INTO")?;
+        let table = self.identifier()?;
+        if !self.eat_symbol("(") {
+            return Err(Error::Invalid(format!(
+                "INSERT INTO {} requires column list",
+                table
+            )));
+        }
+        let mut columns = vec![self.identifier()?];
+        while self.eat_symbol(",") {
+            columns.push(self.identifier()?);
+        }
+        self.expect_symbol(")")?;
+        self.expect_keyword("VALUES")?;
+        self.expect_symbol("(")?;
+        let values = self
+            .list(&[])?
+            .iter()
+            .map(|f| value(f))
+            .collect::<Result<Vec<Bind>, Error>>()?;
+        self.expect_symbol(")")?;
+        if self.peek_is(",") {
+            return Err(Error::Invalid(
+                "INSERT with multiple rows is not supported".to_string(),
+            ));
+        }
+        if columns.len() != values.len() {
+            return Err(Error::Invalid(format!(
+                "INSERT INTO {} has {} columns but {} values",
+                table,
+                columns.len(),
+                values.len()
+            )));
+        }
+
+        let mut builder = Insert::new(&table, None);
+        builder.dialect(self.dialect).values(
+            columns
+                .iter()
+                .map(|f| f.as_str())
+                .zip(values)
+                .collect::<Vec<(&str, Bind)>>(),
+        );
+        Ok(builder)
+    }
+
+    fn update(&mut self) -> Result<Update, Error> {
+        self.expect_keyword("UPDATE")?;
+        let table = self.identifier()?;
+        self.expect_keyword("SET")?;
+        let mut values = vec![];
+        loop {
+            let column = self.identifier()?;
+            self.expect_symbol("=")?;
+            values.push((column, value(&self.until(&["WHERE"], true)?)?));
+            if !self.eat_symbol(",") {
+                break;
+            }
+        }
+
+        let mut builder = Update::new(&table, None);
+        builder.dialect(self.dialect).set(
+            values
+                .iter()
+                .map(|(column, value)| (column.as_str(), value.clone()))
+                .collect(),
+        );
+        if self.eat_keyword("WHERE") {
+            let (where_and, where_or) = conditions(&self.until(&[], false)?)?;
+            for item in where_and.iter() {
+                builder.wheres(&item.column, &item.operator, item.value.clone());
+            }
+            for group in where_or.iter() {
+                builder.where_or(or_items(group));
+            }
+        }
+        Ok(builder)
+    }
+
+    fn delete(&mut self) -> Result<Delete, Error> {
+        self.expect_keyword("DELETE")?;
+        self.expect_keyword("FROM")?;
+        let table = self.identifier()?;
+
+        let mut builder = Delete::new(&table, None);
+        builder.dialect(self.dialect);
+        if self.eat_keyword("WHERE") {
+            let (where_and, where_or) = conditions(&self.until(&[], false)?)?;
+            for item in where_and.iter() {
+                builder.wheres(&item.column, &item.operator, item.value.clone());
+            }
+            for group in where_or.iter() {
+                builder.where_or(or_items(group));
+            }
+        }
+        Ok(builder)
+    }
+}
+
+fn or_items(group: &[Condition]) -> Vec<Or<'_>> {
+    group
+        .iter()
+        .map(|f| Or {
+            column: &f.column,
+            operator: &f.operator,
+            value: f.value.clone(),
+        })
+        .collect()
+}
+
+fn render_list(items: &[Vec<Token>]) -> Result<Vec<String>, Error> {
+    items.iter().map(|f| render(f)).collect()
+}
+
+/// literal value, e.g. `'a'`, `-1`, `TRUE` or `NULL`
+fn literal(tokens: &[Token]) -> Result<Option<Bind>, Error> {
+    match tokens {
+        [token] => token.to_bind(),
+        [sign, Token::Number(value)] if sign.is_symbol("-") => Ok(Some(parse_number(value, true)?)),
+        _ => Ok(None),
+    }
+}
+
+/// insert or update value
+fn value(tokens: &[Token]) -> Result<Bind, Error> {
+    if let [Token::Placeholder(value)] = tokens {
+        return Err(placeholder_error(value));
+    }
+    literal(tokens)?.ok_or(Error::Invalid(format!(
+        "unsupported value {}, expected literal",
+        written(tokens)
+    )))
+}
+
+/// index of `)` closing `(` at `start`
+fn closing(tokens: &[Token], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, token) in tokens.iter().enumerate().skip(start) {
+        if token.is_symbol("(") {
+            depth += 1;
+        } else if token.is_symbol(")") {
+            depth -= 1;
+            if depth == 0 {
+                return Some(idx);
+            }
+        }
+    }
+    None
+}
+
+/// inner tokens when whole expression is wrapped in parentheses
+fn unwrap_parens(tokens: &[Token]) -> Option<&[Token]> {
+    match tokens.first() {
+        Some(token) if token.is_symbol("(") && closing(tokens, 0) == Some(tokens.len() - 1) => {
+            Some(&tokens[1..tokens.len() - 1])
+        }
+        _ => None,
+    }
+}
+
+/// split tokens on keyword outside parentheses
+fn split(tokens: &[Token], keyword: &str) -> Vec<Vec<Token>> {
+    let mut parts = vec![vec![]];
+    let mut depth = 0;
+    for token in tokens {
+        if token.is_symbol("(") {
+            depth += 1;
+        } else if token.is_symbol(")") {
+            depth -= 1;
+        } else if depth == 0 && token.is_keyword(keyword) {
+            parts.push(vec![]);
+            continue;
+        }
+        parts.last_mut().unwrap().push(token.clone());
+    }
+    parts
+}
+
+/// select item expression and alias
+fn select_item(tokens: &[Token]) -> Result<(Expr, Option<String>), Error> {
+    let len = tokens.len();
+    let (tokens, alias) = if len >= 3 && tokens[len - 2].is_keyword("AS") {
+        (&tokens[..len - 2], Some(&tokens[len - 1]))
+    } else if len >= 2
+        && tokens[len - 1].is_identifier()
+        && match &tokens[len - 2] {
+            Token::Symbol(symbol) => symbol == ")",
+            token => !token.is_reserved() || token.is_keyword("END"),
+        }
+    {
+        (&tokens[..len - 1], Some(&tokens[len - 1]))
+    } else {
+        (tokens, None)
+    };
+    let alias = match alias {
+        Some(Token::Word(name)) | Some(Token::Quoted(name)) => Some(name.clone()),
+        Some(token) => return Err(Error::Invalid(format!("invalid alias {}", token))),
+        None => None,
+    };
+    Ok((expr(tokens)?, alias))
+}
+
+/// literal as bound value, function call as [`Expr::Function`] and anything else as raw sql
+fn expr(tokens: &[Token]) -> Result<Expr, Error> {
+    if let Some(value) = literal(tokens)? {
+        return Ok(Expr::Value(value));
+    }
+    let is_function = tokens.len() >= 3
+        && matches!(tokens[0], Token::Word(_))
+        && !tokens[0].is_reserved()
+        && tokens[1].is_symbol("(")
+        && closing(tokens, 1) == Some(tokens.len() - 1);
+    if !is_function {
+        return Ok(Expr::Raw(render(tokens)?));
+    }
+    let mut inner = &tokens[2..tokens.len() - 1];
+    let distinct = inner.first().is_some_and(|f| f.is_keyword("DISTINCT"));
+    if distinct {
+        inner = &inner[1..];
+    }
+    let args = match inner.is_empty() {
+        true => vec![],
+        false => split_commas(inner)
+            .iter()
+            .map(|f| expr(f))
+            .collect::<Result<Vec<Expr>, Error>>()?,
+    };
+    Ok(Expr::Function {
+        name: tokens[0].to_string(),
+        distinct,
+        args,
+    })
+}
+
+fn split_commas(tokens: &[Token]) -> Vec<Vec<Token>> {
+    let mut parts = vec![vec![]];
+    let mut depth = 0;
+    for token in tokens {
+        if token.is_symbol("(") {
+            depth += 1;
+        } else if token.is_symbol(")") {
+            depth -= 1;
+        } else if depth == 0 && token.is_symbol(",") {
+            parts.push(vec![]);
+            continue;
+        }
+        parts.last_mut().unwrap().push(token.clone());
+    }
+    parts
+}
+
+/// where clause as and conditions and or groups
+fn conditions(tokens: &[Token]) -> Result<(Vec<Condition>, Vec<Vec<Condition>>), Error> {
+    let mut where_and = vec![];
+    let mut where_or = vec![];
+    let parts = split(tokens, "OR");
+    if parts.len() > 1 {
+        let group = parts
+            .iter()
+            .map(|f| condition(f))
+            .collect::<Result<Vec<Condition>, Error>>()?;
+        return Ok((where_and, vec![group]));
+    }
+    for part in split(tokens, "AND") {
+        match unwrap_parens(&part) {
+            Some(inner) => {
+                let (and, or) = conditions(inner)?;
+                where_and.extend(and);
+                where_or.extend(or);
+            }
+            None => where_and.push(condition(&part)?),
+        }
+    }
+    Ok((where_and, where_or))
+}
+
+/// single condition, `expression operator literal`
+fn condition(tokens: &[Token]) -> Result<Condition, Error> {
+    if let Some(inner) = unwrap_parens(tokens) {
+        return condition(inner);
+    }
+    let unsupported = || -> Result<Condition, Error> {
+        if let Some(Token::Placeholder(value)) =
+            tokens.iter().find(|f| matches!(f, Token::Placeholder(_)))
+        {
+            return Err(placeholder_error(value));
+        }
+        Err(Error::Invalid(format!(
+            "unsupported condition {}, expected expression compared with literal",
+            written(tokens)
+        )))
+    };
+    let mut depth = 0;
+    for (idx, token) in tokens.iter().enumerate() {
+        if token.is_symbol("(") {
+            depth += 1;
+            continue;
+        } else if token.is_symbol(")") {
+            depth -= 1;
+            continue;
+        }
+        if depth > 0 || idx == 0 {
+            continue;
+        }
+        let operator_len = match token {
+            Token::Symbol(symbol)
+                if ["=", "<>", "!=", "<", ">", "<=", ">="].contains(&symbol.as_str()) =>
+            {
+                1
+            }
+            Token::Word(_) if token.is_keyword("LIKE") || token.is_keyword("ILIKE") => 1,
+            Token::Word(_)
+                if token.is_keyword("IS")
+                    && tokens.get(idx + 1).is_some_and(|f| f.is_keyword("NOT")) =>
+            {
+                2
+            }
+            Token::Word(_) if token.is_keyword("IS") => 1,
+            Token::Word(_)
+                if token.is_keyword("NOT")
+                    && tokens
+                        .get(idx + 1)
+                        .is_some_and(|f| f.is_keyword("LIKE") || f.is_keyword("ILIKE")) =>
+            {
+                2
+            }
+            _ => continue,
+        };
+        let operator = tokens[idx..idx + operator_len]
+            .iter()
+            .map(|f| f.to_string().to_uppercase())
+            .collect::<Vec<String>>()
+            .join(" ");
+        let value = match literal(&tokens[idx + operator_len..])? {
+            Some(value) => value,
+            None => return unsupported(),
+        };
+        if operator.starts_with("IS") && !matches!(value, Bind::Null | Bind::Bool(_)) {
+            return unsupported();
+        }
+        return Ok(Condition::new(&render(&tokens[..idx])?, &operator, value));
+    }
+    unsupported()
+}
@@ -0,0 +1,402 @@
+use std::fmt;
+
+use crate::{
+    dialect::{Dialect, IDENTIFIER_MARK},
+    error::Error,
+    scope::Scope,
+    Bind, Delete, Insert, Select, Update,
+};
+
+/// parsed statement, holds builder equivalent to parsed sql
+#[derive(Clone)]
+pub enum Statement {
+    Select(Box<Select>),
+    Insert(Insert),
+    Update(Update),
+    Delete(Delete),
+}
+
+impl Statement {
+    /// set sql dialect of parsed builder, used to re-render query for another database
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        match self {
+            Statement::Select(builder) => {
+                builder.dialect(dialect);
+            }
+            Statement::Insert(builder) => {
+                builder.dialect(dialect);
+            }
+            Statement::Update(builder) => {
+                builder.dialect(dialect);
+            }
+            Statement::Delete(builder) => {
+                builder.dialect(dialect);
+            }
+        }
+        self
+    }
+
+    /// apply scope on parsed select, update and delete, insert is kept as is
+    pub fn scope(&mut self, scope: &Scope) -> &mut Self {
+        match self {
+            Statement::Select(builder) => {
+                builder.scope(scope);
+            }
+            Statement::Insert(_) => {}
+            Statement::Update(builder) => {
+                builder.scope(scope);
+            }
+            Statement::Delete(builder) => {
+                builder.scope(scope);
+            }
+        }
+        self
+    }
+
+    /// get generated sql query and it's bind
+    pub fn to_sql_with_bind(&mut self) -> (String, Vec<Bind>) {
+        match self {
+            Statement::Select(builder) => builder.to_sql_with_bind(),
+            Statement::Insert(builder) => builder.to_sql_with_bind(),
+            Statement::Update(builder) => builder.to_sql_with_bind(),
+            Statement::Delete(builder) => builder.to_sql_with_bind(),
+        }
+    }
+
+    /// validate parsed builder then get generated sql query and it's bind
+    pub fn try_to_sql(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        match self {
+            Statement::Select(builder) => builder.try_to_sql(),
+            Statement::Insert(builder) => Ok(builder.to_sql_with_bind()),
+            Statement::Update(builder) => builder.try_to_sql(),
+            Statement::Delete(builder) => builder.try_to_sql(),
+        }
+    }
+}
+
+/// sql token, quoted identifiers and strings are stored without quotes
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Token {
+    Word(String),
+    Quoted(String),
+    String(String),
+    Number(String),
+    Placeholder(String),
+    Symbol(String),
+}
+
+/// reserved words, never read as alias and spaced before `(`
+const KEYWORDS: &[&str] = &[
+    "ALL",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CROSS",
+    "DESC",
+    "DISTINCT",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INNER",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NATURAL",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "RETURNING",
+    "RIGHT",
+    "SELECT",
+    "SET",
+    "SOME",
+    "THEN",
+    "TRUE",
+    "UNION",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+];
+
+impl Token {
+    pub(crate) fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    pub(crate) fn is_symbol(&self, symbol: &str) -> bool {
+        matches!(self, Token::Symbol(value) if value == symbol)
+    }
+
+    pub(crate) fn is_reserved(&self) -> bool {
+        match self {
+            Token::Word(word) => KEYWORDS.iter().any(|f| word.eq_ignore_ascii_case(f)),
+            _ => false,
+        }
+    }
+
+    /// identifier usable as table, column or alias name
+    pub(crate) fn is_identifier(&self) -> bool {
+        match self {
+            Token::Word(_) => !self.is_reserved(),
+            Token::Quoted(_) => true,
+            _ => false,
+        }
+    }
+
+    /// token as rendered into builder. quoted identifier is unquoted when it's lowercase plain name,
+    /// other quoted identifiers are marked to be quoted with builder dialect.
+    /// string literal is only supported where it's extracted into bind
+    pub(crate) fn to_sql(&self) -> Result<String, Error> {
+        match self {
+            Token::Quoted(value) => {
+                let plain = value.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+                    && value
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+                    && !Token::Word(value.clone()).is_reserved();
+                Ok(match plain {
+                    true => value.clone(),
+                    false => format!("{}{}{}", IDENTIFIER_MARK, value, IDENTIFIER_MARK),
+                })
+            }
+            Token::String(_) => Err(Error::Invalid(format!(
+                "string literal {} is only supported as condition, insert, update or select function value",
+                self
+            ))),
+            Token::Placeholder(value) => Err(placeholder_error(value)),
+            _ => Ok(self.to_string()),
+        }
+    }
+
+    /// literal value as bind
+    pub(crate) fn to_bind(&self) -> Result<Option<Bind>, Error> {
+        Ok(match self {
+            Token::String(value) => Some(Bind::String(value.clone())),
+            Token::Number(value) => Some(parse_number(value, false)?),
+            Token::Word(_) if self.is_keyword("NULL") => Some(Bind::Null),
+            Token::Word(_) if self.is_keyword("TRUE") => Some(Bind::Bool(true)),
+            Token::Word(_) if self.is_keyword("FALSE") => Some(Bind::Bool(false)),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for Token {
+    /// token as written in sql, used in error message
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(value) | Token::Number(value) | Token::Placeholder(value) => {
+                write!(f, "{}", value)
+            }
+            Token::Symbol(value) => write!(f, "{}", value),
+            Token::Quoted(value) => write!(f, "\"{}\"", value.replace('"', "\"\"")),
+            Token::String(value) => write!(f, "{}", Bind::String(value.clone()).to_literal(None)),
+        }
+    }
+}
+
+pub(crate) fn parse_number(value: &str, negative: bool) -> Result<Bind, Error> {
+    let value = match negative {
+        true => format!("-{}", value),
+        false => value.to_string(),
+    };
+    if value.contains(['.', 'e', 'E']) {
+        return value
+            .parse::<f64>()
+            .map(Bind::Float)
+            .map_err(|_| Error::Invalid(format!("invalid number {}", value)));
+    }
+    let number = value
+        .parse::<i64>()
+        .map_err(|_| Error::Invalid(format!("invalid number {}", value)))?;
+    Ok(match i32::try_from(number) {
+        Ok(number) => Bind::Int(number),
+        Err(_) => Bind::BigInt(number),
+    })
+}
+
+/// split sql into tokens, comments are dropped.
+/// `"` quotes identifier (string on mysql) and `` ` `` quotes identifier
+pub(crate) fn tokenize(sql: &str, dialect: Dialect) -> Result<Vec<Token>, Error> {
+    let chars = sql.chars().collect::<Vec<char>>();
+    let mut tokens = vec![];
+    let mut idx = 0;
+    while idx < chars.len() {
+        let c = chars[idx];
+        let next = chars.get(idx + 1).copied();
+        if c.is_whitespace() {
+            idx += 1;
+        } else if c == '-' && next == Some('-') {
+            while idx < chars.len() && chars[idx] != '\n' {
+                idx += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            idx += 2;
+            while idx < chars.len() && !(chars[idx] == '*' && chars.get(idx + 1) == Some(&'/')) {
+                idx += 1;
+            }
+            if idx >= chars.len() {
+                return Err(Error::Invalid("unterminated comment".to_string()));
+            }
+            idx += 2;
+        } else if c == '\'' || (c == '"' && dialect == Dialect::MySql) {
+            let (value, end) = read_quoted(&chars, idx, dialect == Dialect::MySql)?;
+            tokens.push(Token::String(value));
+            idx = end;
+        } else if c == '"' || c == '`' {
+            let (value, end) = read_quoted(&chars, idx, false)?;
+            tokens.push(Token::Quoted(value));
+            idx = end;
+        } else if c.is_ascii_digit() || (c == '.' && next.is_some_and(|f| f.is_ascii_digit())) {
+            let start = idx;
+            while idx < chars.len() && (chars[idx].is_ascii_alphanumeric() || chars[idx] == '.') {
+                // exponent sign, e.g. 1e-5
+                if matches!(chars[idx], 'e' | 'E')
+                    && matches!(chars.get(idx + 1), Some('+') | Some('-'))
+                {
+                    idx += 1;
+                }
+                idx += 1;
+            }
+            tokens.push(Token::Number(chars[start..idx].iter().collect()));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = idx;
+            while idx < chars.len()
+                && (chars[idx].is_alphanumeric() || chars[idx] == '_' || chars[idx] == '$')
+            {
+                idx += 1;
+            }
+            tokens.push(Token::Word(chars[start..idx].iter().collect()));
+        } else if c == '?' {
+            tokens.push(Token::Placeholder("?".to_string()));
+            idx += 1;
+        } else if (c == '$' && next.is_some_and(|f| f.is_ascii_digit()))
+            || (c == ':' && next.is_some_and(|f| f.is_alphabetic() || f == '_'))
+        {
+            let start = idx;
+            idx += 1;
+            while idx < chars.len() && (chars[idx].is_alphanumeric() || chars[idx] == '_') {
+                idx += 1;
+            }
+            tokens.push(Token::Placeholder(chars[start..idx].iter().collect()));
+        } else {
+            let pair = next.map(|f| format!("{}{}", c, f)).unwrap_or_default();
+            if ["<>", "!=", "<=", ">=", "||", "::"].contains(&pair.as_str()) {
+                tokens.push(Token::Symbol(pair));
+                idx += 2;
+            } else if "=<>+-*/%(),.;".contains(c) {
+                tokens.push(Token::Symbol(c.to_string()));
+                idx += 1;
+            } else {
+                return Err(Error::Invalid(format!("unexpected character {}", c)));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// read quoted value starting at `start`, doubled quote is an escaped quote.
+/// returns value and index after closing quote
+fn read_quoted(chars: &[char], start: usize, backslash: bool) -> Result<(String, usize), Error> {
+    let quote = chars[start];
+    let mut value = String::new();
+    let mut idx = start + 1;
+    while idx < chars.len() {
+        let c = chars[idx];
+        if backslash && c == '\\' && idx + 1 < chars.len() {
+            value.push(match chars[idx + 1] {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                other => other,
+            });
+            idx += 2;
+        } else if c == quote && chars.get(idx + 1) == Some(&quote) {
+            value.push(quote);
+            idx += 2;
+        } else if c == quote {
+            return Ok((value, idx + 1));
+        } else {
+            value.push(c);
+            idx += 1;
+        }
+    }
+    Err(Error::Invalid(format!("unterminated quote {}", quote)))
+}
+
+/// render tokens back into sql text, numbers and keyword literals are kept inline
+pub(crate) fn render(tokens: &[Token]) -> Result<String, Error> {
+    join(tokens, Token::to_sql)
+}
+
+/// tokens as written in sql, used in error message
+pub(crate) fn written(tokens: &[Token]) -> String {
+    join(tokens, |f| Ok(f.to_string())).unwrap_or_default()
+}
+
+fn join(tokens: &[Token], text: impl Fn(&Token) -> Result<String, Error>) -> Result<String, Error> {
+    let mut sql = String::new();
+    for (idx, token) in tokens.iter().enumerate() {
+        if idx > 0 && spaced(tokens, idx) {
+            sql.push(' ');
+        }
+        sql.push_str(text(token)?.as_str());
+    }
+    Ok(sql)
+}
+
+pub(crate) fn placeholder_error(placeholder: &str) -> Error {
+    Error::Invalid(format!(
+        "placeholder {} is not supported, write values as literals",
+        placeholder
+    ))
+}
+
+/// whether space is written between token `idx` and its previous token
+fn spaced(tokens: &[Token], idx: usize) -> bool {
+    let prev = &tokens[idx - 1];
+    let token = &tokens[idx];
+    if [",", ")", ".", "::"].iter().any(|f| token.is_symbol(f))
+        || ["(", ".", "::"].iter().any(|f| prev.is_symbol(f))
+    {
+        return false;
+    }
+    // function call
+    if token.is_symbol("(") && matches!(prev, Token::Word(_) | Token::Quoted(_)) {
+        return prev.is_reserved();
+    }
+    // unary minus
+    if prev.is_symbol("-") {
+        return match idx.checked_sub(2).map(|f| &tokens[f]) {
+            None => false,
+            Some(Token::Symbol(symbol)) => symbol == ")",
+            Some(token) => !token.is_reserved(),
+        };
+    }
+    true
+}
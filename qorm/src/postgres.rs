@@ -0,0 +1,119 @@
+//! postgres backend, enabled with `postgres` feature
+//!
+//! [`Bind`] implements [`ToSql`] so it can be passed to `tokio-postgres` or `postgres` client,
+//! and [`query`] renders builder with postgres dialect (`$1` placeholder).
+//! ```rust,no_run
+//! use qorm::{postgres::query, Bind, Select};
+//!
+//! async fn usernames(
+//!     client: &tokio_postgres::Client,
+//! ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+//!     let mut builder = Select::new("user", None, None);
+//!     builder.select("user.username").wheres("user.is_active", "=", Bind::Bool(true));
+//!     let statement = query(&mut builder)?;
+//!     let rows = client.query(statement.sql(), &statement.params()).await?;
+//!     Ok(rows.iter().map(|row| row.get("username")).collect())
+//! }
+//! ```
+use std::error::Error as StdError;
+
+use bytes::BytesMut;
+pub use postgres_types;
+use postgres_types::{to_sql_checked, IsNull, ToSql, Type, WrongType};
+
+use crate::{dialect::Dialect, error::Error, query::Query, Bind};
+
+/// Statement
+///
+/// generated sql and binds of builder, ready to be passed to postgres client
+#[derive(Clone, Debug)]
+pub struct Statement {
+    sql: String,
+    binds: Vec<Bind>,
+}
+
+impl Statement {
+    /// generated sql query
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// generated binds
+    pub fn binds(&self) -> &[Bind] {
+        &self.binds
+    }
+
+    /// binds as postgres parameters
+    /// ```rust
+    /// use qorm::{postgres::query, Bind, Delete};
+    ///
+    /// let mut builder = Delete::new("todo", None);
+    /// builder.wheres("id", "=", Bind::Int(1));
+    /// let statement = query(&mut builder).unwrap();
+    /// assert_eq!(statement.sql(), "DELETE FROM todo WHERE id = $1");
+    /// assert_eq!(statement.params().len(), 1);
+    /// ```
+    pub fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        self.binds
+            .iter()
+            .map(|f| f as &(dyn ToSql + Sync))
+            .collect()
+    }
+}
+
+/// build postgres statement from qorm builder, builder is rendered on postgres and left unchanged,
+/// fails when builder can not be rendered on postgres
+pub fn query<Q: Query>(query: &mut Q) -> Result<Statement, Error> {
+    let (sql, binds) = query.build_for(Dialect::Postgres)?;
+    Ok(Statement { sql, binds })
+}
+
+impl ToSql for Bind {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        match self {
+            Bind::Null => Ok(IsNull::Yes),
+            Bind::String(value) | Bind::Raw(value) if <&str as ToSql>::accepts(ty) => {
+                value.as_str().to_sql(ty, out)
+            }
+            Bind::Int(value) => int_to_sql(i64::from(*value), ty, out),
+            Bind::BigInt(value) => int_to_sql(*value, ty, out),
+            Bind::Bool(value) if *ty == Type::BOOL => value.to_sql(ty, out),
+            Bind::Float(value) if *ty == Type::FLOAT4 => (*value as f32).to_sql(ty, out),
+            Bind::Float(value) if *ty == Type::FLOAT8 => value.to_sql(ty, out),
+            Bind::Bytes(value) if *ty == Type::BYTEA => value.to_sql(ty, out),
+            _ => Err(Box::new(WrongType::new::<Bind>(ty.clone()))),
+        }
+    }
+
+    // Null fits every column type, so variant itself is checked against type on to_sql
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+/// encode integer with width of column type, out of range value is an error
+/// and float column only takes integer it can represent exactly
+fn int_to_sql(
+    value: i64,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+    let exact = |mantissa: u32| value.unsigned_abs() <= 1 << mantissa;
+    match *ty {
+        Type::INT2 => i16::try_from(value)?.to_sql(ty, out),
+        Type::INT4 => i32::try_from(value)?.to_sql(ty, out),
+        Type::INT8 => value.to_sql(ty, out),
+        Type::FLOAT4 if exact(24) => (value as f32).to_sql(ty, out),
+        Type::FLOAT8 if exact(53) => (value as f64).to_sql(ty, out),
+        Type::FLOAT4 | Type::FLOAT8 => {
+            Err(format!("{} can not be represented exactly as {}", value, ty).into())
+        }
+        _ => Err(Box::new(WrongType::new::<Bind>(ty.clone()))),
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    dialect::Dialect, error::Error, parse_item::Statement, schema_item::SchemaChange,
+    transaction_item::TransactionStatement, AlterTable, Bind, CreateIndex, CreateTable, Delete,
+    DropIndex, DropTable, Insert, RenameTable, Select, Update,
+};
+
+/// Query
+///
+/// common interface of qorm builders, used by executors
+/// ```rust
+/// use qorm::{query::Query, Bind, Delete};
+///
+/// fn render<Q: Query>(query: &mut Q) -> String {
+///     query.build().unwrap().0
+/// }
+///
+/// let mut builder = Delete::new("todo", None);
+/// builder.wheres("id", "=", Bind::Int(1));
+/// assert_eq!(render(&mut builder), "DELETE FROM todo WHERE id = ?");
+/// ```
+pub trait Query {
+    /// generated sql query and it's bind
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error>;
+
+    /// set sql dialect, used by backends to pick placeholder style
+    fn set_dialect(&mut self, dialect: Dialect);
+
+    /// generated sql query and it's bind on dialect, query itself is left unchanged.
+    /// used by backends, so executing builder doesn't switch its dialect
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error>;
+}
+
+/// build copy of query on dialect
+fn build_copy<Q: Query + Clone>(query: &Q, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+    let mut query = query.clone();
+    query.set_dialect(dialect);
+    query.build()
+}
+
+impl Query for Select {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        self.try_to_sql()
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for Insert {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok(self.to_sql_with_bind())
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for Update {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        self.try_to_sql()
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for Delete {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        self.try_to_sql()
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for CreateTable {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.try_to_sql()?, vec![]))
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for AlterTable {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.try_to_sql()?, vec![]))
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for DropTable {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.try_to_sql()?, vec![]))
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for RenameTable {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.to_sql(), vec![]))
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for CreateIndex {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.try_to_sql()?, vec![]))
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for DropIndex {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.try_to_sql()?, vec![]))
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+impl Query for SchemaChange {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.try_to_sql()?, vec![]))
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        match self {
+            SchemaChange::CreateTable(builder) => {
+                builder.dialect(dialect);
+            }
+            SchemaChange::AlterTable(builder) => {
+                builder.dialect(dialect);
+            }
+            SchemaChange::CreateIndex(builder) => {
+                builder.dialect(dialect);
+            }
+        }
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
+
+/// raw sql with binds, placeholders are kept as is
+impl Query for (String, Vec<Bind>) {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok(self.clone())
+    }
+
+    fn set_dialect(&mut self, _dialect: Dialect) {}
+
+    fn build_for(&self, _dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        Ok(self.clone())
+    }
+}
+
+impl Query for TransactionStatement {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.to_sql(), vec![]))
+    }
+
+    // Same sql on every dialect
+    fn set_dialect(&mut self, _dialect: Dialect) {}
+
+    fn build_for(&self, _dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        Ok((self.to_sql(), vec![]))
+    }
+}
+
+impl Query for Statement {
+    fn build(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        self.try_to_sql()
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect(dialect);
+    }
+
+    fn build_for(&self, dialect: Dialect) -> Result<(String, Vec<Bind>), Error> {
+        build_copy(self, dialect)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::dialect::{quote_identifier, Dialect};
+
+/// sql rename table builder
+#[derive(Clone, Debug)]
+pub struct RenameTable {
+    pub table_name: String,
+    pub new_name: String,
+    dialect: Option<Dialect>,
+}
+
+impl RenameTable {
+    /// Initialize RenameTable builder, `RENAME TABLE` on mysql and `ALTER TABLE .. RENAME TO` otherwise
+    /// ```rust
+    /// use qorm::{Dialect, RenameTable};
+    ///
+    /// let mut builder = RenameTable::new("todo", "task");
+    /// assert_eq!(builder.to_sql(), r#"ALTER TABLE todo RENAME TO task"#);
+    /// builder.dialect(Dialect::MySql);
+    /// assert_eq!(builder.to_sql(), "RENAME TABLE `todo` TO `task`");
+    /// ```
+    pub fn new(table_name: &str, new_name: &str) -> Self {
+        Self {
+            table_name: table_name.to_string(),
+            new_name: new_name.to_string(),
+            dialect: None,
+        }
+    }
+
+    /// set sql dialect, used for quoting and syntax
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// get generated sql query
+    pub fn to_sql(&self) -> String {
+        let from = quote_identifier(self.dialect, &self.table_name);
+        let to = quote_identifier(self.dialect, &self.new_name);
+        match self.dialect {
+            Some(Dialect::MySql) => format!("RENAME TABLE {} TO {}", from, to),
+            _ => format!("ALTER TABLE {} RENAME TO {}", from, to),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{error::Error, Bind};
+
+/// Row
+///
+/// database row, implemented by backend adapters (`sqlite` feature)
+pub trait Row {
+    /// column value by index
+    fn get_bind(&self, index: usize) -> Result<Bind, Error>;
+
+    /// column index by name
+    fn column_index(&self, name: &str) -> Result<usize, Error>;
+
+    /// decoded column value by index
+    fn get_index<T: FromBind>(&self, index: usize) -> Result<T, Error>
+    where
+        Self: Sized,
+    {
+        T::from_bind(self.get_bind(index)?)
+            .map_err(|err| Error::Decode(format!("column {}: {}", index, err)))
+    }
+
+    /// decoded column value by name
+    fn get<T: FromBind>(&self, name: &str) -> Result<T, Error>
+    where
+        Self: Sized,
+    {
+        T::from_bind(self.get_bind(self.column_index(name)?)?)
+            .map_err(|err| Error::Decode(format!("column {}: {}", name, err)))
+    }
+}
+
+/// FromRow
+///
+/// struct decoded from [`Row`], usually generated with `#[derive(FromRow)]` (`derive` feature)
+/// ```rust
+/// use qorm::{row::{FromRow, Row}, Bind, Error, Select};
+///
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// impl FromRow for User {
+///     const COLUMNS: &'static [&'static str] = &["id", "name"];
+///
+///     fn from_row<R: Row>(row: &R) -> Result<Self, Error> {
+///         Ok(Self {
+///             id: row.get("id")?,
+///             name: row.get("name")?,
+///         })
+///     }
+/// }
+///
+/// let mut builder = Select::new("user", Some("u"), None);
+/// builder.select_row::<User>();
+/// assert_eq!(builder.to_sql(), r#"SELECT u.id, u.name FROM user u"#);
+///
+/// let row = vec![("id", Bind::Int(1)), ("name", Bind::String("foo".to_string()))];
+/// let user = User::from_row(&row).unwrap();
+/// assert_eq!(user.id, 1);
+/// assert_eq!(user.name, "foo");
+/// ```
+pub trait FromRow: Sized {
+    /// selected columns on field order
+    const COLUMNS: &'static [&'static str];
+
+    fn from_row<R: Row>(row: &R) -> Result<Self, Error>;
+}
+
+/// FromBind
+///
+/// value decoded from [`Bind`]
+pub trait FromBind: Sized {
+    fn from_bind(value: Bind) -> Result<Self, Error>;
+}
+
+fn mismatch<T>(expected: &str, value: Bind) -> Result<T, Error> {
+    Err(Error::Decode(format!(
+        "expected {}, found {:?}",
+        expected, value
+    )))
+}
+
+impl FromBind for Bind {
+    fn from_bind(value: Bind) -> Result<Self, Error> {
+        Ok(value)
+    }
+}
+
+impl FromBind for String {
+    fn from_bind(value: Bind) -> Result<Self, Error> {
+        match value {
+            Bind::String(value) | Bind::Raw(value) => Ok(value),
+            _ => mismatch("string", value),
+        }
+    }
+}
+
+impl FromBind for i32 {
+    fn from_bind(value: Bind) -> Result<Self, Error> {
+        match value {
+            Bind::Int(value) => Ok(value),
+            Bind::BigInt(big) => match i32::try_from(big) {
+                Ok(value) => Ok(value),
+                Err(_) => mismatch("i32", Bind::BigInt(big)),
+            },
+            _ => mismatch("i32", value),
+        }
+    }
+}
+
+impl FromBind for i64 {
+    fn from_bind(value: Bind) -> Result<Self, Error> {
+        match value {
+            Bind::Int(value) => Ok(i64::from(value)),
+            Bind::BigInt(value) => Ok(value),
+            _ => mismatch("i64", value),
+        }
+    }
+}
+
+impl FromBind for f64 {
+    fn from_bind(value: Bind) -> Result<Self, Error> {
+        match value {
+            Bind::Float(value) => Ok(value),
+            Bind::Int(value) => Ok(f64::from(value)),
+            Bind::BigInt(value) => Ok(value as f64),
+            _ => mismatch("f64", value),
+        }
+    }
+}
+
+impl FromBind for bool {
+    fn from_bind(value: Bind) -> Result<Self, Error> {
+        match value {
+            Bind::Bool(value) => Ok(value),
+            Bind::Int(value) => Ok(value != 0),
+            Bind::BigInt(value) => Ok(value != 0),
+            _ => mismatch("bool", value),
+        }
+    }
+}
+
+impl FromBind for Vec<u8> {
+    fn from_bind(value: Bind) -> Result<Self, Error> {
+        match value {
+            Bind::Bytes(value) => Ok(value),
+            Bind::String(value) => Ok(value.into_bytes()),
+            _ => mismatch("bytes", value),
+        }
+    }
+}
+
+impl<T: FromBind> FromBind for Option<T> {
+    fn from_bind(value: Bind) -> Result<Self, Error> {
+        match value {
+            Bind::Null => Ok(None),
+            _ => T::from_bind(value).map(Some),
+        }
+    }
+}
+
+/// in memory row of column name and value pairs
+impl Row for Vec<(&str, Bind)> {
+    fn get_bind(&self, index: usize) -> Result<Bind, Error> {
+        match self.as_slice().get(index) {
+            Some((_, value)) => Ok(value.clone()),
+            None => Err(Error::Decode(format!("column {} not found", index))),
+        }
+    }
+
+    fn column_index(&self, name: &str) -> Result<usize, Error> {
+        match self.iter().position(|(column, _)| *column == name) {
+            Some(index) => Ok(index),
+            None => Err(Error::Decode(format!("column {} not found", name))),
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{
+    alter_table_item::AlterOperation,
+    dialect::Dialect,
+    error::Error,
+    executor::Executor,
+    schema_item::{ColumnInfo, NameRow, SchemaChange, TableDef, TableInfo},
+    table::TableSchema,
+    AlterTable, Bind,
+};
+
+/// schema diff generator
+///
+/// compares desired table definitions with current database schema and generates
+/// `CREATE TABLE`, `ALTER TABLE` and `CREATE INDEX` statements reconciling them.
+/// column types are compared by base type name (length and precision are ignored, sqlite compares
+/// type affinity), defaults are not compared and indexes missing from definition are kept.
+/// columns missing from definition are dropped only with [`SchemaDiff::allow_drop`],
+/// otherwise they are reported by [`SchemaDiff::drops`] and kept.
+/// ```rust
+/// use qorm::{
+///     create_table_item::{ColumnDef, ColumnType},
+///     schema::SchemaDiff,
+///     schema_item::{ColumnInfo, TableDef, TableInfo},
+///     CreateIndex, Dialect,
+/// };
+///
+/// let mut table = TableDef::new("todo");
+/// table
+///     .column(ColumnDef::new("id", ColumnType::BigInt).primary_key())
+///     .column(ColumnDef::new("name", ColumnType::Text).not_null())
+///     .column(ColumnDef::new("point", ColumnType::BigInt).not_null())
+///     .index(CreateIndex::new("todo_name_idx", "todo").column("name"));
+///
+/// let current = vec![TableInfo {
+///     name: "todo".to_string(),
+///     columns: vec![
+///         ColumnInfo { name: "id".to_string(), data_type: "bigint".to_string(), nullable: false },
+///         ColumnInfo { name: "name".to_string(), data_type: "text".to_string(), nullable: true },
+///         ColumnInfo { name: "point".to_string(), data_type: "integer".to_string(), nullable: false },
+///         ColumnInfo { name: "note".to_string(), data_type: "text".to_string(), nullable: true },
+///     ],
+///     indexes: vec!["todo_pkey".to_string()],
+/// }];
+///
+/// let mut diff = SchemaDiff::new(Dialect::Postgres);
+/// diff.table_def(&table);
+/// let changes = diff.diff(&current).unwrap();
+/// assert_eq!(
+///     changes.iter().map(|f| f.to_sql()).collect::<Vec<String>>(),
+///     vec![
+///         r#"ALTER TABLE "todo" ALTER COLUMN "name" SET NOT NULL, ALTER COLUMN "point" TYPE BIGINT"#,
+///         r#"CREATE INDEX "todo_name_idx" ON "todo" ("name")"#,
+///     ]
+/// );
+/// assert_eq!(diff.drops(&current), vec![("todo".to_string(), "note".to_string())]);
+///
+/// diff.allow_drop(true);
+/// assert_eq!(
+///     diff.diff(&current).unwrap()[0].to_sql(),
+///     r#"ALTER TABLE "todo" ALTER COLUMN "name" SET NOT NULL, ALTER COLUMN "point" TYPE BIGINT, DROP COLUMN "note""#
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct SchemaDiff {
+    dialect: Dialect,
+    tables: Vec<TableDef>,
+    allow_drop: bool,
+}
+
+impl SchemaDiff {
+    /// Initialize SchemaDiff, dialect is used to read schema and render statements
+    pub fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            tables: vec![],
+            allow_drop: false,
+        }
+    }
+
+    /// drop columns missing from definition, off by default since dropping column destroys its data
+    pub fn allow_drop(&mut self, allow: bool) -> &mut Self {
+        self.allow_drop = allow;
+        self
+    }
+
+    /// add desired table from table descriptor, usually generated with `#[derive(Table)]`
+    pub fn table<T: TableSchema>(&mut self) -> &mut Self {
+        self.tables.push(TableDef::from_table::<T>());
+        self
+    }
+
+    /// add desired table definition
+    pub fn table_def(&mut self, table: &TableDef) -> &mut Self {
+        self.tables.push(table.clone());
+        self
+    }
+
+    /// read current schema of desired tables, missing tables are omitted.
+    /// uses `sqlite_master` on sqlite and `information_schema` on postgres and mysql.
+    /// it runs through [`Executor`], which is only implemented for sqlite connections today,
+    /// on postgres and mysql read schema yourself and pass it to [`SchemaDiff::diff`]
+    pub fn read<E: Executor>(&self, executor: &mut E) -> Result<Vec<TableInfo>, Error> {
+        let (columns_sql, indexes_sql) = self.schema_sql();
+        let mut result = vec![];
+        for table in self.tables.iter() {
+            let columns: Vec<ColumnInfo> = executor
+                .fetch_all(&mut (columns_sql.clone(), vec![Bind::String(table.name.clone())]))?;
+            if columns.is_empty() {
+                continue;
+            }
+            let indexes: Vec<NameRow> = executor
+                .fetch_all(&mut (indexes_sql.clone(), vec![Bind::String(table.name.clone())]))?;
+            result.push(TableInfo {
+                name: table.name.clone(),
+                columns,
+                indexes: indexes.into_iter().map(|f| f.name).collect(),
+            });
+        }
+        Ok(result)
+    }
+
+    /// columns and indexes query of dialect, table name is the only bind
+    fn schema_sql(&self) -> (String, String) {
+        match self.dialect {
+            Dialect::Sqlite => (
+                r#"SELECT name, type AS data_type, CASE WHEN "notnull" = 0 THEN 1 ELSE 0 END AS nullable FROM pragma_table_info(?) ORDER BY cid"#
+                    .to_string(),
+                "SELECT name FROM sqlite_master WHERE type = 'index' AND tbl_name = ?".to_string(),
+            ),
+            Dialect::Postgres => (
+                "SELECT column_name AS name, data_type, CASE WHEN is_nullable = 'YES' THEN 1 ELSE 0 END AS nullable FROM information_schema.columns WHERE table_schema = current_schema() AND table_name = $1 ORDER BY ordinal_position"
+                    .to_string(),
+                "SELECT indexname AS name FROM pg_indexes WHERE schemaname = current_schema() AND tablename = $1"
+                    .to_string(),
+            ),
+            Dialect::MySql => (
+                "SELECT column_name AS name, data_type AS data_type, CASE WHEN is_nullable = 'YES' THEN 1 ELSE 0 END AS nullable FROM information_schema.columns WHERE table_schema = DATABASE() AND table_name = ? ORDER BY ordinal_position"
+                    .to_string(),
+                "SELECT DISTINCT index_name AS name FROM information_schema.statistics WHERE table_schema = DATABASE() AND table_name = ?"
+                    .to_string(),
+            ),
+        }
+    }
+
+    /// statements reconciling current schema with desired tables.
+    /// sqlite gets one `ALTER TABLE` per operation, changes the dialect can not express in place
+    /// (e.g. column type on sqlite) return [`Error::Unsupported`]
+    pub fn diff(&self, current: &[TableInfo]) -> Result<Vec<SchemaChange>, Error> {
+        let mut changes = vec![];
+        for table in self.tables.iter() {
+            if table.columns.is_empty() {
+                return Err(Error::Invalid(format!(
+                    "table {} has no column definitions",
+                    table.name
+                )));
+            }
+            match current.iter().find(|f| f.name == table.name) {
+                Some(info) => self.diff_table(table, info, &mut changes),
+                None => {
+                    let mut builder = table.to_create_table();
+                    builder.dialect(self.dialect);
+                    changes.push(SchemaChange::CreateTable(builder));
+                }
+            }
+            for index in table.indexes.iter() {
+                let exists = current
+                    .iter()
+                    .any(|f| f.name == table.name && f.indexes.contains(&index.index_name));
+                if !exists {
+                    let mut builder = index.clone();
+                    builder.dialect(self.dialect);
+                    changes.push(SchemaChange::CreateIndex(builder));
+                }
+            }
+        }
+        for change in changes.iter() {
+            change.validate()?;
+        }
+        Ok(changes)
+    }
+
+    /// columns of current schema missing from desired tables as `(table, column)`,
+    /// `diff` drops them only with [`SchemaDiff::allow_drop`]
+    pub fn drops(&self, current: &[TableInfo]) -> Vec<(String, String)> {
+        let mut result = vec![];
+        for table in self.tables.iter() {
+            if let Some(info) = current.iter().find(|f| f.name == table.name) {
+                for column in info.columns.iter() {
+                    if !table.columns.iter().any(|f| f.name == column.name) {
+                        result.push((table.name.clone(), column.name.clone()));
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// read current schema and diff it, see [`SchemaDiff::read`] for supported backends
+    pub fn generate<E: Executor>(&self, executor: &mut E) -> Result<Vec<SchemaChange>, Error> {
+        let current = self.read(executor)?;
+        self.diff(&current)
+    }
+
+    fn diff_table(&self, table: &TableDef, info: &TableInfo, changes: &mut Vec<SchemaChange>) {
+        let mut operations = vec![];
+        for column in table.columns.iter() {
+            let current = match info.columns.iter().find(|f| f.name == column.name) {
+                Some(current) => current,
+                None => {
+                    operations.push(AlterOperation::AddColumn(column.clone()));
+                    continue;
+                }
+            };
+            let type_changed = self.normalize_type(&column.column_type.to_sql(Some(self.dialect)))
+                != self.normalize_type(&current.data_type);
+            // primary key implies not null, sqlite still reports it nullable
+            let null_changed = !column.primary_key && column.not_null == current.nullable;
+            if self.dialect == Dialect::MySql {
+                if type_changed || null_changed {
+                    operations.push(AlterOperation::ModifyColumn(column.clone()));
+                }
+                continue;
+            }
+            if type_changed {
+                operations.push(AlterOperation::AlterType(
+                    column.name.clone(),
+                    column.column_type.clone(),
+                ));
+            }
+            if null_changed {
+                operations.push(match column.not_null {
+                    true => AlterOperation::SetNotNull(column.name.clone()),
+                    false => AlterOperation::DropNotNull(column.name.clone()),
+                });
+            }
+        }
+        if self.allow_drop {
+            for (_, column) in self.drops(std::slice::from_ref(info)) {
+                operations.push(AlterOperation::DropColumn(column));
+            }
+        }
+        if operations.is_empty() {
+            return;
+        }
+        let mut builder = AlterTable::new(&table.name);
+        builder.dialect(self.dialect);
+        if self.dialect == Dialect::Sqlite {
+            for operation in operations {
+                let mut builder = builder.clone();
+                builder.operations.push(operation);
+                changes.push(SchemaChange::AlterTable(builder));
+            }
+            return;
+        }
+        builder.operations = operations;
+        changes.push(SchemaChange::AlterTable(builder));
+    }
+
+    /// comparable type name, sqlite types are compared by affinity
+    fn normalize_type(&self, data_type: &str) -> String {
+        let upper = data_type.to_uppercase();
+        let base = upper.split('(').next().unwrap_or_default().trim();
+        if self.dialect == Dialect::Sqlite {
+            let affinity = if base.contains("INT") {
+                "INTEGER"
+            } else if base.contains("CHAR") || base.contains("CLOB") || base.contains("TEXT") {
+                "TEXT"
+            } else if base.contains("BLOB") || base.is_empty() {
+                "BLOB"
+            } else if base.contains("REAL") || base.contains("FLOA") || base.contains("DOUB") {
+                "REAL"
+            } else {
+                "NUMERIC"
+            };
+            return affinity.to_string();
+        }
+        match base {
+            "INT" | "INT4" | "INTEGER" | "MEDIUMINT" => "INTEGER",
+            "INT2" | "SMALLINT" => "SMALLINT",
+            "INT8" | "BIGINT" => "BIGINT",
+            "BOOL" | "BOOLEAN" | "TINYINT" => "BOOLEAN",
+            "FLOAT4" | "REAL" | "FLOAT" => "REAL",
+            "FLOAT8" | "DOUBLE" | "DOUBLE PRECISION" => "DOUBLE",
+            "NUMERIC" | "DECIMAL" => "DECIMAL",
+            "CHARACTER VARYING" | "VARCHAR" => "VARCHAR",
+            "CHARACTER" | "CHAR" | "BPCHAR" => "CHAR",
+            "TEXT" | "MEDIUMTEXT" | "LONGTEXT" => "TEXT",
+            "BYTEA" | "BLOB" | "MEDIUMBLOB" | "LONGBLOB" => "BYTES",
+            "TIMESTAMP WITHOUT TIME ZONE" | "TIMESTAMP" | "DATETIME" => "TIMESTAMP",
+            "TIMESTAMP WITH TIME ZONE" | "TIMESTAMPTZ" => "TIMESTAMPTZ",
+            "TIME WITHOUT TIME ZONE" | "TIME" => "TIME",
+            "JSON" | "JSONB" => "JSON",
+            other => other,
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    create_table_item::ColumnDef,
+    error::Error,
+    row::{FromRow, Row},
+    table::TableSchema,
+    AlterTable, CreateIndex, CreateTable,
+};
+
+/// TableDef
+///
+/// desired table definition compared by [`crate::schema::SchemaDiff`]
+/// ```rust
+/// use qorm::{
+///     create_table_item::{ColumnDef, ColumnType},
+///     schema_item::TableDef,
+///     CreateIndex,
+/// };
+///
+/// let mut table = TableDef::new("todo");
+/// table
+///     .column(ColumnDef::new("id", ColumnType::BigInt).primary_key())
+///     .column(ColumnDef::new("name", ColumnType::Text).not_null())
+///     .index(CreateIndex::new("todo_name_idx", "todo").column("name"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TableDef {
+    pub name: String,
+    pub(crate) columns: Vec<ColumnDef>,
+    pub(crate) indexes: Vec<CreateIndex>,
+}
+
+impl TableDef {
+    /// Initialize empty table definition
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            columns: vec![],
+            indexes: vec![],
+        }
+    }
+
+    /// table definition from table descriptor, usually generated with `#[derive(Table)]`
+    /// where column types are read from field types
+    pub fn from_table<T: TableSchema>() -> Self {
+        Self {
+            name: T::TABLE.to_string(),
+            columns: T::column_defs(),
+            indexes: T::indexes(),
+        }
+    }
+
+    /// add column definition
+    pub fn column(&mut self, column: &ColumnDef) -> &mut Self {
+        self.columns.push(column.clone());
+        self
+    }
+
+    /// add index
+    pub fn index(&mut self, index: &CreateIndex) -> &mut Self {
+        self.indexes.push(index.clone());
+        self
+    }
+
+    /// create table builder of definition, indexes are not included
+    pub fn to_create_table(&self) -> CreateTable {
+        let mut builder = CreateTable::new(&self.name);
+        for column in self.columns.iter() {
+            builder.column(column);
+        }
+        builder
+    }
+}
+
+/// TableInfo
+///
+/// current table schema read from database
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableInfo {
+    pub name: String,
+    pub columns: Vec<ColumnInfo>,
+    /// index names
+    pub indexes: Vec<String>,
+}
+
+/// ColumnInfo
+///
+/// current column schema read from database, `data_type` is reported by database
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+}
+
+impl FromRow for ColumnInfo {
+    const COLUMNS: &'static [&'static str] = &["name", "data_type", "nullable"];
+
+    fn from_row<R: Row>(row: &R) -> Result<Self, Error> {
+        Ok(Self {
+            name: row.get("name")?,
+            data_type: row.get("data_type")?,
+            nullable: row.get::<i64>("nullable")? != 0,
+        })
+    }
+}
+
+/// name column row of catalog query, e.g. index or table name
+pub(crate) struct NameRow {
+    pub(crate) name: String,
+}
+
+impl FromRow for NameRow {
+    const COLUMNS: &'static [&'static str] = &["name"];
+
+    fn from_row<R: Row>(row: &R) -> Result<Self, Error> {
+        Ok(Self {
+            name: row.get("name")?,
+        })
+    }
+}
+
+/// SchemaChange
+///
+/// statement generated by [`crate::schema::SchemaDiff`]
+#[derive(Clone, Debug)]
+pub enum SchemaChange {
+    CreateTable(CreateTable),
+    AlterTable(AlterTable),
+    CreateIndex(CreateIndex),
+}
+
+impl SchemaChange {
+    /// check statement against its dialect
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            SchemaChange::CreateTable(builder) => builder.validate(),
+            SchemaChange::AlterTable(builder) => builder.validate(),
+            SchemaChange::CreateIndex(builder) => builder.validate(),
+        }
+    }
+
+    /// get generated sql query
+    pub fn to_sql(&self) -> String {
+        match self {
+            SchemaChange::CreateTable(builder) => builder.to_sql(),
+            SchemaChange::AlterTable(builder) => builder.to_sql(),
+            SchemaChange::CreateIndex(builder) => builder.to_sql(),
+        }
+    }
+
+    /// validate builder then get generated sql query
+    pub fn try_to_sql(&self) -> Result<String, Error> {
+        match self {
+            SchemaChange::CreateTable(builder) => builder.try_to_sql(),
+            SchemaChange::AlterTable(builder) => builder.try_to_sql(),
+            SchemaChange::CreateIndex(builder) => builder.try_to_sql(),
+        }
+    }
+}
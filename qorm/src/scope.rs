@@ -0,0 +1,164 @@
+use crate::{
+    dialect::IDENTIFIER_MARK, error::Error, scope_item::Filter, where_item::Condition, Bind,
+};
+
+/// Scope
+///
+/// registry of global filters, e.g. tenant column. builder with scope adds filter condition for every
+/// referenced table that has filter: selected table and updated or deleted table on where, joined table
+/// on join condition (so `LEFT JOIN` keeps unmatched rows). conditions are added at render time,
+/// query held by builder is not changed. subqueries are not scoped, scoped builder holding raw subquery
+/// fails validation and renders condition matching no row
+/// ```rust
+/// use qorm::{scope::Scope, Bind, Delete, Select, Update};
+///
+/// let mut scope = Scope::new();
+/// for table in ["todo", "user"] {
+///     scope.filter(table, "tenant_id", "=", Bind::Int(7));
+/// }
+///
+/// let mut builder = Select::new("todo", Some("t"), None);
+/// builder.join(Some("LEFT"), "user u", "u.id = t.created_by");
+/// builder.wheres("t.is_done", "=", Bind::Bool(false));
+/// builder.scope(&scope);
+/// assert_eq!(
+///     builder.to_sql_with_bind(),
+///     (
+///         "SELECT * FROM todo t LEFT JOIN user u ON (u.id = t.created_by) AND u.tenant_id = ? WHERE t.tenant_id = ? AND t.is_done = ?".to_string(),
+///         vec![Bind::Int(7), Bind::Int(7), Bind::Bool(false)]
+///     )
+/// );
+///
+/// let mut builder = Update::new("todo", None);
+/// builder.set(vec![("is_done", Bind::Bool(true))]).scope(&scope);
+/// assert_eq!(builder.to_sql(), "UPDATE todo SET is_done = ? WHERE tenant_id = ?");
+///
+/// let mut builder = Delete::new("todo", None);
+/// builder.wheres("id", "=", Bind::Int(1)).scope(&scope);
+/// assert_eq!(builder.to_sql(), "DELETE FROM todo WHERE tenant_id = ? AND id = ?");
+/// ```
+///
+/// builder marked unscoped ignores scope, e.g. admin query
+/// ```rust
+/// use qorm::{scope::Scope, Bind, Select};
+///
+/// let mut scope = Scope::new();
+/// scope.filter("todo", "tenant_id", "=", Bind::Int(7));
+///
+/// let mut builder = Select::new("todo", None, None);
+/// builder.unscoped().scope(&scope);
+/// assert_eq!(builder.to_sql(), "SELECT * FROM todo todo");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Scope {
+    filters: Vec<Filter>,
+}
+
+impl Scope {
+    /// Initialize empty Scope
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// add filter for table, table is matched by name, not alias. quotes, schema prefix and case
+    /// are ignored, so filter of `todo` also applies to `public.todo` and `"todo"`
+    pub fn filter(&mut self, table: &str, column: &str, operator: &str, value: Bind) -> &mut Self {
+        self.filters.push(Filter {
+            table: table.to_string(),
+            column: column.to_string(),
+            operator: operator.to_string(),
+            value,
+        });
+        self
+    }
+
+    /// registered filters
+    pub fn filters(&self) -> &[Filter] {
+        &self.filters
+    }
+
+    /// conditions of table, column is prefixed with `qualifier` (table alias or name) when given
+    /// ```rust
+    /// use qorm::{scope::Scope, where_item::Condition, Bind};
+    ///
+    /// let mut scope = Scope::new();
+    /// scope.filter("todo", "tenant_id", "=", Bind::Int(7));
+    /// assert_eq!(
+    ///     scope.conditions("todo", Some("t")),
+    ///     vec![Condition::new("t.tenant_id", "=", Bind::Int(7))]
+    /// );
+    /// assert_eq!(scope.conditions(r#"public."Todo""#, None).len(), 1);
+    /// assert!(scope.conditions("user", None).is_empty());
+    /// ```
+    pub fn conditions(&self, table: &str, qualifier: Option<&str>) -> Vec<Condition> {
+        let table = table_key(table);
+        self.filters
+            .iter()
+            .filter(|f| table_key(&f.table) == table)
+            .map(|f| {
+                let column = match qualifier {
+                    Some(qualifier) => format!("{}.{}", qualifier, f.column),
+                    None => f.column.clone(),
+                };
+                Condition::new(&column, &f.operator, f.value.clone())
+            })
+            .collect()
+    }
+}
+
+/// table name without quotes and schema prefix, lowercased
+fn table_key(table: &str) -> String {
+    let name = table.rsplit('.').next().unwrap_or(table);
+    name.trim_matches(|c| matches!(c, '"' | '`' | '[' | ']' | IDENTIFIER_MARK))
+        .to_lowercase()
+}
+
+/// condition matching no row, rendered by scoped builder holding raw subquery
+/// so nothing outside scope is read or written when it's rendered without validation
+pub(crate) fn guard() -> Condition {
+    Condition::new("1", "=", Bind::Int(0))
+}
+
+/// scoped builder rejects raw sql holding subquery, filters can not be applied inside it
+pub(crate) fn validate_raw(raws: Vec<String>) -> Result<(), Error> {
+    match raws.iter().find(|f| is_subquery(f)) {
+        Some(raw) => Err(Error::Invalid(format!(
+            "scope can not be applied inside raw subquery `{}`, build it unscoped with filters written by hand",
+            raw.trim()
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// raw sql of conditions, values are bound so only column is raw
+pub(crate) fn condition_raws<'a>(
+    conditions: impl IntoIterator<Item = &'a Condition>,
+) -> Vec<String> {
+    conditions.into_iter().map(|f| f.column.clone()).collect()
+}
+
+/// `SELECT` keyword outside of quoted string or identifier
+fn is_subquery(raw: &str) -> bool {
+    let mut quote = None;
+    let mut word = String::new();
+    for c in raw.chars().chain([' ']) {
+        if let Some(open) = quote {
+            if c == open {
+                quote = None;
+            }
+            continue;
+        }
+        if c.is_alphanumeric() || c == '_' {
+            word.push(c);
+            continue;
+        }
+        if word.eq_ignore_ascii_case("select") {
+            return true;
+        }
+        word.clear();
+        if c == '\'' || c == '"' || c == '`' {
+            quote = Some(c);
+        }
+    }
+    false
+}
@@ -0,0 +1,10 @@
+use crate::Bind;
+
+/// filter of [`crate::scope::Scope`], `column operator value` added for every reference of `table`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter {
+    pub table: String,
+    pub column: String,
+    pub operator: String,
+    pub value: Bind,
+}
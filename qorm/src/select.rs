@@ -1,39 +1,36 @@
-use crate::{select_item::SelectConfig, table::Table, where_item::Or, Bind};
+use crate::{
+    bind::{debug_sql, DEBUG_PLACEHOLDER},
+    dialect::{quote_identifier, quote_marked, Dialect},
+    error::Error,
+    expr::Expr,
+    row::FromRow,
+    scope::{condition_raws, guard, validate_raw, Scope},
+    select_item::{Join, LockStrength, LockWait, SelectColumn, SelectConfig, SelectStatement},
+    table::{Table, TableSchema},
+    where_item::{Condition, Or},
+    window::{Window, WindowFunction},
+    Bind,
+};
 
 fn is_index(pattern: String) -> bool {
     pattern.contains("%d")
 }
 
-#[derive(Clone, Debug)]
-struct WhereInternal {
-    pub column: String,
-    pub operator: String,
-    pub value: Bind,
-}
-
-#[derive(Clone, Debug)]
-struct JoinInternal {
-    pub join_type: Option<String>,
-    pub table_name: String,
-    pub on: String,
-}
-
 /// Select
 ///
 /// qorm sql select builder
+#[derive(Clone)]
 pub struct Select {
+    /// table selected from, rendered `FROM` table and `table` of [`Select::ast`]
     pub table_name: Table,
+    ast: SelectStatement,
     config: SelectConfig,
-    select: Option<Vec<String>>,
-    join: Option<Vec<JoinInternal>>,
-    where_and: Option<Vec<WhereInternal>>,
-    where_or: Option<Vec<Vec<WhereInternal>>>,
-    order_by_query: Option<Vec<String>>,
-    group_by_query: Option<Vec<String>>,
-    limit: Option<i64>,
-    offset: Option<i64>,
+    custom_config: bool,
     bind_index: Option<i32>,
     binds: Vec<Bind>,
+    count: bool,
+    scope: Option<Scope>,
+    unscoped: bool,
 }
 
 impl Select {
@@ -71,6 +68,7 @@ impl Select {
     /// assert_eq!(sql, "SELECT * FROM todo todo WHERE todo.id = #0 AND todo.name = #1");
     /// ```
     pub fn new(table_name: &str, alias: Option<&str>, config: Option<SelectConfig>) -> Self {
+        let custom_config = config.is_some();
         let config_select = match config {
             Some(data) => data,
             None => SelectConfig {
@@ -79,26 +77,301 @@ impl Select {
             },
         };
         let bind_index = is_index(config_select.clone().placeholder);
+        let table = Table {
+            name: table_name.to_string(),
+            alias: alias.map(|x| x.to_string()),
+        };
         Self {
-            table_name: Table {
-                name: table_name.to_string(),
-                alias: alias.map(|x| x.to_string()),
+            table_name: table.clone(),
+            ast: SelectStatement {
+                table,
+                ..Default::default()
             },
             config: config_select.clone(),
-            select: None,
-            join: None,
-            where_and: None,
-            where_or: None,
-            order_by_query: None,
-            group_by_query: None,
-            limit: None,
-            offset: None,
+            custom_config,
             bind_index: match bind_index {
                 true => Some(config_select.start.unwrap()),
                 false => None,
             },
             binds: vec![],
+            count: false,
+            scope: None,
+            unscoped: false,
+        }
+    }
+
+    /// Initialize Select builder from [`TableSchema`]
+    /// ```rust
+    /// use qorm::{table::{Column, TableSchema}, Select};
+    ///
+    /// struct User;
+    ///
+    /// impl TableSchema for User {
+    ///     const TABLE: &'static str = "user";
+    ///     const COLUMNS: &'static [Column] = &[];
+    /// }
+    ///
+    /// let mut builder = Select::from_table::<User>(Some("u"), None);
+    /// assert_eq!(builder.to_sql(), "SELECT * FROM user u");
+    /// ```
+    pub fn from_table<T: TableSchema>(alias: Option<&str>, config: Option<SelectConfig>) -> Self {
+        Self::new(T::TABLE, alias, config)
+    }
+
+    /// Initialize Select builder from [`SelectStatement`], e.g. saved filter deserialized with
+    /// `serde` feature. statement dialect overrides config placeholder
+    /// ```rust
+    /// use qorm::{Bind, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.select("t.id").wheres("t.is_done", "=", Bind::Bool(false));
+    /// builder.order_by(vec!["t.id DESC"]).limit(10);
+    /// let ast = builder.ast();
+    ///
+    /// let mut rebuilt = Select::from_ast(&ast, None);
+    /// assert_eq!(rebuilt.to_sql(), "SELECT t.id FROM todo t WHERE t.is_done = ? ORDER BY t.id DESC LIMIT 10");
+    /// assert_eq!(rebuilt.ast(), ast);
+    /// ```
+    pub fn from_ast(ast: &SelectStatement, config: Option<SelectConfig>) -> Self {
+        let mut builder = Self::new(&ast.table.name, None, config);
+        builder.ast = ast.clone();
+        builder.table_name = ast.table.clone();
+        if let Some(dialect) = ast.dialect {
+            builder.dialect(dialect);
+        }
+        builder
+    }
+
+    /// query held by builder, `table` is read from [`Select::table_name`]
+    pub fn ast(&self) -> SelectStatement {
+        SelectStatement {
+            table: self.table_name.clone(),
+            ..self.ast.clone()
+        }
+    }
+
+    /// table selected from
+    pub fn get_table(&self) -> &Table {
+        &self.table_name
+    }
+
+    /// selected expressions, empty means `*`
+    pub fn get_select(&self) -> &[SelectColumn] {
+        self.ast.select.as_deref().unwrap_or_default()
+    }
+
+    /// joined tables
+    pub fn get_join(&self) -> &[Join] {
+        self.ast.join.as_deref().unwrap_or_default()
+    }
+
+    /// conditions joined with `AND`
+    pub fn get_where(&self) -> &[Condition] {
+        self.ast.where_and.as_deref().unwrap_or_default()
+    }
+
+    /// condition groups joined with `OR`
+    pub fn get_where_or(&self) -> &[Vec<Condition>] {
+        self.ast.where_or.as_deref().unwrap_or_default()
+    }
+
+    /// order by expressions
+    pub fn get_order_by(&self) -> &[String] {
+        self.ast.order_by.as_deref().unwrap_or_default()
+    }
+
+    /// group by expressions
+    pub fn get_group_by(&self) -> &[String] {
+        self.ast.group_by.as_deref().unwrap_or_default()
+    }
+
+    pub fn get_limit(&self) -> Option<i64> {
+        self.ast.limit
+    }
+
+    pub fn get_offset(&self) -> Option<i64> {
+        self.ast.offset
+    }
+
+    /// every where condition, `AND` conditions first then `OR` groups
+    /// ```rust
+    /// use qorm::{where_item::Or, Bind, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.wheres("t.is_done", "=", Bind::Bool(false));
+    /// builder.where_or(vec![
+    ///     Or { column: "t.user_id", operator: "=", value: Bind::Int(1) },
+    ///     Or { column: "t.is_public", operator: "=", value: Bind::Bool(true) },
+    /// ]);
+    /// let columns = builder.conditions().map(|f| f.column.as_str()).collect::<Vec<&str>>();
+    /// assert_eq!(columns, vec!["t.is_done", "t.user_id", "t.is_public"]);
+    /// ```
+    pub fn conditions(&self) -> impl Iterator<Item = &Condition> {
+        self.get_where()
+            .iter()
+            .chain(self.get_where_or().iter().flatten())
+    }
+
+    /// every where condition as mutable, e.g. to rename column or replace value
+    /// ```rust
+    /// use qorm::{Bind, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.wheres("t.status", "=", Bind::from("open"));
+    /// for condition in builder.conditions_mut() {
+    ///     if condition.column == "t.status" {
+    ///         condition.value = Bind::from("closed");
+    ///     }
+    /// }
+    /// assert_eq!(builder.to_sql_with_bind().1, vec![Bind::from("closed")]);
+    /// ```
+    pub fn conditions_mut(&mut self) -> impl Iterator<Item = &mut Condition> {
+        self.ast
+            .where_and
+            .iter_mut()
+            .flatten()
+            .chain(self.ast.where_or.iter_mut().flatten().flatten())
+    }
+
+    /// keep where conditions matching predicate, `OR` groups left empty are removed
+    /// ```rust
+    /// use qorm::{where_item::Or, Bind, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.wheres("t.is_done", "=", Bind::Bool(false));
+    /// builder.wheres("t.user_id", "=", Bind::Int(1));
+    /// builder.where_or(vec![Or { column: "t.user_id", operator: "IS", value: Bind::Null }]);
+    /// builder.retain_conditions(|f| f.column != "t.user_id");
+    /// assert_eq!(builder.to_sql(), "SELECT * FROM todo t WHERE t.is_done = ?");
+    /// ```
+    pub fn retain_conditions<F: FnMut(&Condition) -> bool>(&mut self, mut f: F) -> &mut Self {
+        if let Some(where_and) = self.ast.where_and.as_mut() {
+            where_and.retain(&mut f);
+        }
+        if let Some(where_or) = self.ast.where_or.as_mut() {
+            for group in where_or.iter_mut() {
+                group.retain(&mut f);
+            }
+            where_or.retain(|group| !group.is_empty());
+        }
+        if self.ast.where_and.as_ref().is_some_and(|f| f.is_empty()) {
+            self.ast.where_and = None;
+        }
+        if self.ast.where_or.as_ref().is_some_and(|f| f.is_empty()) {
+            self.ast.where_or = None;
+        }
+        self
+    }
+
+    /// tables referenced by query, selected table then joined tables
+    /// ```rust
+    /// use qorm::{table::Table, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.join(Some("LEFT"), "user u", "u.id = t.created_by");
+    /// assert_eq!(
+    ///     builder.tables(),
+    ///     vec![
+    ///         Table { name: "todo".to_string(), alias: Some("t".to_string()) },
+    ///         Table { name: "user".to_string(), alias: Some("u".to_string()) },
+    ///     ]
+    /// );
+    /// ```
+    pub fn tables(&self) -> Vec<Table> {
+        let mut tables = vec![self.table_name.clone()];
+        tables.extend(self.get_join().iter().map(|f| f.table()));
+        tables
+    }
+
+    /// replace selected expressions with raw columns, empty selects `*`
+    /// ```rust
+    /// use qorm::Select;
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.select("t.id").select("t.name");
+    /// builder.replace_select(vec!["COUNT(*)"]);
+    /// assert_eq!(builder.to_sql(), "SELECT COUNT(*) FROM todo t");
+    /// ```
+    pub fn replace_select(&mut self, raw: Vec<&str>) -> &mut Self {
+        self.ast.select = None;
+        for item in raw {
+            self.select(item);
+        }
+        self
+    }
+
+    /// remove selected expressions, selects `*`
+    pub fn clear_select(&mut self) -> &mut Self {
+        self.ast.select = None;
+        self
+    }
+
+    /// remove where conditions and `OR` groups
+    pub fn clear_where(&mut self) -> &mut Self {
+        self.ast.where_and = None;
+        self.ast.where_or = None;
+        self
+    }
+
+    /// remove order by
+    /// ```rust
+    /// use qorm::Select;
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.order_by(vec!["t.id DESC"]).limit(10).offset(20);
+    /// builder.clear_order_by().remove_limit().remove_offset();
+    /// assert_eq!(builder.to_sql(), "SELECT * FROM todo t");
+    /// ```
+    pub fn clear_order_by(&mut self) -> &mut Self {
+        self.ast.order_by = None;
+        self
+    }
+
+    /// remove group by
+    pub fn clear_group_by(&mut self) -> &mut Self {
+        self.ast.group_by = None;
+        self
+    }
+
+    /// remove limit
+    pub fn remove_limit(&mut self) -> &mut Self {
+        self.ast.limit = None;
+        self
+    }
+
+    /// remove offset
+    pub fn remove_offset(&mut self) -> &mut Self {
+        self.ast.offset = None;
+        self
+    }
+
+    /// set sql dialect, placeholder is switched to dialect default (see [`Dialect::placeholder`])
+    /// unless config was passed on initialize,
+    /// and dialect specific syntax is validated on [`Select::validate`]
+    /// ```rust
+    /// use qorm::{Bind, Dialect, Select};
+    ///
+    /// let mut builder = Select::new("job", None, None);
+    /// builder.dialect(Dialect::Postgres);
+    /// builder.wheres("job.id", "=", Bind::Int(1));
+    /// assert_eq!(builder.to_sql(), "SELECT * FROM job job WHERE job.id = $1");
+    ///
+    /// builder.dialect(Dialect::Sqlite).for_update();
+    /// assert!(builder.validate().is_err());
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        // Placeholder of config passed on initialize is kept, default one follows dialect
+        if !self.custom_config {
+            let (placeholder, start) = dialect.placeholder();
+            self.bind_index = match is_index(placeholder.clone()) {
+                true => start,
+                false => None,
+            };
+            self.config.placeholder = placeholder;
+            self.config.start = start;
         }
+        self.ast.dialect = Some(dialect);
+        self
     }
 
     fn get_alias(&self) -> String {
@@ -108,6 +381,12 @@ impl Select {
             .unwrap_or(self.table_name.name.clone())
     }
 
+    fn reset_bind_index(&mut self) {
+        if self.bind_index.is_some() {
+            self.bind_index = self.config.start;
+        }
+    }
+
     fn gen_bind_key(&mut self) -> String {
         if self.bind_index.is_some() {
             let index = self.bind_index.unwrap();
@@ -145,24 +424,208 @@ impl Select {
     /// assert_eq!(sql, "SELECT count(todo) FROM todo todo");
     /// ```
     pub fn select(&mut self, raw: &str) -> &mut Self {
-        if self.select.is_none() {
-            self.select = Some(vec![raw.to_string()]);
-        } else {
-            self.select.as_mut().unwrap().push(raw.to_string());
+        self.select_internal(Expr::raw(raw), None)
+    }
+
+    pub(crate) fn select_internal(&mut self, expr: Expr, alias: Option<&str>) -> &mut Self {
+        let item = SelectColumn {
+            expr,
+            alias: alias.map(|f| f.to_string()),
+        };
+        self.ast.select.get_or_insert_with(Vec::new).push(item);
+        self
+    }
+
+    /// select expression with alias, identifiers are quoted per dialect
+    /// ```rust
+    /// use qorm::{expr::Expr, Bind, Dialect, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.dialect(Dialect::MySql);
+    /// builder.select_as(Expr::column("t.name"), "title");
+    /// builder.select_as(Expr::count_distinct(Expr::column("t.created_by")), "creators");
+    /// builder.select_as(Expr::sum(Expr::column("t.point")), "total");
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT `t`.`name` AS `title`, COUNT(DISTINCT `t`.`created_by`) AS `creators`, SUM(`t`.`point`) AS `total` FROM todo t");
+    /// ```
+    pub fn select_as(&mut self, expr: Expr, alias: &str) -> &mut Self {
+        self.select_internal(expr, Some(alias))
+    }
+
+    /// select columns, quoted per dialect (plain names are kept unquoted when dialect is empty,
+    /// other names get ansi double quote)
+    /// ```rust
+    /// use qorm::{Dialect, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.select_many(vec!["t.id", "t.name"]);
+    /// assert_eq!(builder.to_sql(), "SELECT t.id, t.name FROM todo t");
+    /// builder.dialect(Dialect::Postgres);
+    /// assert_eq!(builder.to_sql(), r#"SELECT "t"."id", "t"."name" FROM todo t"#);
+    /// ```
+    pub fn select_many(&mut self, columns: Vec<&str>) -> &mut Self {
+        for column in columns {
+            self.select_internal(Expr::column(column), None);
         }
         self
     }
 
-    fn parse_select(&self, sql: &mut String) {
-        if self.select.is_none() {
+    /// select columns of [`FromRow`] struct, qualified with table alias
+    ///
+    /// how to use see [`FromRow`]
+    pub fn select_row<T: FromRow>(&mut self) -> &mut Self {
+        let alias = self.get_alias();
+        for column in T::COLUMNS {
+            self.select_internal(Expr::column(format!("{}.{}", alias, column).as_str()), None);
+        }
+        self
+    }
+
+    fn parse_select(&mut self, sql: &mut String) {
+        if self.ast.select.is_none() {
             return;
         }
 
-        for (idx, item) in self.select.clone().unwrap().iter().enumerate() {
-            if idx + 1 == self.select.clone().unwrap().len() {
-                sql.push_str(format!(" {}", item).as_str());
+        let dialect = self.ast.dialect;
+        for (idx, item) in self.ast.select.clone().unwrap().iter().enumerate() {
+            let mut column = item.expr.to_sql(dialect, &mut |value| {
+                let key = self.gen_bind_key();
+                self.bind_push(value.clone());
+                key
+            });
+            if item.alias.is_some() {
+                column.push_str(
+                    format!(
+                        " AS {}",
+                        quote_identifier(dialect, item.alias.clone().unwrap().as_str())
+                    )
+                    .as_str(),
+                );
+            }
+            if idx + 1 == self.ast.select.clone().unwrap().len() {
+                sql.push_str(format!(" {}", column).as_str());
+            } else {
+                sql.push_str(format!(" {},", column).as_str());
+            }
+        }
+    }
+
+    /// sql select distinct
+    /// ```rust
+    /// use qorm::Select;
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.distinct().select("t.created_by");
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT DISTINCT t.created_by FROM todo t");
+    /// ```
+    pub fn distinct(&mut self) -> &mut Self {
+        self.ast.distinct = true;
+        self
+    }
+
+    /// sql select distinct on (postgres only)
+    /// ```rust
+    /// use qorm::{Dialect, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.dialect(Dialect::Postgres);
+    /// builder.distinct_on(vec!["t.created_by"]).select("t.created_by").select("t.name");
+    /// builder.order_by(vec!["t.created_by", "t.created_at DESC"]);
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT DISTINCT ON (t.created_by) t.created_by, t.name FROM todo t ORDER BY t.created_by, t.created_at DESC");
+    /// ```
+    pub fn distinct_on(&mut self, columns: Vec<&str>) -> &mut Self {
+        self.ast
+            .distinct_on
+            .get_or_insert_with(Vec::new)
+            .extend(columns.iter().map(|f| f.to_string()));
+        self
+    }
+
+    fn validate_distinct(&self) -> Result<(), Error> {
+        if self.ast.distinct_on.is_none() {
+            return Ok(());
+        }
+        match self.ast.dialect {
+            Some(Dialect::Postgres) | None => Ok(()),
+            Some(dialect) => Err(Error::Unsupported {
+                feature: "DISTINCT ON".to_string(),
+                dialect,
+            }),
+        }
+    }
+
+    fn parse_distinct(&self, sql: &mut String) {
+        if self.ast.distinct_on.is_some() {
+            sql.push_str(
+                format!(
+                    " DISTINCT ON ({})",
+                    self.ast.distinct_on.clone().unwrap().join(", ")
+                )
+                .as_str(),
+            );
+        } else if self.ast.distinct {
+            sql.push_str(" DISTINCT");
+        }
+    }
+
+    /// select window function with alias
+    /// ```rust
+    /// use qorm::{window::{Window, WindowFunction}, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.select("t.id");
+    /// builder.select_window(
+    ///     WindowFunction::row_number().over(
+    ///         Window::new()
+    ///             .partition_by(vec!["t.created_by"])
+    ///             .order_by(vec!["t.created_at DESC"]),
+    ///     ),
+    ///     "rn",
+    /// );
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT t.id, ROW_NUMBER() OVER (PARTITION BY t.created_by ORDER BY t.created_at DESC) AS rn FROM todo t");
+    /// ```
+    pub fn select_window(&mut self, function: &WindowFunction, alias: &str) -> &mut Self {
+        self.select_internal(Expr::raw(function.to_sql().as_str()), Some(alias))
+    }
+
+    /// sql named window, `WINDOW name AS (...)`
+    /// ```rust
+    /// use qorm::{window::{Window, WindowFunction}, Select};
+    ///
+    /// let mut builder = Select::new("payment", Some("p"), None);
+    /// builder.select("p.id");
+    /// builder.select_window(WindowFunction::sum("p.amount").over_window("w"), "running_total");
+    /// builder.select_window(WindowFunction::lag("p.amount", None).over_window("w"), "previous");
+    /// builder.window(
+    ///     "w",
+    ///     Window::new()
+    ///         .partition_by(vec!["p.user_id"])
+    ///         .order_by(vec!["p.paid_at"]),
+    /// );
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT p.id, SUM(p.amount) OVER w AS running_total, LAG(p.amount) OVER w AS previous FROM payment p WINDOW w AS (PARTITION BY p.user_id ORDER BY p.paid_at)");
+    /// ```
+    pub fn window(&mut self, name: &str, window: &Window) -> &mut Self {
+        self.ast
+            .window
+            .get_or_insert_with(Vec::new)
+            .push((name.to_string(), window.clone()));
+        self
+    }
+
+    fn parse_window(&self, sql: &mut String) {
+        if self.ast.window.is_none() {
+            return;
+        }
+        sql.push_str(" WINDOW ");
+        for (idx, (name, window)) in self.ast.window.clone().unwrap().iter().enumerate() {
+            if idx + 1 == self.ast.window.clone().unwrap().len() {
+                sql.push_str(format!("{} AS ({})", name, window.to_sql()).as_str());
             } else {
-                sql.push_str(format!(" {},", item).as_str());
+                sql.push_str(format!("{} AS ({}), ", name, window.to_sql()).as_str());
             }
         }
     }
@@ -178,42 +641,63 @@ impl Select {
     /// assert_eq!(sql, "SELECT * FROM todo t JOIN user u ON t.created_by = u.id LEFT JOIN user_profile up ON u.id = up.user_id");
     /// ```
     pub fn join(&mut self, join_type: Option<&str>, table_name: &str, on: &str) -> &mut Self {
-        if self.join.is_none() {
-            self.join = Some(vec![JoinInternal {
-                join_type: join_type.map(|f| f.to_string()),
-                table_name: table_name.to_string(),
-                on: on.to_string(),
-            }]);
-        } else {
-            self.join.as_mut().unwrap().push(JoinInternal {
-                join_type: join_type.map(|f| f.to_string()),
-                table_name: table_name.to_string(),
-                on: on.to_string(),
-            });
-        }
+        self.ast.join.get_or_insert_with(Vec::new).push(Join {
+            join_type: join_type.map(|f| f.to_string()),
+            table_name: table_name.to_string(),
+            on: on.to_string(),
+        });
         self
     }
 
-    fn parse_join(&self, sql: &mut String) {
-        if self.join.is_none() {
+    fn parse_join(&mut self, sql: &mut String) {
+        if self.ast.join.is_none() {
             return;
         }
 
-        for item in self.join.clone().unwrap() {
-            if item.join_type.is_some() {
-                sql.push_str(
-                    format!(
-                        " {} JOIN {} ON {}",
-                        item.join_type.unwrap(),
-                        item.table_name,
-                        item.on
-                    )
-                    .as_str(),
-                );
+        for item in self.ast.join.clone().unwrap() {
+            let on = self.scoped_on(&item);
+            if let Some(join_type) = item.join_type {
+                sql.push_str(format!(" {} JOIN {} ON {}", join_type, item.table_name, on).as_str());
             } else {
-                sql.push_str(format!(" JOIN {} ON {}", item.table_name, item.on).as_str());
+                sql.push_str(format!(" JOIN {} ON {}", item.table_name, on).as_str());
+            }
+        }
+    }
+
+    /// join condition with scope conditions of joined table
+    fn scoped_on(&mut self, join: &Join) -> String {
+        let table = join.table();
+        let conditions = match &self.scope {
+            Some(scope) => scope.conditions(
+                &table.name,
+                Some(table.alias.as_deref().unwrap_or(&table.name)),
+            ),
+            None => vec![],
+        };
+        if conditions.is_empty() {
+            return join.on.clone();
+        }
+        let mut on = format!("({})", join.on);
+        for item in conditions {
+            match item.value {
+                Bind::Null => {
+                    on.push_str(format!(" AND {} {} NULL", item.column, item.operator).as_str());
+                }
+                _ => {
+                    on.push_str(
+                        format!(
+                            " AND {} {} {}",
+                            item.column,
+                            item.operator,
+                            self.gen_bind_key()
+                        )
+                        .as_str(),
+                    );
+                    self.bind_push(item.value.clone());
+                }
             }
         }
+        on
     }
 
     /// sql where and
@@ -236,27 +720,22 @@ impl Select {
     /// }
     /// ```
     pub fn wheres(&mut self, column: &str, operator: &str, value: Bind) -> &mut Self {
-        if self.where_and.is_none() {
-            self.where_and = Some(vec![WhereInternal {
-                column: column.to_string(),
-                operator: operator.to_string(),
-                value,
-            }])
-        } else {
-            self.where_and.as_mut().unwrap().push(WhereInternal {
+        self.ast
+            .where_and
+            .get_or_insert_with(Vec::new)
+            .push(Condition {
                 column: column.to_string(),
                 operator: operator.to_string(),
                 value,
             });
-        }
         self
     }
 
     fn parse_where(&mut self, sql: &mut String) {
-        if self.where_and.is_none() {
+        if self.ast.where_and.is_none() {
             return;
         }
-        for (idx, item) in self.where_and.clone().unwrap().iter().enumerate() {
+        for (idx, item) in self.ast.where_and.clone().unwrap().iter().enumerate() {
             match item.value {
                 Bind::Null => {
                     sql.push_str(format!(" {} {} NULL", item.column, item.operator).as_str());
@@ -269,7 +748,7 @@ impl Select {
                     self.bind_push(item.value.clone());
                 }
             }
-            if idx + 1 != self.where_and.clone().unwrap().len() {
+            if idx + 1 != self.ast.where_and.clone().unwrap().len() {
                 sql.push_str(" AND");
             }
         }
@@ -304,35 +783,24 @@ impl Select {
     /// }
     /// ```
     pub fn where_or(&mut self, wheres: Vec<Or>) -> &mut Self {
-        if self.where_or.is_none() {
-            self.where_or = Some(vec![wheres
+        self.ast.where_or.get_or_insert_with(Vec::new).push(
+            wheres
                 .iter()
-                .map(|f| WhereInternal {
+                .map(|f| Condition {
                     column: f.column.to_string(),
                     operator: f.operator.to_string(),
                     value: f.value.clone(),
                 })
-                .collect()]);
-        } else {
-            self.where_or.as_mut().unwrap().push(
-                wheres
-                    .iter()
-                    .map(|f| WhereInternal {
-                        column: f.column.to_string(),
-                        operator: f.operator.to_string(),
-                        value: f.value.clone(),
-                    })
-                    .collect(),
-            );
-        }
+                .collect(),
+        );
         self
     }
 
     fn parse_where_or(&mut self, sql: &mut String) {
-        if self.where_or.is_none() {
+        if self.ast.where_or.is_none() {
             return;
         }
-        for (idx, or_vec) in self.where_or.clone().unwrap().iter().enumerate() {
+        for (idx, or_vec) in self.ast.where_or.clone().unwrap().iter().enumerate() {
             if idx == 0 {
                 sql.push_str(" (");
             } else {
@@ -370,24 +838,21 @@ impl Select {
     /// assert_eq!(sql, "SELECT * FROM user u ORDER BY u.username DESC, u.profile ASC");
     /// ```
     pub fn order_by(&mut self, raw: Vec<&str>) -> &mut Self {
-        if self.order_by_query.is_none() {
-            self.order_by_query = Some(raw.iter().map(|f| f.to_string()).collect())
-        } else {
-            for item in raw {
-                self.order_by_query.as_mut().unwrap().push(item.to_string());
-            }
-        }
+        self.ast
+            .order_by
+            .get_or_insert_with(Vec::new)
+            .extend(raw.iter().map(|f| f.to_string()));
 
         self
     }
 
     fn parse_order_by(&self, sql: &mut String) {
-        if self.order_by_query.clone().is_none() {
+        if self.ast.order_by.clone().is_none() {
             return;
         }
         sql.push_str(" ORDER BY ");
-        for (idx, item) in self.order_by_query.clone().unwrap().iter().enumerate() {
-            if idx + 1 == self.order_by_query.clone().unwrap().len() {
+        for (idx, item) in self.ast.order_by.clone().unwrap().iter().enumerate() {
+            if idx + 1 == self.ast.order_by.clone().unwrap().len() {
                 sql.push_str(item.as_str());
             } else {
                 sql.push_str(format!("{}, ", item).as_str());
@@ -405,24 +870,21 @@ impl Select {
     /// assert_eq!(sql, "SELECT * FROM todo t GROUP BY t.created_by");
     /// ```
     pub fn group_by(&mut self, raw: Vec<&str>) -> &mut Self {
-        if self.group_by_query.is_none() {
-            self.group_by_query = Some(raw.iter().map(|f| f.to_string()).collect())
-        } else {
-            for item in raw {
-                self.group_by_query.as_mut().unwrap().push(item.to_string());
-            }
-        }
+        self.ast
+            .group_by
+            .get_or_insert_with(Vec::new)
+            .extend(raw.iter().map(|f| f.to_string()));
 
         self
     }
 
     fn parse_group_by(&self, sql: &mut String) {
-        if self.group_by_query.clone().is_none() {
+        if self.ast.group_by.clone().is_none() {
             return;
         }
         sql.push_str(" GROUP BY ");
-        for (idx, item) in self.group_by_query.clone().unwrap().iter().enumerate() {
-            if idx + 1 == self.group_by_query.clone().unwrap().len() {
+        for (idx, item) in self.ast.group_by.clone().unwrap().iter().enumerate() {
+            if idx + 1 == self.ast.group_by.clone().unwrap().len() {
                 sql.push_str(item.as_str());
             } else {
                 sql.push_str(format!("{}, ", item).as_str());
@@ -440,15 +902,15 @@ impl Select {
     /// assert_eq!(sql, "SELECT * FROM todo t LIMIT 10");
     /// ```
     pub fn limit(&mut self, limit: i64) -> &mut Self {
-        self.limit = Some(limit);
+        self.ast.limit = Some(limit);
         self
     }
 
     fn parse_limit(&self, sql: &mut String) {
-        if self.limit.is_none() {
+        if self.ast.limit.is_none() {
             return;
         }
-        sql.push_str(format!(" LIMIT {}", self.limit.unwrap()).as_str());
+        sql.push_str(format!(" LIMIT {}", self.ast.limit.unwrap()).as_str());
     }
 
     /// sql offset
@@ -461,15 +923,227 @@ impl Select {
     /// assert_eq!(sql, "SELECT * FROM todo t OFFSET 10");
     /// ```
     pub fn offset(&mut self, offset: i64) -> &mut Self {
-        self.offset = Some(offset);
+        self.ast.offset = Some(offset);
         self
     }
 
     fn parse_offset(&self, sql: &mut String) {
-        if self.offset.is_none() {
+        if self.ast.offset.is_none() {
+            return;
+        }
+        sql.push_str(format!(" OFFSET {}", self.ast.offset.unwrap()).as_str());
+    }
+
+    /// sql row locking, `FOR UPDATE`
+    /// ```rust
+    /// use qorm::{Bind, Select};
+    ///
+    /// let mut builder = Select::new("job", Some("j"), None);
+    /// builder.wheres("j.status", "=", Bind::String("pending".to_string()));
+    /// builder.limit(1);
+    /// builder.for_update().skip_locked();
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT * FROM job j WHERE j.status = ? LIMIT 1 FOR UPDATE SKIP LOCKED");
+    /// ```
+    pub fn for_update(&mut self) -> &mut Self {
+        self.ast.lock = Some(LockStrength::Update);
+        self
+    }
+
+    /// sql row locking, `FOR SHARE`
+    /// ```rust
+    /// use qorm::Select;
+    ///
+    /// let mut builder = Select::new("job", Some("j"), None);
+    /// builder.for_share().nowait();
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT * FROM job j FOR SHARE NOWAIT");
+    /// ```
+    pub fn for_share(&mut self) -> &mut Self {
+        self.ast.lock = Some(LockStrength::Share);
+        self
+    }
+
+    /// sql row locking, `FOR NO KEY UPDATE` (postgres only)
+    /// ```rust
+    /// use qorm::{Dialect, Select};
+    ///
+    /// let mut builder = Select::new("job", Some("j"), None);
+    /// builder.dialect(Dialect::Postgres).for_no_key_update();
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT * FROM job j FOR NO KEY UPDATE");
+    /// ```
+    pub fn for_no_key_update(&mut self) -> &mut Self {
+        self.ast.lock = Some(LockStrength::NoKeyUpdate);
+        self
+    }
+
+    /// restrict row locking to tables, `OF table`
+    /// ```rust
+    /// use qorm::Select;
+    ///
+    /// let mut builder = Select::new("job", Some("j"), None);
+    /// builder.join(None, "worker w", "w.id = j.worker_id");
+    /// builder.for_update().of(vec!["j"]);
+    /// let sql = builder.to_sql();
+    /// assert_eq!(sql, "SELECT * FROM job j JOIN worker w ON w.id = j.worker_id FOR UPDATE OF j");
+    /// ```
+    pub fn of(&mut self, tables: Vec<&str>) -> &mut Self {
+        self.ast
+            .lock_of
+            .get_or_insert_with(Vec::new)
+            .extend(tables.iter().map(|f| f.to_string()));
+        self
+    }
+
+    /// skip locked rows, `SKIP LOCKED`
+    ///
+    /// how to use see [`Select::for_update`]
+    pub fn skip_locked(&mut self) -> &mut Self {
+        self.ast.lock_wait = Some(LockWait::SkipLocked);
+        self
+    }
+
+    /// fail instead of waiting locked rows, `NOWAIT`
+    ///
+    /// how to use see [`Select::for_share`]
+    pub fn nowait(&mut self) -> &mut Self {
+        self.ast.lock_wait = Some(LockWait::NoWait);
+        self
+    }
+
+    fn validate_lock(&self) -> Result<(), Error> {
+        if self.ast.lock.is_none() {
+            if self.ast.lock_of.is_some() || self.ast.lock_wait.is_some() {
+                return Err(Error::Invalid(
+                    "OF, SKIP LOCKED and NOWAIT require FOR UPDATE or FOR SHARE".to_string(),
+                ));
+            }
+            return Ok(());
+        }
+        let feature = match self.ast.lock.clone().unwrap() {
+            LockStrength::Update => "FOR UPDATE",
+            LockStrength::NoKeyUpdate => "FOR NO KEY UPDATE",
+            LockStrength::Share => "FOR SHARE",
+        };
+        match self.ast.dialect {
+            Some(Dialect::Sqlite) => Err(Error::Unsupported {
+                feature: feature.to_string(),
+                dialect: Dialect::Sqlite,
+            }),
+            Some(Dialect::MySql) if self.ast.lock == Some(LockStrength::NoKeyUpdate) => {
+                Err(Error::Unsupported {
+                    feature: feature.to_string(),
+                    dialect: Dialect::MySql,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn parse_lock(&self, sql: &mut String) {
+        if self.ast.lock.is_none() {
             return;
         }
-        sql.push_str(format!(" OFFSET {}", self.offset.unwrap()).as_str());
+        match self.ast.lock.clone().unwrap() {
+            LockStrength::Update => sql.push_str(" FOR UPDATE"),
+            LockStrength::NoKeyUpdate => sql.push_str(" FOR NO KEY UPDATE"),
+            LockStrength::Share => sql.push_str(" FOR SHARE"),
+        }
+        if self.ast.lock_of.is_some() {
+            sql.push_str(format!(" OF {}", self.ast.lock_of.clone().unwrap().join(", ")).as_str());
+        }
+        match self.ast.lock_wait {
+            Some(LockWait::SkipLocked) => sql.push_str(" SKIP LOCKED"),
+            Some(LockWait::NoWait) => sql.push_str(" NOWAIT"),
+            None => {}
+        }
+    }
+
+    /// check builder against its dialect
+    /// ```rust
+    /// use qorm::{Dialect, Error, Select};
+    ///
+    /// let mut builder = Select::new("job", None, None);
+    /// builder.dialect(Dialect::MySql).for_no_key_update();
+    /// assert_eq!(
+    ///     builder.validate(),
+    ///     Err(Error::Unsupported {
+    ///         feature: "FOR NO KEY UPDATE".to_string(),
+    ///         dialect: Dialect::MySql,
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// scoped builder also fails on raw subquery (select, join, where, group by or order by),
+    /// scope filters are not applied inside it, see [`Select::scope`]
+    pub fn validate(&self) -> Result<(), Error> {
+        self.validate_distinct()?;
+        self.validate_lock()?;
+        self.validate_scope()
+    }
+
+    fn validate_scope(&self) -> Result<(), Error> {
+        if self.scope.is_none() {
+            return Ok(());
+        }
+        let mut raws = vec![self.table_name.name.clone()];
+        for item in self.ast.select.iter().flatten() {
+            raws.push(item.expr.to_sql(self.ast.dialect, &mut |_| "?".to_string()));
+        }
+        for join in self.ast.join.iter().flatten() {
+            raws.push(join.table_name.clone());
+            raws.push(join.on.clone());
+        }
+        raws.extend(condition_raws(self.ast.where_and.iter().flatten()));
+        raws.extend(condition_raws(self.ast.where_or.iter().flatten().flatten()));
+        raws.extend(self.ast.group_by.iter().flatten().cloned());
+        raws.extend(self.ast.order_by.iter().flatten().cloned());
+        validate_raw(raws)
+    }
+
+    /// add conditions of [`Scope`] filters on render, for selected and joined tables.
+    /// ignored when builder is [`Select::unscoped`]
+    /// ```rust
+    /// use qorm::{scope::Scope, Bind, Select};
+    ///
+    /// let mut scope = Scope::new();
+    /// scope.filter("todo", "tenant_id", "=", Bind::Int(7));
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.scope(&scope);
+    /// assert_eq!(builder.to_sql(), "SELECT * FROM todo t WHERE t.tenant_id = ?");
+    /// assert!(builder.get_where().is_empty());
+    /// ```
+    pub fn scope(&mut self, scope: &Scope) -> &mut Self {
+        if !self.unscoped {
+            self.scope = Some(scope.clone());
+        }
+        self
+    }
+
+    /// remove scope and ignore scope applied later, for admin query
+    pub fn unscoped(&mut self) -> &mut Self {
+        self.unscoped = true;
+        self.scope = None;
+        self
+    }
+
+    /// where conditions with scope conditions of selected table first
+    fn scoped_where(&self) -> Option<Vec<Condition>> {
+        let mut where_and = match &self.scope {
+            Some(scope) => scope.conditions(&self.table_name.name, Some(&self.get_alias())),
+            None => return self.ast.where_and.clone(),
+        };
+        // Raw subquery can not be scoped, match no row instead of rows outside scope
+        if self.validate_scope().is_err() {
+            where_and.insert(0, guard());
+        }
+        where_and.extend(self.ast.where_and.clone().unwrap_or_default());
+        match where_and.is_empty() {
+            true => None,
+            false => Some(where_and),
+        }
     }
 
     fn bind_push(&mut self, raw: Bind) -> &mut Self {
@@ -485,41 +1159,117 @@ impl Select {
     /// let sql = builder.to_sql();
     /// assert_eq!(sql, "SELECT * FROM todo t");
     /// ```
+    ///
+    /// builder is not validated, use [`Select::try_to_sql`] to get [`Select::validate`] error
     pub fn to_sql(&mut self) -> String {
         self.binds = vec![];
+        // Placeholder numbering restarts on every render, also on cloned count query
+        self.reset_bind_index();
+        // Scope conditions are rendered as leading where conditions
+        let where_and = self.ast.where_and.clone();
+        self.ast.where_and = self.scoped_where();
+        let mut sql = String::new();
+        // Count over grouped or distinct rows
+        if self.count && self.is_count_subquery() {
+            sql.push_str("SELECT COUNT(*) FROM (");
+            self.parse_query(&mut sql);
+            sql.push_str(") count_query");
+        } else {
+            self.parse_query(&mut sql);
+        }
+        self.ast.where_and = where_and;
+        quote_marked(self.ast.dialect, sql)
+    }
+
+    fn is_count_subquery(&self) -> bool {
+        self.ast.group_by.is_some() || self.ast.distinct || self.ast.distinct_on.is_some()
+    }
+
+    fn parse_query(&mut self, sql: &mut String) {
         // Select
-        let mut sql = "SELECT".to_string();
-        if self.select.is_none() {
-            sql.push_str(" *");
+        sql.push_str("SELECT");
+        if self.count && !self.is_count_subquery() {
+            sql.push_str(" COUNT(*)");
+        } else {
+            self.parse_distinct(sql);
+            if self.ast.select.is_none() {
+                sql.push_str(" *");
+            }
+            self.parse_select(sql);
         }
-        self.parse_select(&mut sql);
 
-        self.parse_from(&mut sql);
+        self.parse_from(sql);
 
         // Join
-        self.parse_join(&mut sql);
+        self.parse_join(sql);
 
         // Where
-        if self.where_and.is_some() || self.where_or.is_some() {
+        if self.ast.where_and.is_some() || self.ast.where_or.is_some() {
             sql.push_str(" WHERE");
         }
         // And
-        self.parse_where(&mut sql);
+        self.parse_where(sql);
 
         // Or
-        if self.where_and.is_some() && self.where_or.is_some() {
+        if self.ast.where_and.is_some() && self.ast.where_or.is_some() {
             sql.push_str(" AND");
         }
-        self.parse_where_or(&mut sql);
-        // Order By
-        self.parse_order_by(&mut sql);
+        self.parse_where_or(sql);
         // Group By
-        self.parse_group_by(&mut sql);
+        self.parse_group_by(sql);
+        // Window
+        self.parse_window(sql);
+        // Order By
+        self.parse_order_by(sql);
         // limit
-        self.parse_limit(&mut sql);
+        self.parse_limit(sql);
         // offset
-        self.parse_offset(&mut sql);
-        sql
+        self.parse_offset(sql);
+        // Lock
+        self.parse_lock(sql);
+    }
+
+    /// derive count query from current builder
+    ///
+    /// order by, limit, offset and row locking are dropped, join, where and group by are kept.
+    /// grouped or distinct query is counted as subquery.
+    /// ```rust
+    /// use qorm::{Bind, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.select("t.id").select("t.name");
+    /// builder.join(None, "user u", "t.created_by = u.id");
+    /// builder.wheres("t.is_done", "=", Bind::Bool(true));
+    /// builder.order_by(vec!["t.id DESC"]);
+    /// builder.limit(10);
+    /// builder.offset(20);
+    /// let (sql, binds) = builder.to_count().to_sql_with_bind();
+    /// assert_eq!(sql, "SELECT COUNT(*) FROM todo t JOIN user u ON t.created_by = u.id WHERE t.is_done = ?");
+    /// assert_eq!(binds, vec![Bind::Bool(true)]);
+    /// ```
+    ///
+    /// grouped query
+    /// ```rust
+    /// use qorm::{Bind, Select};
+    ///
+    /// let mut builder = Select::new("todo", Some("t"), None);
+    /// builder.select("t.created_by");
+    /// builder.wheres("t.is_done", "=", Bind::Bool(true));
+    /// builder.group_by(vec!["t.created_by"]);
+    /// builder.limit(10);
+    /// let sql = builder.to_count().to_sql();
+    /// assert_eq!(sql, "SELECT COUNT(*) FROM (SELECT t.created_by FROM todo t WHERE t.is_done = ? GROUP BY t.created_by) count_query");
+    /// ```
+    pub fn to_count(&self) -> Select {
+        let mut builder = self.clone();
+        builder.ast.order_by = None;
+        builder.ast.limit = None;
+        builder.ast.offset = None;
+        builder.ast.lock = None;
+        builder.ast.lock_of = None;
+        builder.ast.lock_wait = None;
+        builder.count = true;
+        builder
     }
 
     /// get generated sql query and it's bind
@@ -542,4 +1292,49 @@ impl Select {
         let sql = self.to_sql();
         (sql, self.binds.clone())
     }
+
+    /// validate builder then get generated sql query and it's bind
+    /// ```rust
+    /// use qorm::{Dialect, Error, Select};
+    ///
+    /// let mut builder = Select::new("todo", None, None);
+    /// builder.dialect(Dialect::Sqlite).for_update();
+    /// assert!(matches!(builder.try_to_sql(), Err(Error::Unsupported { .. })));
+    ///
+    /// builder.dialect(Dialect::Postgres);
+    /// let (sql, binds) = builder.try_to_sql().unwrap();
+    /// assert_eq!(sql, "SELECT * FROM todo todo FOR UPDATE");
+    /// assert!(binds.is_empty());
+    /// ```
+    pub fn try_to_sql(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        self.validate()?;
+        Ok(self.to_sql_with_bind())
+    }
+
+    /// get generated sql query with binds inlined as dialect literals, for debugging only,
+    /// output starts with comment marking it as not for execution
+    /// ```rust
+    /// use qorm::{Bind, Dialect, Select};
+    ///
+    /// let mut builder = Select::new("todo", None, None);
+    /// builder.dialect(Dialect::Postgres);
+    /// builder.wheres("todo.name", "=", Bind::from("it's"));
+    /// builder.wheres("todo.is_done", "=", Bind::Bool(false));
+    /// assert_eq!(
+    ///     builder.to_debug_sql(),
+    ///     "-- debug sql with inlined binds, not for execution\nSELECT * FROM todo todo WHERE todo.name = 'it''s' AND todo.is_done = FALSE"
+    /// );
+    /// assert_eq!(builder.to_sql(), "SELECT * FROM todo todo WHERE todo.name = $1 AND todo.is_done = $2");
+    /// ```
+    pub fn to_debug_sql(&mut self) -> String {
+        let config = self.config.clone();
+        let bind_index = self.bind_index;
+        self.config.placeholder = DEBUG_PLACEHOLDER.to_string();
+        self.config.start = Some(0);
+        self.bind_index = Some(0);
+        let (sql, binds) = self.to_sql_with_bind();
+        self.config = config;
+        self.bind_index = bind_index;
+        debug_sql(&sql, &binds, self.ast.dialect)
+    }
 }
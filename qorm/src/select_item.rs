@@ -1,5 +1,91 @@
+use crate::{dialect::Dialect, expr::Expr, table::Table, where_item::Condition, window::Window};
+
 #[derive(Clone)]
 pub struct SelectConfig {
     pub placeholder: String,
     pub start: Option<i32>,
 }
+
+/// row locking strength
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LockStrength {
+    Update,
+    NoKeyUpdate,
+    Share,
+}
+
+/// row locking wait behaviour
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LockWait {
+    SkipLocked,
+    NoWait,
+}
+
+/// selected expression with optional alias
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectColumn {
+    pub expr: Expr,
+    pub alias: Option<String>,
+}
+
+/// joined table, `join_type` empty means inner join
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Join {
+    pub join_type: Option<String>,
+    pub table_name: String,
+    pub on: String,
+}
+
+impl Join {
+    /// joined table name and alias, `table_name` is written as `name [AS] alias`
+    /// ```rust
+    /// use qorm::{select_item::Join, table::Table};
+    ///
+    /// let join = Join {
+    ///     join_type: None,
+    ///     table_name: "user AS u".to_string(),
+    ///     on: "u.id = t.created_by".to_string(),
+    /// };
+    /// assert_eq!(join.table(), Table { name: "user".to_string(), alias: Some("u".to_string()) });
+    /// ```
+    pub fn table(&self) -> Table {
+        let parts = self.table_name.split_whitespace().collect::<Vec<&str>>();
+        Table {
+            name: parts.first().unwrap_or(&"").to_string(),
+            alias: match parts.len() > 1 {
+                true => parts.last().map(|f| f.to_string()),
+                false => None,
+            },
+        }
+    }
+}
+
+/// SelectStatement
+///
+/// query held by [`Select`](crate::Select) builder, rebuilt with [`Select::from_ast`](crate::Select::from_ast).
+/// serializable with `serde` feature, missing fields are read as empty
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SelectStatement {
+    pub table: Table,
+    pub dialect: Option<Dialect>,
+    pub distinct: bool,
+    pub distinct_on: Option<Vec<String>>,
+    pub select: Option<Vec<SelectColumn>>,
+    pub join: Option<Vec<Join>>,
+    pub where_and: Option<Vec<Condition>>,
+    pub where_or: Option<Vec<Vec<Condition>>>,
+    pub order_by: Option<Vec<String>>,
+    pub group_by: Option<Vec<String>>,
+    pub window: Option<Vec<(String, Window)>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub lock: Option<LockStrength>,
+    pub lock_of: Option<Vec<String>>,
+    pub lock_wait: Option<LockWait>,
+}
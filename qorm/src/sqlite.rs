@@ -0,0 +1,114 @@
+//! sqlite backend, enabled with `sqlite` feature
+//!
+//! [`Executor`] is implemented for [`rusqlite::Connection`], since `Connection` has its own `execute`
+//! method call it as `Executor::execute(&mut conn, &mut builder)`.
+//! ```rust
+//! use qorm::{sqlite::rusqlite::Connection, Bind, Executor, FromRow, Insert, Select};
+//!
+//! #[derive(FromRow)]
+//! struct User {
+//!     id: i64,
+//!     username: String,
+//! }
+//!
+//! let mut conn = Connection::open_in_memory().unwrap();
+//! conn.execute_batch("CREATE TABLE user (id INTEGER PRIMARY KEY, username TEXT NOT NULL)")
+//!     .unwrap();
+//!
+//! let mut builder = Insert::new("user", None);
+//! builder.values(vec![("username", Bind::String("foo".to_string()))]);
+//! assert_eq!(Executor::execute(&mut conn, &mut builder).unwrap(), 1);
+//!
+//! let mut builder = Select::new("user", None, None);
+//! builder.select_row::<User>();
+//! builder.wheres("user.username", "=", Bind::String("foo".to_string()));
+//! let user: User = conn.fetch_one(&mut builder).unwrap();
+//! assert_eq!(user.id, 1);
+//! ```
+pub use rusqlite;
+use rusqlite::{
+    params_from_iter,
+    types::{ToSqlOutput, Value, ValueRef},
+    Connection, ToSql,
+};
+
+use crate::{
+    dialect::Dialect,
+    error::Error,
+    executor::Executor,
+    query::Query,
+    row::{FromRow, Row},
+    Bind,
+};
+
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Self {
+        Error::Database(err.to_string())
+    }
+}
+
+impl ToSql for Bind {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(match self {
+            Bind::Null => ToSqlOutput::Owned(Value::Null),
+            Bind::String(value) | Bind::Raw(value) => {
+                ToSqlOutput::Borrowed(ValueRef::Text(value.as_bytes()))
+            }
+            Bind::Int(value) => ToSqlOutput::Owned(Value::Integer(i64::from(*value))),
+            Bind::BigInt(value) => ToSqlOutput::Owned(Value::Integer(*value)),
+            Bind::Bool(value) => ToSqlOutput::Owned(Value::Integer(i64::from(*value))),
+            Bind::Float(value) => ToSqlOutput::Owned(Value::Real(*value)),
+            Bind::Bytes(value) => ToSqlOutput::Borrowed(ValueRef::Blob(value)),
+        })
+    }
+}
+
+impl Row for rusqlite::Row<'_> {
+    fn get_bind(&self, index: usize) -> Result<Bind, Error> {
+        let value = self
+            .get_ref(index)
+            .map_err(|err| Error::Decode(err.to_string()))?;
+        Ok(match value {
+            ValueRef::Null => Bind::Null,
+            ValueRef::Integer(value) => Bind::BigInt(value),
+            ValueRef::Real(value) => Bind::Float(value),
+            ValueRef::Text(value) => Bind::String(String::from_utf8_lossy(value).to_string()),
+            ValueRef::Blob(value) => Bind::Bytes(value.to_vec()),
+        })
+    }
+
+    fn column_index(&self, name: &str) -> Result<usize, Error> {
+        self.as_ref()
+            .column_index(name)
+            .map_err(|err| Error::Decode(err.to_string()))
+    }
+}
+
+impl Executor for Connection {
+    fn execute<Q: Query>(&mut self, query: &mut Q) -> Result<u64, Error> {
+        let (sql, binds) = query.build_for(Dialect::Sqlite)?;
+        let affected = Connection::execute(self, &sql, params_from_iter(binds.iter()))?;
+        Ok(affected as u64)
+    }
+
+    fn fetch_all<T: FromRow, Q: Query>(&mut self, query: &mut Q) -> Result<Vec<T>, Error> {
+        let (sql, binds) = query.build_for(Dialect::Sqlite)?;
+        let mut stmt = self.prepare(&sql)?;
+        let mut rows = stmt.query(params_from_iter(binds.iter()))?;
+        let mut result = vec![];
+        while let Some(row) = rows.next()? {
+            result.push(T::from_row(row)?);
+        }
+        Ok(result)
+    }
+
+    fn fetch_optional<T: FromRow, Q: Query>(&mut self, query: &mut Q) -> Result<Option<T>, Error> {
+        let (sql, binds) = query.build_for(Dialect::Sqlite)?;
+        let mut stmt = self.prepare(&sql)?;
+        let mut rows = stmt.query(params_from_iter(binds.iter()))?;
+        match rows.next()? {
+            Some(row) => Ok(Some(T::from_row(row)?)),
+            None => Ok(None),
+        }
+    }
+}
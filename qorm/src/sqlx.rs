@@ -0,0 +1,167 @@
+//! sqlx integration, enabled with `sqlx` feature
+//!
+//! [`Bind`] implements sqlx `Encode`/`Type` for postgres, mysql and sqlite,
+//! and [`query`] turns any qorm builder into bound `sqlx::query::Query` with placeholder style of the database.
+//! ```rust
+//! use qorm::{sqlx::query, Bind, Select};
+//! use sqlx::Postgres;
+//!
+//! let mut builder = Select::new("user", None, None);
+//! builder.wheres("user.id", "=", Bind::Int(1));
+//! let query = query::<Postgres, _>(&mut builder).unwrap();
+//! assert_eq!(sqlx::Execute::sql(query), "SELECT * FROM user user WHERE user.id = $1");
+//! ```
+use ::sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    mysql::{MySql, MySqlTypeInfo},
+    postgres::{types::Oid, PgArgumentBuffer, PgTypeInfo, Postgres},
+    sqlite::{Sqlite, SqliteArgumentsBuffer, SqliteTypeInfo},
+    AssertSqlSafe, Encode, Type,
+};
+
+use crate::{dialect::Dialect, error::Error, query::Query, Bind};
+
+/// sqlx database supported by qorm
+pub trait Database: ::sqlx::Database {
+    /// dialect used to render query
+    const DIALECT: Dialect;
+}
+
+impl Database for Postgres {
+    const DIALECT: Dialect = Dialect::Postgres;
+}
+
+impl Database for MySql {
+    const DIALECT: Dialect = Dialect::MySql;
+}
+
+impl Database for Sqlite {
+    const DIALECT: Dialect = Dialect::Sqlite;
+}
+
+/// build sqlx query from qorm builder, builder is rendered on `DB` dialect and left unchanged,
+/// fails when builder can not be rendered on `DB`
+pub fn query<DB, Q>(
+    query: &mut Q,
+) -> Result<::sqlx::query::Query<'static, DB, DB::Arguments>, Error>
+where
+    DB: Database,
+    Q: Query,
+    Bind: for<'q> Encode<'q, DB> + Type<DB>,
+{
+    let (sql, binds) = query.build_for(DB::DIALECT)?;
+    let mut result = ::sqlx::query(AssertSqlSafe(sql));
+    for bind in binds {
+        result = result.bind(bind);
+    }
+    Ok(result)
+}
+
+/// builder errors are reported as sqlx encode error, so `?` works inside sqlx code
+impl From<Error> for ::sqlx::Error {
+    fn from(err: Error) -> Self {
+        ::sqlx::Error::Encode(Box::new(err))
+    }
+}
+
+impl Type<Postgres> for Bind {
+    fn type_info() -> PgTypeInfo {
+        <String as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Bind {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        match self {
+            Bind::Null => Ok(IsNull::Yes),
+            Bind::String(value) | Bind::Raw(value) => {
+                <String as Encode<Postgres>>::encode_by_ref(value, buf)
+            }
+            Bind::Int(value) => <i32 as Encode<Postgres>>::encode_by_ref(value, buf),
+            Bind::BigInt(value) => <i64 as Encode<Postgres>>::encode_by_ref(value, buf),
+            Bind::Bool(value) => <bool as Encode<Postgres>>::encode_by_ref(value, buf),
+            Bind::Float(value) => <f64 as Encode<Postgres>>::encode_by_ref(value, buf),
+            Bind::Bytes(value) => <Vec<u8> as Encode<Postgres>>::encode_by_ref(value, buf),
+        }
+    }
+
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(match self {
+            // unknown, let postgres infer type of NULL
+            Bind::Null => PgTypeInfo::with_oid(Oid(705)),
+            Bind::String(_) | Bind::Raw(_) => <String as Type<Postgres>>::type_info(),
+            Bind::Int(_) => <i32 as Type<Postgres>>::type_info(),
+            Bind::BigInt(_) => <i64 as Type<Postgres>>::type_info(),
+            Bind::Bool(_) => <bool as Type<Postgres>>::type_info(),
+            Bind::Float(_) => <f64 as Type<Postgres>>::type_info(),
+            Bind::Bytes(_) => <Vec<u8> as Type<Postgres>>::type_info(),
+        })
+    }
+}
+
+impl Type<MySql> for Bind {
+    fn type_info() -> MySqlTypeInfo {
+        <String as Type<MySql>>::type_info()
+    }
+}
+
+impl Encode<'_, MySql> for Bind {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        match self {
+            Bind::Null => Ok(IsNull::Yes),
+            Bind::String(value) | Bind::Raw(value) => {
+                <String as Encode<MySql>>::encode_by_ref(value, buf)
+            }
+            Bind::Int(value) => <i32 as Encode<MySql>>::encode_by_ref(value, buf),
+            Bind::BigInt(value) => <i64 as Encode<MySql>>::encode_by_ref(value, buf),
+            Bind::Bool(value) => <bool as Encode<MySql>>::encode_by_ref(value, buf),
+            Bind::Float(value) => <f64 as Encode<MySql>>::encode_by_ref(value, buf),
+            Bind::Bytes(value) => <Vec<u8> as Encode<MySql>>::encode_by_ref(value, buf),
+        }
+    }
+
+    fn produces(&self) -> Option<MySqlTypeInfo> {
+        Some(match self {
+            Bind::Null | Bind::String(_) | Bind::Raw(_) => <String as Type<MySql>>::type_info(),
+            Bind::Int(_) => <i32 as Type<MySql>>::type_info(),
+            Bind::BigInt(_) => <i64 as Type<MySql>>::type_info(),
+            Bind::Bool(_) => <bool as Type<MySql>>::type_info(),
+            Bind::Float(_) => <f64 as Type<MySql>>::type_info(),
+            Bind::Bytes(_) => <Vec<u8> as Type<MySql>>::type_info(),
+        })
+    }
+}
+
+impl Type<Sqlite> for Bind {
+    fn type_info() -> SqliteTypeInfo {
+        <String as Type<Sqlite>>::type_info()
+    }
+}
+
+impl Encode<'_, Sqlite> for Bind {
+    fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+        match self {
+            Bind::Null => Ok(IsNull::Yes),
+            Bind::String(value) | Bind::Raw(value) => {
+                <String as Encode<Sqlite>>::encode_by_ref(value, buf)
+            }
+            Bind::Int(value) => <i32 as Encode<Sqlite>>::encode_by_ref(value, buf),
+            Bind::BigInt(value) => <i64 as Encode<Sqlite>>::encode_by_ref(value, buf),
+            Bind::Bool(value) => <bool as Encode<Sqlite>>::encode_by_ref(value, buf),
+            Bind::Float(value) => <f64 as Encode<Sqlite>>::encode_by_ref(value, buf),
+            Bind::Bytes(value) => <Vec<u8> as Encode<Sqlite>>::encode_by_ref(value, buf),
+        }
+    }
+
+    fn produces(&self) -> Option<SqliteTypeInfo> {
+        Some(match self {
+            Bind::Null | Bind::String(_) | Bind::Raw(_) => <String as Type<Sqlite>>::type_info(),
+            Bind::Int(_) => <i32 as Type<Sqlite>>::type_info(),
+            Bind::BigInt(_) => <i64 as Type<Sqlite>>::type_info(),
+            Bind::Bool(_) => <bool as Type<Sqlite>>::type_info(),
+            Bind::Float(_) => <f64 as Type<Sqlite>>::type_info(),
+            Bind::Bytes(_) => <Vec<u8> as Type<Sqlite>>::type_info(),
+        })
+    }
+}
@@ -1,4 +1,168 @@
+use std::{fmt, ops::Deref};
+
+use crate::{
+    create_table_item::{ColumnDef, ColumnType},
+    CreateIndex,
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table {
     pub name: String,
     pub alias: Option<String>,
 }
+
+/// Column
+///
+/// column reference of [`TableSchema`], derefs to column name so it can be passed where `&str` is expected
+/// ```rust
+/// use qorm::table::Column;
+///
+/// const ID: Column = Column::new("user", "id");
+/// assert_eq!(&*ID, "id");
+/// assert_eq!(ID.qualified(), "user.id");
+/// assert_eq!(ID.qualified_with("u"), "u.id");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Column {
+    pub table: &'static str,
+    pub name: &'static str,
+}
+
+impl Column {
+    pub const fn new(table: &'static str, name: &'static str) -> Self {
+        Self { table, name }
+    }
+
+    /// column name prefixed with table name
+    pub fn qualified(&self) -> String {
+        format!("{}.{}", self.table, self.name)
+    }
+
+    /// column name prefixed with table alias
+    pub fn qualified_with(&self, alias: &str) -> String {
+        format!("{}.{}", alias, self.name)
+    }
+}
+
+impl Deref for Column {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.name
+    }
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// TableSchema
+///
+/// table descriptor, usually generated with `#[derive(Table)]` (`derive` feature)
+/// ```rust
+/// use qorm::{table::{Column, TableSchema}, Bind, Select};
+///
+/// struct User;
+///
+/// impl User {
+///     const ID: Column = Column::new("user", "id");
+///     const NAME: Column = Column::new("user", "name");
+/// }
+///
+/// impl TableSchema for User {
+///     const TABLE: &'static str = "user";
+///     const COLUMNS: &'static [Column] = &[Self::ID, Self::NAME];
+/// }
+///
+/// let mut builder = Select::from_table::<User>(None, None);
+/// builder.select(&User::NAME.qualified());
+/// builder.wheres(&User::ID, "=", Bind::Int(1));
+/// assert_eq!(builder.to_sql(), "SELECT user.name FROM user user WHERE id = ?");
+/// ```
+pub trait TableSchema {
+    /// table name
+    const TABLE: &'static str;
+    /// table columns on declaration order
+    const COLUMNS: &'static [Column];
+
+    /// column definitions used by schema diffing, generated from field types by `#[derive(Table)]`
+    /// with `#[qorm(schema)]`
+    fn column_defs() -> Vec<ColumnDef> {
+        vec![]
+    }
+
+    /// indexes used by schema diffing, generated from `#[qorm(indexed)]` fields
+    fn indexes() -> Vec<CreateIndex> {
+        vec![]
+    }
+}
+
+/// SqlType
+///
+/// rust type stored on column, used by `#[derive(Table)]` with `#[qorm(schema)]` to generate column definitions.
+/// fields of other types require `#[qorm(column_type = "...")]`
+/// ```rust
+/// use qorm::{create_table_item::ColumnType, table::SqlType};
+///
+/// assert_eq!(<Option<i64> as SqlType>::column_type(), ColumnType::BigInt);
+/// ```
+pub trait SqlType {
+    fn column_type() -> ColumnType;
+}
+
+impl SqlType for i16 {
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInt
+    }
+}
+
+impl SqlType for i32 {
+    fn column_type() -> ColumnType {
+        ColumnType::Integer
+    }
+}
+
+impl SqlType for i64 {
+    fn column_type() -> ColumnType {
+        ColumnType::BigInt
+    }
+}
+
+impl SqlType for f32 {
+    fn column_type() -> ColumnType {
+        ColumnType::Real
+    }
+}
+
+impl SqlType for f64 {
+    fn column_type() -> ColumnType {
+        ColumnType::Double
+    }
+}
+
+impl SqlType for bool {
+    fn column_type() -> ColumnType {
+        ColumnType::Boolean
+    }
+}
+
+impl SqlType for String {
+    fn column_type() -> ColumnType {
+        ColumnType::Text
+    }
+}
+
+impl SqlType for Vec<u8> {
+    fn column_type() -> ColumnType {
+        ColumnType::Bytes
+    }
+}
+
+impl<T: SqlType> SqlType for Option<T> {
+    fn column_type() -> ColumnType {
+        T::column_type()
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    error::Error,
+    executor::Executor,
+    query::Query,
+    row::FromRow,
+    transaction_item::{savepoint_name, TransactionStatement},
+};
+
+/// Transaction
+///
+/// transaction or savepoint opened with [`Executor::begin`], usable as [`Executor`].
+/// calling `begin` on transaction opens nested savepoint with generated name (`qorm_savepoint_1`, `qorm_savepoint_2`, ...),
+/// rolled back savepoint is also released. transaction that is neither committed nor rolled back
+/// (including failed commit) is rolled back on drop.
+///
+/// it works over [`Executor`], implemented for `rusqlite::Connection` (`sqlite` feature).
+/// for async drivers of `sqlx`, `postgres` and `mysql` features, run statements of
+/// [`crate::transaction_item::TransactionState`] with their `query` helper (see `docs/transaction.md`).
+/// ```rust
+/// use qorm::{Bind, Error, Executor, Insert};
+///
+/// fn create_users<E: Executor>(conn: &mut E) -> Result<(), Error> {
+///     let mut tx = conn.begin()?;
+///     let mut builder = Insert::new("user", None);
+///     builder.values(vec![("username", Bind::String("foo".to_string()))]);
+///     tx.execute(&mut builder)?;
+///
+///     let mut savepoint = tx.begin()?;
+///     let mut builder = Insert::new("user", None);
+///     builder.values(vec![("username", Bind::String("bar".to_string()))]);
+///     savepoint.execute(&mut builder)?;
+///     savepoint.rollback()?;
+///
+///     tx.commit()
+/// }
+/// ```
+pub struct Transaction<'a, E: Executor> {
+    executor: &'a mut E,
+    depth: usize,
+    done: bool,
+}
+
+impl<'a, E: Executor> Transaction<'a, E> {
+    pub(crate) fn begin(executor: &'a mut E, depth: usize) -> Result<Self, Error> {
+        executor.execute(&mut TransactionStatement::begin(depth))?;
+        Ok(Self {
+            executor,
+            depth,
+            done: false,
+        })
+    }
+
+    /// savepoint name, `None` on top level transaction
+    pub fn savepoint(&self) -> Option<String> {
+        match self.depth {
+            0 => None,
+            depth => Some(savepoint_name(depth)),
+        }
+    }
+
+    /// commit transaction, or release savepoint
+    pub fn commit(mut self) -> Result<(), Error> {
+        self.executor
+            .execute(&mut TransactionStatement::commit(self.depth))?;
+        // Failed commit is still open, it is rolled back on drop
+        self.done = true;
+        Ok(())
+    }
+
+    /// rollback transaction, or rollback to savepoint and release it
+    pub fn rollback(mut self) -> Result<(), Error> {
+        for mut statement in TransactionStatement::rollback(self.depth) {
+            self.executor.execute(&mut statement)?;
+        }
+        self.done = true;
+        Ok(())
+    }
+}
+
+impl<E: Executor> Executor for Transaction<'_, E> {
+    fn execute<Q: Query>(&mut self, query: &mut Q) -> Result<u64, Error> {
+        self.executor.execute(query)
+    }
+
+    fn fetch_all<T: FromRow, Q: Query>(&mut self, query: &mut Q) -> Result<Vec<T>, Error> {
+        self.executor.fetch_all(query)
+    }
+
+    fn fetch_optional<T: FromRow, Q: Query>(&mut self, query: &mut Q) -> Result<Option<T>, Error> {
+        self.executor.fetch_optional(query)
+    }
+
+    fn begin(&mut self) -> Result<Transaction<'_, Self>, Error> {
+        let depth = self.depth + 1;
+        Transaction::begin(self, depth)
+    }
+}
+
+impl<E: Executor> Drop for Transaction<'_, E> {
+    fn drop(&mut self) {
+        if !self.done {
+            for mut statement in TransactionStatement::rollback(self.depth) {
+                let _ = self.executor.execute(&mut statement);
+            }
+        }
+    }
+}
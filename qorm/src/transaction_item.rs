@@ -0,0 +1,136 @@
+use crate::error::Error;
+
+/// TransactionStatement
+///
+/// transaction control statement, same sql on every dialect. implements [`crate::query::Query`],
+/// so it runs through [`crate::Executor`] and `query` helper of `sqlx`, `postgres` and `mysql` features
+/// ```rust
+/// use qorm::transaction_item::TransactionStatement;
+///
+/// assert_eq!(TransactionStatement::Begin.to_sql(), "BEGIN");
+/// assert_eq!(
+///     TransactionStatement::RollbackTo("qorm_savepoint_1".to_string()).to_sql(),
+///     "ROLLBACK TO SAVEPOINT qorm_savepoint_1"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransactionStatement {
+    Begin,
+    Commit,
+    Rollback,
+    Savepoint(String),
+    Release(String),
+    RollbackTo(String),
+}
+
+impl TransactionStatement {
+    /// get generated sql query
+    pub fn to_sql(&self) -> String {
+        match self {
+            TransactionStatement::Begin => "BEGIN".to_string(),
+            TransactionStatement::Commit => "COMMIT".to_string(),
+            TransactionStatement::Rollback => "ROLLBACK".to_string(),
+            TransactionStatement::Savepoint(name) => format!("SAVEPOINT {}", name),
+            TransactionStatement::Release(name) => format!("RELEASE SAVEPOINT {}", name),
+            TransactionStatement::RollbackTo(name) => format!("ROLLBACK TO SAVEPOINT {}", name),
+        }
+    }
+
+    /// `BEGIN` on top level (`depth` 0), `SAVEPOINT` when nested
+    pub(crate) fn begin(depth: usize) -> Self {
+        match depth {
+            0 => TransactionStatement::Begin,
+            _ => TransactionStatement::Savepoint(savepoint_name(depth)),
+        }
+    }
+
+    /// `COMMIT` on top level, `RELEASE SAVEPOINT` when nested
+    pub(crate) fn commit(depth: usize) -> Self {
+        match depth {
+            0 => TransactionStatement::Commit,
+            _ => TransactionStatement::Release(savepoint_name(depth)),
+        }
+    }
+
+    /// `ROLLBACK` on top level. nested savepoint is rolled back to and released,
+    /// so it doesn't stay on savepoint stack
+    pub(crate) fn rollback(depth: usize) -> Vec<Self> {
+        match depth {
+            0 => vec![TransactionStatement::Rollback],
+            _ => vec![
+                TransactionStatement::RollbackTo(savepoint_name(depth)),
+                TransactionStatement::Release(savepoint_name(depth)),
+            ],
+        }
+    }
+}
+
+/// savepoint name of nested transaction at `depth`
+pub(crate) fn savepoint_name(depth: usize) -> String {
+    format!("qorm_savepoint_{}", depth)
+}
+
+/// TransactionState
+///
+/// open transaction depth of connection, returns statements to run for begin, commit and rollback.
+/// nested begin opens savepoint, same as [`crate::Transaction`]. it holds no connection, so it's used
+/// with any backend, e.g. async drivers of `sqlx`, `postgres` and `mysql` features
+/// ```rust
+/// use qorm::transaction_item::{TransactionState, TransactionStatement};
+///
+/// let mut state = TransactionState::new();
+/// assert_eq!(state.begin(), TransactionStatement::Begin);
+/// assert_eq!(state.begin(), TransactionStatement::Savepoint("qorm_savepoint_1".to_string()));
+/// assert_eq!(
+///     state.rollback().unwrap(),
+///     vec![
+///         TransactionStatement::RollbackTo("qorm_savepoint_1".to_string()),
+///         TransactionStatement::Release("qorm_savepoint_1".to_string()),
+///     ]
+/// );
+/// assert_eq!(state.commit().unwrap(), TransactionStatement::Commit);
+/// assert!(state.commit().is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransactionState {
+    depth: usize,
+}
+
+impl TransactionState {
+    /// Initialize state without open transaction
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// number of open transaction and savepoints
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// begin transaction, or savepoint when transaction is open
+    pub fn begin(&mut self) -> TransactionStatement {
+        let statement = TransactionStatement::begin(self.depth);
+        self.depth += 1;
+        statement
+    }
+
+    /// commit innermost transaction or savepoint, fails when nothing is open
+    pub fn commit(&mut self) -> Result<TransactionStatement, Error> {
+        Ok(TransactionStatement::commit(self.close()?))
+    }
+
+    /// rollback innermost transaction or savepoint, fails when nothing is open
+    pub fn rollback(&mut self) -> Result<Vec<TransactionStatement>, Error> {
+        Ok(TransactionStatement::rollback(self.close()?))
+    }
+
+    fn close(&mut self) -> Result<usize, Error> {
+        match self.depth {
+            0 => Err(Error::Invalid("no open transaction".to_string())),
+            _ => {
+                self.depth -= 1;
+                Ok(self.depth)
+            }
+        }
+    }
+}
@@ -1,25 +1,32 @@
-use crate::{update_item::UpdateConfig, where_item::Or, Bind};
+use crate::{
+    bind::{debug_sql, DEBUG_PLACEHOLDER},
+    dialect::{quote_marked, Dialect},
+    error::Error,
+    scope::{condition_raws, guard, validate_raw, Scope},
+    table::TableSchema,
+    update_item::UpdateConfig,
+    where_item::{Condition, Or},
+    Bind,
+};
 
 fn is_index(pattern: String) -> bool {
     pattern.contains("%d")
 }
 
-#[derive(Clone, Debug)]
-struct WhereInternal {
-    pub column: String,
-    pub operator: String,
-    pub value: Bind,
-}
-
 /// sql update builder
+#[derive(Clone)]
 pub struct Update {
     pub table_name: String,
     config: UpdateConfig,
+    custom_config: bool,
+    dialect: Option<Dialect>,
     binds: Vec<Bind>,
     bind_index: Option<i32>,
     set_values: Option<Vec<(String, Bind)>>,
-    where_and: Option<Vec<WhereInternal>>,
-    where_or: Option<Vec<Vec<WhereInternal>>>,
+    where_and: Option<Vec<Condition>>,
+    where_or: Option<Vec<Vec<Condition>>>,
+    scope: Option<Scope>,
+    unscoped: bool,
 }
 
 impl Update {
@@ -74,6 +81,7 @@ impl Update {
     /// }
     /// ```
     pub fn new(table_name: &str, config: Option<UpdateConfig>) -> Self {
+        let custom_config = config.is_some();
         let config_select = match config {
             Some(data) => data,
             None => UpdateConfig {
@@ -85,9 +93,13 @@ impl Update {
         Self {
             table_name: table_name.to_string(),
             config: config_select.clone(),
+            custom_config,
+            dialect: None,
             set_values: None,
             where_and: None,
             where_or: None,
+            scope: None,
+            unscoped: false,
             binds: vec![],
             bind_index: match bind_index {
                 true => Some(config_select.start.unwrap()),
@@ -96,6 +108,63 @@ impl Update {
         }
     }
 
+    /// Initialize Update builder from [`TableSchema`]
+    /// ```rust
+    /// use qorm::{table::{Column, TableSchema}, Bind, Update};
+    ///
+    /// struct User;
+    ///
+    /// impl User {
+    ///     const ID: Column = Column::new("user", "id");
+    ///     const NAME: Column = Column::new("user", "name");
+    /// }
+    ///
+    /// impl TableSchema for User {
+    ///     const TABLE: &'static str = "user";
+    ///     const COLUMNS: &'static [Column] = &[Self::ID, Self::NAME];
+    /// }
+    ///
+    /// let mut builder = Update::from_table::<User>(None);
+    /// builder.set(vec![(&User::NAME, Bind::String("foo".to_string()))]);
+    /// builder.wheres(&User::ID, "=", Bind::Int(1));
+    /// assert_eq!(builder.to_sql(), "UPDATE user SET name = ? WHERE id = ?");
+    /// ```
+    pub fn from_table<T: TableSchema>(config: Option<UpdateConfig>) -> Self {
+        Self::new(T::TABLE, config)
+    }
+
+    /// set sql dialect, placeholder is switched to dialect default (see [`Dialect::placeholder`])
+    /// unless config was passed on initialize
+    /// ```rust
+    /// use qorm::{Bind, Dialect, Update};
+    ///
+    /// let mut builder = Update::new("user", None);
+    /// builder.dialect(Dialect::Postgres);
+    /// builder.set(vec![("username", Bind::String("foo".to_string()))]);
+    /// builder.wheres("id", "=", Bind::Int(1));
+    /// assert_eq!(builder.to_sql(), "UPDATE user SET username = $1 WHERE id = $2");
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        // Placeholder of config passed on initialize is kept, default one follows dialect
+        if !self.custom_config {
+            let (placeholder, start) = dialect.placeholder();
+            self.bind_index = match is_index(placeholder.clone()) {
+                true => start,
+                false => None,
+            };
+            self.config.placeholder = placeholder;
+            self.config.start = start;
+        }
+        self.dialect = Some(dialect);
+        self
+    }
+
+    fn reset_bind_index(&mut self) {
+        if self.bind_index.is_some() {
+            self.bind_index = self.config.start;
+        }
+    }
+
     fn gen_bind_key(&mut self) -> String {
         if self.bind_index.is_some() {
             let index = self.bind_index.unwrap();
@@ -161,19 +230,11 @@ impl Update {
     /// }
     /// ```
     pub fn wheres(&mut self, column: &str, operator: &str, value: Bind) -> &mut Self {
-        if self.where_and.is_none() {
-            self.where_and = Some(vec![WhereInternal {
-                column: column.to_string(),
-                operator: operator.to_string(),
-                value,
-            }])
-        } else {
-            self.where_and.as_mut().unwrap().push(WhereInternal {
-                column: column.to_string(),
-                operator: operator.to_string(),
-                value,
-            });
-        }
+        self.where_and.get_or_insert_with(Vec::new).push(Condition {
+            column: column.to_string(),
+            operator: operator.to_string(),
+            value,
+        });
         self
     }
 
@@ -234,27 +295,16 @@ impl Update {
     /// }
     /// ```
     pub fn where_or(&mut self, wheres: Vec<Or>) -> &mut Self {
-        if self.where_or.is_none() {
-            self.where_or = Some(vec![wheres
+        self.where_or.get_or_insert_with(Vec::new).push(
+            wheres
                 .iter()
-                .map(|f| WhereInternal {
+                .map(|f| Condition {
                     column: f.column.to_string(),
                     operator: f.operator.to_string(),
                     value: f.value.clone(),
                 })
-                .collect()]);
-        } else {
-            self.where_or.as_mut().unwrap().push(
-                wheres
-                    .iter()
-                    .map(|f| WhereInternal {
-                        column: f.column.to_string(),
-                        operator: f.operator.to_string(),
-                        value: f.value.clone(),
-                    })
-                    .collect(),
-            );
-        }
+                .collect(),
+        );
         self
     }
 
@@ -292,6 +342,48 @@ impl Update {
         }
     }
 
+    /// add conditions of [`Scope`] filters on render, ignored when builder is [`Update::unscoped`]
+    /// ```rust
+    /// use qorm::{scope::Scope, Bind, Update};
+    ///
+    /// let mut scope = Scope::new();
+    /// scope.filter("todo", "tenant_id", "=", Bind::Int(7));
+    ///
+    /// let mut builder = Update::new("todo", None);
+    /// builder.set(vec![("is_done", Bind::Bool(true))]).scope(&scope);
+    /// assert_eq!(builder.to_sql(), "UPDATE todo SET is_done = ? WHERE tenant_id = ?");
+    /// ```
+    pub fn scope(&mut self, scope: &Scope) -> &mut Self {
+        if !self.unscoped {
+            self.scope = Some(scope.clone());
+        }
+        self
+    }
+
+    /// remove scope and ignore scope applied later, for admin query
+    pub fn unscoped(&mut self) -> &mut Self {
+        self.unscoped = true;
+        self.scope = None;
+        self
+    }
+
+    /// where conditions with scope conditions of table first
+    fn scoped_where(&self) -> Option<Vec<Condition>> {
+        let mut where_and = match &self.scope {
+            Some(scope) => scope.conditions(&self.table_name, None),
+            None => return self.where_and.clone(),
+        };
+        // Raw subquery can not be scoped, match no row instead of rows outside scope
+        if self.validate().is_err() {
+            where_and.insert(0, guard());
+        }
+        where_and.extend(self.where_and.clone().unwrap_or_default());
+        match where_and.is_empty() {
+            true => None,
+            false => Some(where_and),
+        }
+    }
+
     /// get generated sql query
     /// ```rust
     /// use qorm::{Bind, Update};
@@ -306,6 +398,10 @@ impl Update {
     /// ```
     pub fn to_sql(&mut self) -> String {
         self.binds = vec![];
+        self.reset_bind_index();
+        // Scope conditions are rendered as leading where conditions
+        let where_and = self.where_and.clone();
+        self.where_and = self.scoped_where();
         // Update
         let mut sql = format!("UPDATE {}", self.table_name).to_string();
 
@@ -325,7 +421,8 @@ impl Update {
         }
         self.parse_where_or(&mut sql);
 
-        sql
+        self.where_and = where_and;
+        quote_marked(self.dialect, sql)
     }
 
     /// get generated sql query and it's bind
@@ -352,4 +449,58 @@ impl Update {
         let sql = self.to_sql();
         (sql, self.binds.clone())
     }
+
+    /// check builder, scoped builder fails on raw subquery in where column,
+    /// scope filters are not applied inside it
+    /// ```rust
+    /// use qorm::{scope::Scope, Bind, Update};
+    ///
+    /// let mut scope = Scope::new();
+    /// scope.filter("todo", "tenant_id", "=", Bind::Int(7));
+    ///
+    /// let mut builder = Update::new("todo", None);
+    /// builder.wheres("EXISTS (SELECT 1 FROM tag WHERE tag.todo_id = todo.id)", "=", Bind::Bool(true));
+    /// assert!(builder.validate().is_ok());
+    /// builder.scope(&scope);
+    /// assert!(builder.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.scope.is_none() {
+            return Ok(());
+        }
+        let mut raws = vec![self.table_name.clone()];
+        raws.extend(condition_raws(self.where_and.iter().flatten()));
+        raws.extend(condition_raws(self.where_or.iter().flatten().flatten()));
+        validate_raw(raws)
+    }
+
+    /// validate builder then get generated sql query and it's bind
+    pub fn try_to_sql(&mut self) -> Result<(String, Vec<Bind>), Error> {
+        self.validate()?;
+        Ok(self.to_sql_with_bind())
+    }
+
+    /// get generated sql query with binds inlined as dialect literals, for debugging only,
+    /// output starts with comment marking it as not for execution
+    /// ```rust
+    /// use qorm::{Bind, Update};
+    ///
+    /// let mut builder = Update::new("todo", None);
+    /// builder.set(vec![("name", Bind::from("Foo"))]).wheres("id", "=", Bind::Int(1));
+    /// assert_eq!(
+    ///     builder.to_debug_sql(),
+    ///     "-- debug sql with inlined binds, not for execution\nUPDATE todo SET name = 'Foo' WHERE id = 1"
+    /// );
+    /// ```
+    pub fn to_debug_sql(&mut self) -> String {
+        let config = self.config.clone();
+        let bind_index = self.bind_index;
+        self.config.placeholder = DEBUG_PLACEHOLDER.to_string();
+        self.config.start = Some(0);
+        self.bind_index = Some(0);
+        let (sql, binds) = self.to_sql_with_bind();
+        self.config = config;
+        self.bind_index = bind_index;
+        debug_sql(&sql, &binds, self.dialect)
+    }
 }
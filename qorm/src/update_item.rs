@@ -1,5 +1,43 @@
+use crate::Bind;
+
 #[derive(Clone)]
 pub struct UpdateConfig {
     pub placeholder: String,
     pub start: Option<i32>,
 }
+
+/// Changeset
+///
+/// struct that can be turned into update column/bind pairs, usually generated with `#[derive(Changeset)]` (`derive` feature)
+/// ```rust
+/// use qorm::{update_item::Changeset, Bind, Update};
+///
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// impl Changeset for User {
+///     fn changeset(&self) -> Vec<(&'static str, Bind)> {
+///         vec![("name", Bind::from(self.name.clone()))]
+///     }
+///
+///     fn primary_key(&self) -> Vec<(&'static str, Bind)> {
+///         vec![("id", Bind::from(self.id))]
+///     }
+/// }
+///
+/// let user = User { id: 1, name: "foo".to_string() };
+/// let mut builder = Update::new("user", None);
+/// builder.set(user.changeset());
+/// for (column, value) in user.primary_key() {
+///     builder.wheres(column, "=", value);
+/// }
+/// assert_eq!(builder.to_sql(), "UPDATE user SET name = ? WHERE id = ?");
+/// ```
+pub trait Changeset {
+    /// column/bind pairs to set, primary key is excluded
+    fn changeset(&self) -> Vec<(&'static str, Bind)>;
+    /// primary key column/bind pairs
+    fn primary_key(&self) -> Vec<(&'static str, Bind)>;
+}
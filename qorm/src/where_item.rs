@@ -6,3 +6,30 @@ pub struct Or<'a> {
     pub operator: &'a str,
     pub value: Bind,
 }
+
+/// where condition, `column operator value`. null value is rendered inline as `NULL`,
+/// other values are bound
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Condition {
+    pub column: String,
+    pub operator: String,
+    pub value: Bind,
+}
+
+impl Condition {
+    /// Initialize Condition
+    pub fn new(column: &str, operator: &str, value: Bind) -> Self {
+        Self {
+            column: column.to_string(),
+            operator: operator.to_string(),
+            value,
+        }
+    }
+}
+
+impl From<&Or<'_>> for Condition {
+    fn from(value: &Or<'_>) -> Self {
+        Self::new(value.column, value.operator, value.value.clone())
+    }
+}
@@ -0,0 +1,246 @@
+use crate::window_item::Frame;
+
+/// Window
+///
+/// window specification used on `OVER (...)` and `WINDOW name AS (...)`
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Window {
+    partition_by_query: Option<Vec<String>>,
+    order_by_query: Option<Vec<String>>,
+    frame: Option<Frame>,
+}
+
+impl Window {
+    /// Initialize empty window
+    /// ```rust
+    /// use qorm::window::Window;
+    ///
+    /// let window = Window::new();
+    /// assert_eq!(window.to_sql(), "");
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// window partition by
+    /// ```rust
+    /// use qorm::window::Window;
+    ///
+    /// let mut window = Window::new();
+    /// window.partition_by(vec!["t.created_by", "t.is_done"]);
+    /// assert_eq!(window.to_sql(), "PARTITION BY t.created_by, t.is_done");
+    /// ```
+    pub fn partition_by(&mut self, raw: Vec<&str>) -> &mut Self {
+        self.partition_by_query
+            .get_or_insert_with(Vec::new)
+            .extend(raw.iter().map(|f| f.to_string()));
+        self
+    }
+
+    /// window order by
+    /// ```rust
+    /// use qorm::window::Window;
+    ///
+    /// let mut window = Window::new();
+    /// window.partition_by(vec!["t.created_by"]).order_by(vec!["t.created_at DESC"]);
+    /// assert_eq!(window.to_sql(), "PARTITION BY t.created_by ORDER BY t.created_at DESC");
+    /// ```
+    pub fn order_by(&mut self, raw: Vec<&str>) -> &mut Self {
+        self.order_by_query
+            .get_or_insert_with(Vec::new)
+            .extend(raw.iter().map(|f| f.to_string()));
+        self
+    }
+
+    /// window frame
+    /// ```rust
+    /// use qorm::{
+    ///     window::Window,
+    ///     window_item::{Frame, FrameBound, FrameUnit},
+    /// };
+    ///
+    /// let mut window = Window::new();
+    /// window.order_by(vec!["t.created_at"]).frame(Frame {
+    ///     unit: FrameUnit::Rows,
+    ///     start: FrameBound::UnboundedPreceding,
+    ///     end: Some(FrameBound::CurrentRow),
+    /// });
+    /// assert_eq!(
+    ///     window.to_sql(),
+    ///     "ORDER BY t.created_at ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW"
+    /// );
+    /// ```
+    pub fn frame(&mut self, frame: Frame) -> &mut Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// get generated window specification, without parentheses
+    pub fn to_sql(&self) -> String {
+        let mut parts = vec![];
+        if let Some(partition_by) = &self.partition_by_query {
+            parts.push(format!("PARTITION BY {}", partition_by.join(", ")));
+        }
+        if let Some(order_by) = &self.order_by_query {
+            parts.push(format!("ORDER BY {}", order_by.join(", ")));
+        }
+        if let Some(frame) = &self.frame {
+            parts.push(frame.to_sql());
+        }
+        parts.join(" ")
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Over {
+    Window(Window),
+    Named(String),
+}
+
+/// WindowFunction
+///
+/// window function call with its `OVER` clause, add to select with [`crate::Select::select_window`]
+#[derive(Clone, Debug)]
+pub struct WindowFunction {
+    function: String,
+    over: Option<Over>,
+}
+
+impl WindowFunction {
+    fn new(function: String) -> Self {
+        Self {
+            function,
+            over: None,
+        }
+    }
+
+    /// `ROW_NUMBER()`
+    pub fn row_number() -> Self {
+        Self::new("ROW_NUMBER()".to_string())
+    }
+
+    /// `RANK()`
+    pub fn rank() -> Self {
+        Self::new("RANK()".to_string())
+    }
+
+    /// `DENSE_RANK()`
+    pub fn dense_rank() -> Self {
+        Self::new("DENSE_RANK()".to_string())
+    }
+
+    /// `PERCENT_RANK()`
+    pub fn percent_rank() -> Self {
+        Self::new("PERCENT_RANK()".to_string())
+    }
+
+    /// `CUME_DIST()`
+    pub fn cume_dist() -> Self {
+        Self::new("CUME_DIST()".to_string())
+    }
+
+    /// `NTILE(buckets)`
+    pub fn ntile(buckets: i64) -> Self {
+        Self::new(format!("NTILE({})", buckets))
+    }
+
+    /// `LAG(column)` or `LAG(column, offset)`
+    /// ```rust
+    /// use qorm::window::{Window, WindowFunction};
+    ///
+    /// let function = WindowFunction::lag("t.amount", Some(2))
+    ///     .over(Window::new().order_by(vec!["t.created_at"]))
+    ///     .to_sql();
+    /// assert_eq!(function, "LAG(t.amount, 2) OVER (ORDER BY t.created_at)");
+    /// ```
+    pub fn lag(column: &str, offset: Option<i64>) -> Self {
+        match offset {
+            Some(offset) => Self::new(format!("LAG({}, {})", column, offset)),
+            None => Self::new(format!("LAG({})", column)),
+        }
+    }
+
+    /// `LEAD(column)` or `LEAD(column, offset)`
+    pub fn lead(column: &str, offset: Option<i64>) -> Self {
+        match offset {
+            Some(offset) => Self::new(format!("LEAD({}, {})", column, offset)),
+            None => Self::new(format!("LEAD({})", column)),
+        }
+    }
+
+    /// `FIRST_VALUE(column)`
+    pub fn first_value(column: &str) -> Self {
+        Self::new(format!("FIRST_VALUE({})", column))
+    }
+
+    /// `LAST_VALUE(column)`
+    pub fn last_value(column: &str) -> Self {
+        Self::new(format!("LAST_VALUE({})", column))
+    }
+
+    /// `NTH_VALUE(column, n)`
+    pub fn nth_value(column: &str, n: i64) -> Self {
+        Self::new(format!("NTH_VALUE({}, {})", column, n))
+    }
+
+    /// `SUM(column)`
+    pub fn sum(column: &str) -> Self {
+        Self::new(format!("SUM({})", column))
+    }
+
+    /// `AVG(column)`
+    pub fn avg(column: &str) -> Self {
+        Self::new(format!("AVG({})", column))
+    }
+
+    /// `COUNT(column)`
+    pub fn count(column: &str) -> Self {
+        Self::new(format!("COUNT({})", column))
+    }
+
+    /// `MIN(column)`
+    pub fn min(column: &str) -> Self {
+        Self::new(format!("MIN({})", column))
+    }
+
+    /// `MAX(column)`
+    pub fn max(column: &str) -> Self {
+        Self::new(format!("MAX({})", column))
+    }
+
+    /// inline window specification, `OVER (...)`
+    /// ```rust
+    /// use qorm::window::{Window, WindowFunction};
+    ///
+    /// let function = WindowFunction::row_number()
+    ///     .over(Window::new().partition_by(vec!["t.created_by"]).order_by(vec!["t.id"]))
+    ///     .to_sql();
+    /// assert_eq!(function, "ROW_NUMBER() OVER (PARTITION BY t.created_by ORDER BY t.id)");
+    /// ```
+    pub fn over(&mut self, window: &Window) -> &mut Self {
+        self.over = Some(Over::Window(window.clone()));
+        self
+    }
+
+    /// reference named window declared with [`crate::Select::window`], `OVER name`
+    /// ```rust
+    /// use qorm::window::WindowFunction;
+    ///
+    /// let function = WindowFunction::sum("t.amount").over_window("w").to_sql();
+    /// assert_eq!(function, "SUM(t.amount) OVER w");
+    /// ```
+    pub fn over_window(&mut self, name: &str) -> &mut Self {
+        self.over = Some(Over::Named(name.to_string()));
+        self
+    }
+
+    /// get generated window function
+    pub fn to_sql(&self) -> String {
+        match &self.over {
+            Some(Over::Window(window)) => format!("{} OVER ({})", self.function, window.to_sql()),
+            Some(Over::Named(name)) => format!("{} OVER {}", self.function, name),
+            None => format!("{} OVER ()", self.function),
+        }
+    }
+}
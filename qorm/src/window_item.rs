@@ -0,0 +1,59 @@
+/// window frame unit
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameUnit {
+    Rows,
+    Range,
+    Groups,
+}
+
+/// window frame boundary
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(i64),
+    CurrentRow,
+    Following(i64),
+    UnboundedFollowing,
+}
+
+/// window frame, `end` empty means frame is only bounded by `start`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frame {
+    pub unit: FrameUnit,
+    pub start: FrameBound,
+    pub end: Option<FrameBound>,
+}
+
+impl FrameBound {
+    pub(crate) fn to_sql(&self) -> String {
+        match self {
+            FrameBound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_string(),
+            FrameBound::Preceding(offset) => format!("{} PRECEDING", offset),
+            FrameBound::CurrentRow => "CURRENT ROW".to_string(),
+            FrameBound::Following(offset) => format!("{} FOLLOWING", offset),
+            FrameBound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_string(),
+        }
+    }
+}
+
+impl Frame {
+    pub(crate) fn to_sql(&self) -> String {
+        let unit = match self.unit {
+            FrameUnit::Rows => "ROWS",
+            FrameUnit::Range => "RANGE",
+            FrameUnit::Groups => "GROUPS",
+        };
+        match &self.end {
+            Some(end) => format!(
+                "{} BETWEEN {} AND {}",
+                unit,
+                self.start.to_sql(),
+                end.to_sql()
+            ),
+            None => format!("{} {}", unit, self.start.to_sql()),
+        }
+    }
+}
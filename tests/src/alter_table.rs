@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        alter_table_item::Constraint,
+        create_table_item::{ColumnDef, ColumnType, ForeignKey, ReferentialAction},
+        sqlite::rusqlite::Connection,
+        AlterTable, Bind, CreateTable, Dialect, DropTable, Error, Executor, FromRow, Insert,
+        RenameTable, Select,
+    };
+
+    #[test]
+    fn alter_table_postgres_query() {
+        let mut builder = AlterTable::new("todo");
+        builder
+            .dialect(Dialect::Postgres)
+            .add_column(
+                ColumnDef::new("user_id", ColumnType::BigInt)
+                    .references("user", "id")
+                    .on_delete(ReferentialAction::Cascade),
+            )
+            .drop_column("note")
+            .alter_type("point", ColumnType::Double)
+            .set_default("point", Bind::Float(1.5))
+            .set_default_raw("created_at", "now()")
+            .drop_default("name")
+            .drop_not_null("name")
+            .add_constraint(
+                "todo_point_check",
+                Constraint::Check("point > 0".to_string()),
+            )
+            .add_constraint(
+                "todo_name_key",
+                Constraint::Unique(vec!["name".to_string()]),
+            )
+            .drop_constraint("todo_old_key");
+        assert_eq!(
+            builder.to_sql(),
+            r#"ALTER TABLE "todo" ADD COLUMN "user_id" BIGINT REFERENCES "user" ("id") ON DELETE CASCADE, DROP COLUMN "note", ALTER COLUMN "point" TYPE DOUBLE PRECISION, ALTER COLUMN "point" SET DEFAULT 1.5, ALTER COLUMN "created_at" SET DEFAULT now(), ALTER COLUMN "name" DROP DEFAULT, ALTER COLUMN "name" DROP NOT NULL, ADD CONSTRAINT "todo_point_check" CHECK (point > 0), ADD CONSTRAINT "todo_name_key" UNIQUE ("name"), DROP CONSTRAINT "todo_old_key""#
+        );
+    }
+
+    #[test]
+    fn alter_table_mysql_query() {
+        let mut builder = AlterTable::new("todo");
+        builder
+            .dialect(Dialect::MySql)
+            .add_column(ColumnDef::new("user_id", ColumnType::BigInt).references("user", "id"))
+            .modify_column(ColumnDef::new("point", ColumnType::Double).not_null())
+            .rename_column("name", "title")
+            .add_constraint("todo_pk", Constraint::PrimaryKey(vec!["id".to_string()]));
+        assert_eq!(
+            builder.to_sql(),
+            "ALTER TABLE `todo` ADD COLUMN `user_id` BIGINT, ADD FOREIGN KEY (`user_id`) REFERENCES `user` (`id`), MODIFY COLUMN `point` DOUBLE NOT NULL, RENAME COLUMN `name` TO `title`, ADD CONSTRAINT `todo_pk` PRIMARY KEY (`id`)"
+        );
+
+        let mut builder = AlterTable::new("todo");
+        builder.dialect(Dialect::MySql).set_not_null("name");
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "SET NOT NULL".to_string(),
+                dialect: Dialect::MySql,
+            })
+        );
+
+        let mut builder = AlterTable::new("todo");
+        builder
+            .dialect(Dialect::Postgres)
+            .modify_column(&ColumnDef::new("point", ColumnType::Double));
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "MODIFY COLUMN".to_string(),
+                dialect: Dialect::Postgres,
+            })
+        );
+    }
+
+    #[test]
+    fn alter_table_sqlite_invalid() {
+        let mut builder = AlterTable::new("todo");
+        builder
+            .dialect(Dialect::Sqlite)
+            .drop_column("note")
+            .drop_column("point");
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "multiple ALTER TABLE operations".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+
+        let mut builder = AlterTable::new("todo");
+        builder.dialect(Dialect::Sqlite).add_constraint(
+            "todo_user_fk",
+            Constraint::ForeignKey(ForeignKey::new(vec!["user_id"], "user", vec!["id"])),
+        );
+        assert!(builder.validate().is_err());
+
+        let mut builder = AlterTable::new("todo");
+        builder
+            .dialect(Dialect::Sqlite)
+            .add_column(ColumnDef::new("code", ColumnType::Text).not_null());
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "ADD COLUMN NOT NULL without DEFAULT".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+
+        // invalid column still renders, error is only returned by validate
+        let mut builder = AlterTable::new("todo");
+        builder
+            .dialect(Dialect::Sqlite)
+            .add_column(ColumnDef::new("code", ColumnType::Text).auto_increment());
+        assert!(builder.try_to_sql().is_err());
+        assert_eq!(
+            builder.to_sql(),
+            r#"ALTER TABLE "todo" ADD COLUMN "code" INTEGER AUTOINCREMENT"#
+        );
+
+        let mut builder = AlterTable::new("todo");
+        builder
+            .dialect(Dialect::MySql)
+            .modify_column(ColumnDef::new("code", ColumnType::Text).auto_increment());
+        assert!(builder.try_to_sql().is_err());
+        assert_eq!(
+            builder.to_sql(),
+            "ALTER TABLE `todo` MODIFY COLUMN `code` TEXT NOT NULL AUTO_INCREMENT"
+        );
+
+        assert_eq!(
+            AlterTable::new("todo").validate(),
+            Err(Error::Invalid(
+                "ALTER TABLE todo requires at least one operation".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn drop_rename_table_query() {
+        let mut builder = DropTable::new("todo");
+        assert_eq!(builder.to_sql(), "DROP TABLE todo");
+        builder.dialect(Dialect::MySql).if_exists().cascade();
+        assert_eq!(builder.to_sql(), "DROP TABLE IF EXISTS `todo` CASCADE");
+        builder.dialect(Dialect::Sqlite);
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "DROP TABLE CASCADE".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+
+        let mut builder = RenameTable::new("todo", "task");
+        builder.dialect(Dialect::Sqlite);
+        assert_eq!(builder.to_sql(), r#"ALTER TABLE "todo" RENAME TO "task""#);
+    }
+
+    #[derive(FromRow, Debug, PartialEq)]
+    struct Task {
+        id: i64,
+        title: String,
+        point: i64,
+    }
+
+    #[test]
+    fn alter_table_sqlite() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let mut builder = CreateTable::new("todo");
+        builder
+            .dialect(Dialect::Sqlite)
+            .column(ColumnDef::new("id", ColumnType::Integer).primary_key())
+            .column(ColumnDef::new("name", ColumnType::Text).not_null())
+            .column(&ColumnDef::new("note", ColumnType::Text));
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        let mut builder = Insert::new("todo", None);
+        builder.values(vec![("name", Bind::from("Foo"))]);
+        Executor::execute(&mut conn, &mut builder).unwrap();
+
+        let mut builder = AlterTable::new("todo");
+        builder.add_column(
+            ColumnDef::new("point", ColumnType::Integer)
+                .not_null()
+                .default(Bind::Int(3)),
+        );
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        let mut builder = AlterTable::new("todo");
+        builder.rename_column("name", "title");
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        let mut builder = AlterTable::new("todo");
+        builder.drop_column("note");
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        Executor::execute(&mut conn, &mut RenameTable::new("todo", "task")).unwrap();
+
+        let mut builder = Select::new("task", None, None);
+        builder.select_row::<Task>();
+        let task: Task = conn.fetch_one(&mut builder).unwrap();
+        assert_eq!(
+            task,
+            Task {
+                id: 1,
+                title: "Foo".to_string(),
+                point: 3,
+            }
+        );
+
+        Executor::execute(&mut conn, DropTable::new("task").if_exists()).unwrap();
+        assert!(conn.fetch_optional::<Task, _>(&mut builder).is_err());
+    }
+
+    #[test]
+    fn alter_table_sqlite_unsupported() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE todo (id INTEGER PRIMARY KEY, point INTEGER)")
+            .unwrap();
+
+        let mut builder = AlterTable::new("todo");
+        builder.alter_type("point", ColumnType::Double);
+        assert_eq!(
+            Executor::execute(&mut conn, &mut builder),
+            Err(Error::Unsupported {
+                feature: "ALTER COLUMN TYPE".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+        assert!(matches!(
+            Executor::execute(&mut conn, DropTable::new("todo").cascade()),
+            Err(Error::Unsupported { .. })
+        ));
+    }
+}
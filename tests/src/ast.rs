@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        expr::Expr,
+        select_item::{Join, SelectStatement},
+        table::Table,
+        where_item::{Condition, Or},
+        window::{Window, WindowFunction},
+        Bind, Dialect, Select,
+    };
+
+    fn report() -> Select {
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .dialect(Dialect::Postgres)
+            .select("t.id")
+            .select_as(Expr::count(Expr::column("u.id")), "users")
+            .select_window(
+                WindowFunction::row_number().over(Window::new().order_by(vec!["t.id"])),
+                "rn",
+            )
+            .join(Some("LEFT"), "user u", "u.id = t.created_by")
+            .wheres("t.is_done", "=", Bind::Bool(false))
+            .wheres("t.deleted_at", "IS", Bind::Null)
+            .where_or(vec![
+                Or {
+                    column: "t.point",
+                    operator: ">",
+                    value: Bind::BigInt(10),
+                },
+                Or {
+                    column: "t.name",
+                    operator: "LIKE",
+                    value: Bind::String("%urgent%".to_string()),
+                },
+            ])
+            .group_by(vec!["t.id"])
+            .order_by(vec!["t.id DESC"])
+            .limit(10)
+            .offset(20);
+        builder
+    }
+
+    #[test]
+    fn select_ast() {
+        let builder = report();
+        let ast = builder.ast();
+        assert_eq!(
+            ast.table,
+            Table {
+                name: "todo".to_string(),
+                alias: Some("t".to_string()),
+            }
+        );
+        assert_eq!(
+            ast.join,
+            Some(vec![Join {
+                join_type: Some("LEFT".to_string()),
+                table_name: "user u".to_string(),
+                on: "u.id = t.created_by".to_string(),
+            }])
+        );
+        assert_eq!(
+            ast.where_and,
+            Some(vec![
+                Condition::new("t.is_done", "=", Bind::Bool(false)),
+                Condition::new("t.deleted_at", "IS", Bind::Null),
+            ])
+        );
+        assert_eq!(ast.where_or.as_ref().unwrap()[0].len(), 2);
+        assert_eq!(ast.limit, Some(10));
+    }
+
+    #[test]
+    fn select_ast_json_round_trip() {
+        let mut builder = report();
+        let (sql, binds) = builder.to_sql_with_bind();
+        let json = serde_json::to_string(&builder.ast()).unwrap();
+
+        let ast: SelectStatement = serde_json::from_str(&json).unwrap();
+        assert_eq!(ast, builder.ast());
+        let mut rebuilt = Select::from_ast(&ast, None);
+        assert_eq!(rebuilt.to_sql_with_bind(), (sql, binds));
+    }
+
+    #[test]
+    fn select_ast_partial_json() {
+        let ast: SelectStatement = serde_json::from_str(
+            r#"{
+                "table": {"name": "todo", "alias": "t"},
+                "dialect": "postgres",
+                "where_and": [
+                    {"column": "t.is_done", "operator": "=", "value": {"Bool": false}},
+                    {"column": "t.name", "operator": "LIKE", "value": {"String": "%a%"}}
+                ],
+                "order_by": ["t.id DESC"],
+                "limit": 10
+            }"#,
+        )
+        .unwrap();
+        let mut builder = Select::from_ast(&ast, None);
+        assert_eq!(
+            builder.to_sql_with_bind(),
+            (
+                "SELECT * FROM todo t WHERE t.is_done = $1 AND t.name LIKE $2 ORDER BY t.id DESC LIMIT 10"
+                    .to_string(),
+                vec![Bind::Bool(false), Bind::String("%a%".to_string())]
+            )
+        );
+
+        // rebuilt builder keeps accepting conditions
+        builder.wheres("t.point", ">", Bind::Int(1));
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT * FROM todo t WHERE t.is_done = $1 AND t.name LIKE $2 AND t.point > $3 ORDER BY t.id DESC LIMIT 10"
+        );
+        assert!(serde_json::from_str::<SelectStatement>(r#"{"dialect": "oracle"}"#).is_err());
+    }
+}
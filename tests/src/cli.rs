@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use qorm::{sqlite::rusqlite::Connection, Dialect};
+    use qorm_cli::{migrate, render, schema};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("qorm_cli_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn migrate_new_up_status_down() {
+        let dir = temp_dir("migrate");
+        let paths = migrate::new(&dir, "create_todo").unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                dir.join("0001_create_todo.up.sql"),
+                dir.join("0001_create_todo.down.sql"),
+            ]
+        );
+        fs::write(
+            &paths[0],
+            "CREATE TABLE todo (id INTEGER PRIMARY KEY, name TEXT NOT NULL);",
+        )
+        .unwrap();
+        fs::write(&paths[1], "DROP TABLE todo;").unwrap();
+        let paths = migrate::new(&dir, "add_todo_index").unwrap();
+        assert_eq!(paths[0], dir.join("0002_add_todo_index.up.sql"));
+        fs::write(&paths[0], "CREATE INDEX todo_name_idx ON todo (name);").unwrap();
+        fs::write(&paths[1], "DROP INDEX todo_name_idx;").unwrap();
+
+        let mut conn = Connection::open_in_memory().unwrap();
+        assert_eq!(
+            migrate::status(&mut conn, &dir).unwrap(),
+            "1 create_todo pending\n2 add_todo_index pending"
+        );
+        assert_eq!(
+            migrate::up(&mut conn, &dir, true).unwrap(),
+            "-- 1 create_todo
+CREATE TABLE todo (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+
+-- 2 add_todo_index
+CREATE INDEX todo_name_idx ON todo (name);
+"
+        );
+        assert_eq!(
+            migrate::up(&mut conn, &dir, false).unwrap(),
+            "applied 1 create_todo\napplied 2 add_todo_index"
+        );
+        assert_eq!(
+            migrate::up(&mut conn, &dir, false).unwrap(),
+            "nothing to do"
+        );
+        assert_eq!(
+            schema::dump(&mut conn).unwrap(),
+            "CREATE TABLE todo (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+CREATE INDEX todo_name_idx ON todo (name);"
+        );
+
+        fs::write(
+            dir.join("0002_add_todo_index.up.sql"),
+            "CREATE UNIQUE INDEX todo_name_idx ON todo (name);",
+        )
+        .unwrap();
+        assert_eq!(
+            migrate::status(&mut conn, &dir).unwrap(),
+            "1 create_todo applied\n2 add_todo_index changed"
+        );
+        fs::remove_file(dir.join("0002_add_todo_index.up.sql")).unwrap();
+        fs::remove_file(dir.join("0002_add_todo_index.down.sql")).unwrap();
+        assert_eq!(
+            migrate::status(&mut conn, &dir).unwrap(),
+            "1 create_todo applied\n2 add_todo_index missing"
+        );
+        fs::write(
+            dir.join("0002_add_todo_index.up.sql"),
+            "CREATE INDEX todo_name_idx ON todo (name);",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("0002_add_todo_index.down.sql"),
+            "DROP INDEX todo_name_idx;",
+        )
+        .unwrap();
+        assert_eq!(
+            migrate::down(&mut conn, &dir, 2, false).unwrap(),
+            "rolled back 2 add_todo_index\nrolled back 1 create_todo"
+        );
+        assert_eq!(schema::dump(&mut conn).unwrap(), "");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn migrate_new_invalid_name() {
+        let dir = temp_dir("invalid");
+        assert_eq!(
+            migrate::new(&dir, "create todo").unwrap_err().to_string(),
+            "migration name create todo must only contain letters, digits and _"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn render_toml_select() {
+        let source = r#"
+type = "select"
+table = "user"
+alias = "u"
+columns = ["u.id", "u.name"]
+order_by = ["u.id DESC"]
+limit = 10
+where_or = [[
+    { column = "u.id", operator = "=", value = 1 },
+    { column = "u.is_active", operator = "=", value = true },
+]]
+
+[[joins]]
+type = "LEFT"
+table = "role r"
+on = "r.id = u.role_id"
+
+[[where]]
+column = "u.name"
+operator = "LIKE"
+value = "foo%"
+"#;
+        assert_eq!(
+            render::render(source, true, Dialect::Postgres, false).unwrap(),
+            "SELECT u.id, u.name FROM user u LEFT JOIN role r ON r.id = u.role_id WHERE u.name LIKE $1 AND ( u.id = $2 OR u.is_active = $3) ORDER BY u.id DESC LIMIT 10;
+-- bind 1: 'foo%'
+-- bind 2: 1
+-- bind 3: TRUE"
+        );
+    }
+
+    #[test]
+    fn render_json() {
+        let insert = r#"{"type": "insert", "table": "user", "values": {"name": "foo", "point": 5000000000, "score": 1.5, "note": null}}"#;
+        assert_eq!(
+            render::render(insert, false, Dialect::Sqlite, false).unwrap(),
+            "INSERT INTO user (name,point,score,note) VALUES (?,?,?,?);
+-- bind 1: 'foo'
+-- bind 2: 5000000000
+-- bind 3: 1.5
+-- bind 4: NULL"
+        );
+        let update = r#"{
+            "type": "update",
+            "table": "user",
+            "set": {"name": "foo", "is_active": true},
+            "where": [{"column": "id", "operator": "=", "value": 1}]
+        }"#;
+        assert_eq!(
+            render::render(update, false, Dialect::Sqlite, false).unwrap(),
+            "UPDATE user SET name = ?,is_active = ? WHERE id = ?;
+-- bind 1: 'foo'
+-- bind 2: 1
+-- bind 3: 1"
+        );
+        let delete = r#"{"type": "delete", "table": "user", "where": [{"column": "id", "operator": "=", "value": 1}]}"#;
+        assert_eq!(
+            render::render(delete, false, Dialect::MySql, false).unwrap(),
+            "DELETE FROM user WHERE id = ?;
+-- bind 1: 1"
+        );
+    }
+
+    #[test]
+    fn render_invalid() {
+        assert!(render::render(
+            r#"{"type": "truncate", "table": "user"}"#,
+            false,
+            Dialect::Sqlite,
+            false
+        )
+        .is_err());
+        assert!(render::render(
+            r#"{"type": "select", "table": "user", "wheres": []}"#,
+            false,
+            Dialect::Sqlite,
+            false
+        )
+        .is_err());
+        assert!(render::render(
+            r#"{"type": "select", "table": "user", "where": [{"column": "id", "operator": "IN", "value": [1]}]}"#,
+            false,
+            Dialect::Sqlite,
+            false
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        create_index_item::IndexMethod,
+        create_table_item::{ColumnDef, ColumnType},
+        sqlite::rusqlite::Connection,
+        where_item::Or,
+        Bind, CreateIndex, CreateTable, Dialect, DropIndex, Error, Executor, Insert,
+    };
+
+    #[test]
+    fn create_index_postgres_query() {
+        let mut builder = CreateIndex::new("todo_tags_idx", "todo");
+        builder
+            .dialect(Dialect::Postgres)
+            .unique()
+            .using(IndexMethod::Gin)
+            .column("tags")
+            .expression("lower(name)")
+            .wheres("is_done", "=", Bind::Bool(false))
+            .wheres("name", "LIKE", Bind::from("it's%"))
+            .where_or(vec![
+                Or {
+                    column: "point",
+                    operator: ">",
+                    value: Bind::Int(1),
+                },
+                Or {
+                    column: "deleted_at",
+                    operator: "IS NOT",
+                    value: Bind::Null,
+                },
+            ]);
+        assert_eq!(
+            builder.to_sql(),
+            r#"CREATE UNIQUE INDEX "todo_tags_idx" ON "todo" USING GIN ("tags", (lower(name))) WHERE is_done = FALSE AND name LIKE 'it''s%' AND ( point > 1 OR deleted_at IS NOT NULL)"#
+        );
+    }
+
+    #[test]
+    fn create_index_invalid() {
+        assert_eq!(
+            CreateIndex::new("todo_idx", "todo").validate(),
+            Err(Error::Invalid(
+                "CREATE INDEX todo_idx requires at least one column or expression".to_string()
+            ))
+        );
+
+        let mut builder = CreateIndex::new("todo_idx", "todo");
+        builder
+            .dialect(Dialect::Sqlite)
+            .concurrently()
+            .column("name");
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "CREATE INDEX CONCURRENTLY".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+
+        let mut builder = CreateIndex::new("todo_idx", "todo");
+        builder
+            .dialect(Dialect::Sqlite)
+            .using(IndexMethod::BTree)
+            .column("name");
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "index method BTREE".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+
+        let mut builder = CreateIndex::new("todo_idx", "todo");
+        builder
+            .dialect(Dialect::MySql)
+            .column("name")
+            .wheres("is_done", "=", Bind::Bool(false));
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "partial index".to_string(),
+                dialect: Dialect::MySql,
+            })
+        );
+
+        let mut builder = CreateIndex::new("todo_idx", "todo");
+        builder
+            .dialect(Dialect::MySql)
+            .if_not_exists()
+            .column("name");
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "CREATE INDEX IF NOT EXISTS".to_string(),
+                dialect: Dialect::MySql,
+            })
+        );
+    }
+
+    #[test]
+    fn drop_index_query() {
+        let mut builder = DropIndex::new("todo_idx");
+        assert_eq!(builder.to_sql(), r#"DROP INDEX todo_idx"#);
+        builder.dialect(Dialect::Postgres).if_exists().cascade();
+        assert_eq!(
+            builder.to_sql(),
+            r#"DROP INDEX IF EXISTS "todo_idx" CASCADE"#
+        );
+        builder.dialect(Dialect::Sqlite);
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "DROP INDEX CASCADE".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+
+        // missing table is only reported by validate
+        let mut builder = DropIndex::new("todo_idx");
+        builder.dialect(Dialect::MySql);
+        assert!(builder.try_to_sql().is_err());
+        assert_eq!(builder.to_sql(), "DROP INDEX `todo_idx`");
+
+        let mut builder = DropIndex::new("todo_idx");
+        builder.dialect(Dialect::MySql).table("todo").if_exists();
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "DROP INDEX IF EXISTS".to_string(),
+                dialect: Dialect::MySql,
+            })
+        );
+    }
+
+    #[test]
+    fn create_index_sqlite() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let mut builder = CreateTable::new("user");
+        builder
+            .column(ColumnDef::new("id", ColumnType::Integer).primary_key())
+            .column(ColumnDef::new("email", ColumnType::Text).not_null())
+            .column(&ColumnDef::new("deleted_at", ColumnType::Text));
+        Executor::execute(&mut conn, &mut builder).unwrap();
+
+        let mut builder = CreateIndex::new("user_email_idx", "user");
+        builder
+            .unique()
+            .if_not_exists()
+            .expression("lower(email)")
+            .wheres("deleted_at", "IS", Bind::Null);
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        // second run is a no-op
+        Executor::execute(&mut conn, &mut builder).unwrap();
+
+        let mut builder = Insert::new("user", None);
+        builder.values(vec![("email", Bind::from("foo@mail.com"))]);
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        let mut builder = Insert::new("user", None);
+        builder.values(vec![
+            ("email", Bind::from("FOO@mail.com")),
+            ("deleted_at", Bind::from("2024-01-01")),
+        ]);
+        // deleted row is outside of the partial index
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        let mut builder = Insert::new("user", None);
+        builder.values(vec![("email", Bind::from("FOO@mail.com"))]);
+        assert!(Executor::execute(&mut conn, &mut builder).is_err());
+
+        let mut builder = DropIndex::new("user_email_idx");
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        let mut builder = Insert::new("user", None);
+        builder.values(vec![("email", Bind::from("FOO@mail.com"))]);
+        Executor::execute(&mut conn, &mut builder).unwrap();
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        create_table_item::{ColumnDef, ColumnType, ForeignKey, ReferentialAction},
+        sqlite::rusqlite::Connection,
+        Bind, CreateTable, Delete, Dialect, Error, Executor, FromRow, Insert, Select,
+    };
+
+    fn todo_table() -> CreateTable {
+        let mut builder = CreateTable::new("todo");
+        builder
+            .column(
+                ColumnDef::new("id", ColumnType::BigInt)
+                    .primary_key()
+                    .auto_increment(),
+            )
+            .column(ColumnDef::new("name", ColumnType::Varchar(100)).not_null())
+            .column(
+                ColumnDef::new("point", ColumnType::Decimal(10, 2))
+                    .not_null()
+                    .default(Bind::Int(0))
+                    .check("point >= 0"),
+            )
+            .column(
+                ColumnDef::new("created_at", ColumnType::Timestamp)
+                    .not_null()
+                    .default_raw("CURRENT_TIMESTAMP"),
+            )
+            .column(
+                ColumnDef::new("user_id", ColumnType::BigInt)
+                    .references("user", "id")
+                    .on_delete(ReferentialAction::Cascade)
+                    .on_update(ReferentialAction::NoAction),
+            )
+            .unique(vec!["user_id", "name"]);
+        builder
+    }
+
+    #[test]
+    fn create_table_query() {
+        let mut builder = todo_table();
+        assert_eq!(
+            builder.dialect(Dialect::Postgres).to_sql(),
+            r#"CREATE TABLE "todo" ("id" BIGSERIAL PRIMARY KEY, "name" VARCHAR(100) NOT NULL, "point" NUMERIC(10, 2) NOT NULL DEFAULT 0 CHECK (point >= 0), "created_at" TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, "user_id" BIGINT REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE NO ACTION, UNIQUE ("user_id", "name"))"#
+        );
+        assert_eq!(
+            builder.dialect(Dialect::MySql).to_sql(),
+            "CREATE TABLE `todo` (`id` BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY, `name` VARCHAR(100) NOT NULL, `point` DECIMAL(10, 2) NOT NULL DEFAULT 0 CHECK (point >= 0), `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP, `user_id` BIGINT, UNIQUE (`user_id`, `name`), FOREIGN KEY (`user_id`) REFERENCES `user` (`id`) ON DELETE CASCADE ON UPDATE NO ACTION)"
+        );
+        assert_eq!(
+            builder.dialect(Dialect::Sqlite).to_sql(),
+            r#"CREATE TABLE "todo" ("id" INTEGER PRIMARY KEY AUTOINCREMENT, "name" VARCHAR(100) NOT NULL, "point" NUMERIC(10, 2) NOT NULL DEFAULT 0 CHECK (point >= 0), "created_at" TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, "user_id" BIGINT REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE NO ACTION, UNIQUE ("user_id", "name"))"#
+        );
+    }
+
+    #[test]
+    fn create_table_generated_query() {
+        let mut builder = CreateTable::new("invoice");
+        builder
+            .dialect(Dialect::Postgres)
+            .if_not_exists()
+            .column(ColumnDef::new("id", ColumnType::BigInt).identity(false))
+            .column(ColumnDef::new("amount", ColumnType::Double).not_null())
+            .column(ColumnDef::new("tax", ColumnType::Double).generated("amount * 0.1", true))
+            .column(ColumnDef::new("code", ColumnType::Uuid).unique())
+            .primary_key(vec!["id"])
+            .check("amount > 0")
+            .foreign_key(
+                ForeignKey::new(vec!["code"], "voucher", vec!["code"])
+                    .on_delete(ReferentialAction::SetNull),
+            );
+        assert_eq!(
+            builder.to_sql(),
+            r#"CREATE TABLE IF NOT EXISTS "invoice" ("id" BIGINT GENERATED BY DEFAULT AS IDENTITY, "amount" DOUBLE PRECISION NOT NULL, "tax" DOUBLE PRECISION GENERATED ALWAYS AS (amount * 0.1) STORED, "code" UUID UNIQUE, PRIMARY KEY ("id"), CHECK (amount > 0), FOREIGN KEY ("code") REFERENCES "voucher" ("code") ON DELETE SET NULL)"#
+        );
+    }
+
+    #[test]
+    fn create_table_invalid() {
+        let builder = CreateTable::new("todo");
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Invalid(
+                "CREATE TABLE todo requires at least one column".to_string()
+            ))
+        );
+
+        let mut builder = CreateTable::new("todo");
+        builder
+            .dialect(Dialect::MySql)
+            .column(ColumnDef::new("id", ColumnType::Text).auto_increment());
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Invalid(
+                "auto increment column id requires integer type".to_string()
+            ))
+        );
+
+        let mut builder = CreateTable::new("todo");
+        builder
+            .dialect(Dialect::Sqlite)
+            .column(ColumnDef::new("id", ColumnType::Integer).auto_increment());
+        assert!(builder.validate().is_err());
+
+        // invalid column still renders, error is only returned by validate
+        let mut builder = CreateTable::new("todo");
+        builder
+            .dialect(Dialect::Sqlite)
+            .column(ColumnDef::new("id", ColumnType::Text).auto_increment());
+        assert!(builder.try_to_sql().is_err());
+        assert_eq!(
+            builder.to_sql(),
+            r#"CREATE TABLE "todo" ("id" INTEGER AUTOINCREMENT)"#
+        );
+
+        let mut builder = CreateTable::new("todo");
+        builder
+            .dialect(Dialect::Postgres)
+            .column(ColumnDef::new("total", ColumnType::Integer).generated("1 + 1", false));
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "VIRTUAL generated column".to_string(),
+                dialect: Dialect::Postgres,
+            })
+        );
+
+        let mut builder = CreateTable::new("todo");
+        builder
+            .column(ColumnDef::new("id", ColumnType::Integer).primary_key())
+            .primary_key(vec!["id"]);
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Invalid(
+                "table todo has multiple primary keys".to_string()
+            ))
+        );
+    }
+
+    #[derive(FromRow, Debug, PartialEq)]
+    struct Todo {
+        id: i64,
+        name: String,
+        point: f64,
+    }
+
+    #[test]
+    fn create_table_sqlite() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys = ON").unwrap();
+        let mut builder = CreateTable::new("user");
+        builder.column(
+            ColumnDef::new("id", ColumnType::Integer)
+                .primary_key()
+                .auto_increment(),
+        );
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        Executor::execute(&mut conn, &mut todo_table()).unwrap();
+
+        let mut builder = Insert::new("user", None);
+        builder.values(vec![("id", Bind::Int(1))]);
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        let mut builder = Insert::new("todo", None);
+        builder.values(vec![("name", Bind::from("Foo")), ("user_id", Bind::Int(1))]);
+        Executor::execute(&mut conn, &mut builder).unwrap();
+
+        let mut builder = Select::new("todo", None, None);
+        builder.select_row::<Todo>();
+        let todo: Todo = conn.fetch_one(&mut builder).unwrap();
+        assert_eq!(
+            todo,
+            Todo {
+                id: 1,
+                name: "Foo".to_string(),
+                point: 0.0,
+            }
+        );
+
+        let mut builder = Delete::new("user", None);
+        Executor::execute(&mut conn, &mut builder).unwrap();
+        let mut builder = Select::new("todo", None, None);
+        builder.select_row::<Todo>();
+        let todo: Option<Todo> = conn.fetch_optional(&mut builder).unwrap();
+        assert_eq!(todo, None);
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        format::{format_sql, FormatConfig},
+        where_item::Or,
+        window::{Window, WindowFunction},
+        Bind, Dialect, Insert, Select, Update,
+    };
+
+    #[test]
+    fn format_select_query() {
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder.dialect(Dialect::Postgres);
+        builder
+            .select("t.id")
+            .select_window(
+                WindowFunction::row_number().over(Window::new().order_by(vec!["t.id DESC"])),
+                "rn",
+            )
+            .join(None, "role r", "r.id = t.role_id")
+            .wheres("t.created_at", ">=", Bind::Int(1))
+            .where_or(vec![
+                Or {
+                    column: "t.id",
+                    operator: "=",
+                    value: Bind::Int(2),
+                },
+                Or {
+                    column: "t.is_done",
+                    operator: "=",
+                    value: Bind::Bool(true),
+                },
+            ])
+            .order_by(vec!["t.id"])
+            .limit(5)
+            .for_update()
+            .skip_locked();
+        let sql = builder.to_sql();
+        assert_eq!(
+            format_sql(&sql, None),
+            "SELECT t.id, ROW_NUMBER() OVER (ORDER BY t.id DESC) AS \"rn\"
+FROM todo t
+JOIN role r ON r.id = t.role_id
+WHERE t.created_at >= $1
+  AND (t.id = $2 OR t.is_done = $3)
+ORDER BY t.id
+LIMIT 5
+FOR UPDATE SKIP LOCKED"
+        );
+    }
+
+    #[test]
+    fn format_cte_query() {
+        let sql = "with recent as (select * from todo where created_at > $1), done as (select id from recent where is_done = $2) select count(*) from done where id between $3 and $4 union all select 1";
+        assert_eq!(
+            format_sql(sql, None),
+            "WITH recent AS (
+  SELECT *
+  FROM todo
+  WHERE created_at > $1
+), done AS (
+  SELECT id
+  FROM recent
+  WHERE is_done = $2
+)
+SELECT count(*)
+FROM done
+WHERE id BETWEEN $3 AND $4
+UNION ALL
+SELECT 1"
+        );
+    }
+
+    #[test]
+    fn format_insert_update_query() {
+        let mut builder = Insert::new("todo", None);
+        builder.values(vec![("name", Bind::from("a, (b)")), ("note", Bind::Null)]);
+        assert_eq!(
+            format_sql(&builder.to_debug_sql(), None),
+            "-- debug sql with inlined binds, not for execution
+INSERT INTO todo (name, note)
+VALUES ('a, (b)', NULL)"
+        );
+
+        let mut builder = Update::new("todo", None);
+        builder
+            .set(vec![("name", Bind::from("Foo"))])
+            .wheres("id", "=", Bind::Int(1))
+            .wheres("is_done", "=", Bind::Bool(false));
+        assert_eq!(
+            format_sql(
+                &builder.to_sql(),
+                Some(FormatConfig {
+                    indent: "    ".to_string(),
+                    uppercase: false,
+                })
+            ),
+            "UPDATE todo
+SET name = ?
+WHERE id = ?
+    AND is_done = ?"
+        );
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use qorm::{insert_item::InsertConfig, Bind, Insert};
+    use qorm::{
+        insert_item::{InsertConfig, Insertable},
+        Bind, Dialect, Insert, Insertable,
+    };
 
     #[test]
     fn insert_query() {
@@ -31,4 +34,72 @@ mod tests {
             assert_eq!(binds[idx], answer[idx]);
         }
     }
+
+    #[derive(Insertable)]
+    struct NewUser {
+        #[qorm(primary_key, generated)]
+        id: i32,
+        #[qorm(column = "username")]
+        name: String,
+        nickname: Option<String>,
+        #[qorm(skip_none)]
+        role_id: Option<i32>,
+        #[qorm(skip)]
+        #[allow(dead_code)]
+        password_confirmation: String,
+    }
+
+    #[test]
+    fn insert_derive_query() {
+        let user = NewUser {
+            id: 0,
+            name: "foo".to_string(),
+            nickname: None,
+            role_id: None,
+            password_confirmation: "secret".to_string(),
+        };
+        let mut builder = Insert::new("user", None);
+        builder.values(user.insert_values());
+        let (sql, binds) = builder.to_sql_with_bind();
+        assert_eq!(sql, "INSERT INTO user (username,nickname) VALUES (?,?)");
+        assert_eq!(binds, vec![Bind::String("foo".to_string()), Bind::Null]);
+        assert_eq!(user.id, 0);
+
+        let user = NewUser {
+            nickname: Some("bar".to_string()),
+            role_id: Some(2),
+            ..user
+        };
+        assert_eq!(
+            user.insert_values(),
+            vec![
+                ("username", Bind::String("foo".to_string())),
+                ("nickname", Bind::String("bar".to_string())),
+                ("role_id", Bind::Int(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_query() {
+        let mut builder = Insert::new("todo", None);
+        builder.dialect(Dialect::Postgres);
+        builder.values(vec![
+            ("name", Bind::from("Foo")),
+            ("is_done", Bind::Bool(true)),
+            ("payload", Bind::Bytes(vec![1, 2])),
+        ]);
+        assert_eq!(
+            builder.to_debug_sql(),
+            "-- debug sql with inlined binds, not for execution\nINSERT INTO todo (name,is_done,payload) VALUES ('Foo',TRUE,'\\x0102')"
+        );
+        assert_eq!(
+            builder.to_sql(),
+            "INSERT INTO todo (name,is_done,payload) VALUES ($1,$2,$3)"
+        );
+        assert_eq!(
+            builder.to_sql(),
+            "INSERT INTO todo (name,is_done,payload) VALUES ($1,$2,$3)"
+        );
+    }
 }
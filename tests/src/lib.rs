@@ -1,4 +1,22 @@
+mod alter_table;
+mod ast;
+mod cli;
+mod create_index;
+mod create_table;
 mod delete;
+mod format;
 mod insert;
+mod migrate;
+mod mysql;
+mod parse;
+mod postgres;
+mod row;
+mod schema;
+mod scope;
 mod select;
+mod sqlite;
+mod sqlx;
+mod table;
+mod transaction;
 mod update;
+mod window;
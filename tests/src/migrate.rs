@@ -0,0 +1,326 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        create_index_item::IndexMethod,
+        create_table_item::{ColumnDef, ColumnType},
+        migrate::Migrator,
+        migrate_item::Migration,
+        query::Query,
+        row::FromRow,
+        sqlite::rusqlite::{self, Connection},
+        CreateIndex, CreateTable, Dialect, DropTable, Error, Executor,
+    };
+
+    /// executor without database, records executed sql and returns no row
+    #[derive(Default)]
+    struct Recorder {
+        sql: Vec<String>,
+    }
+
+    impl Executor for Recorder {
+        fn execute<Q: Query>(&mut self, query: &mut Q) -> Result<u64, Error> {
+            self.sql.push(query.build()?.0);
+            Ok(0)
+        }
+
+        fn fetch_all<T: FromRow, Q: Query>(&mut self, query: &mut Q) -> Result<Vec<T>, Error> {
+            self.sql.push(query.build()?.0);
+            Ok(vec![])
+        }
+
+        fn fetch_optional<T: FromRow, Q: Query>(
+            &mut self,
+            query: &mut Q,
+        ) -> Result<Option<T>, Error> {
+            self.sql.push(query.build()?.0);
+            Ok(None)
+        }
+    }
+
+    fn create_todo() -> Vec<Box<dyn Query>> {
+        let mut builder = CreateTable::new("todo");
+        builder
+            .column(ColumnDef::new("id", ColumnType::Integer).primary_key())
+            .column(ColumnDef::new("name", ColumnType::Text).not_null());
+        let mut index = CreateIndex::new("todo_name_idx", "todo");
+        index.column("name");
+        vec![Box::new(builder), Box::new(index)]
+    }
+
+    fn drop_todo() -> Vec<Box<dyn Query>> {
+        vec![Box::new(DropTable::new("todo"))]
+    }
+
+    fn migrator() -> Migrator {
+        let mut migrator = Migrator::new(Dialect::Sqlite);
+        migrator
+            .migration(
+                Migration::sql(
+                    2,
+                    "create_tag",
+                    "-- tag table
+                    CREATE TABLE tag (id INTEGER PRIMARY KEY, name TEXT NOT NULL DEFAULT 'a;b');
+                    INSERT INTO tag (name) VALUES ('todo');
+                    /* trailing comment; */",
+                )
+                .down_sql("DROP TABLE tag;"),
+            )
+            .migration(Migration::new(1, "create_todo", create_todo).down(drop_todo));
+        migrator
+    }
+
+    fn count(conn: &Connection, table: &str) -> rusqlite::Result<i64> {
+        conn.query_row(
+            format!("SELECT COUNT(*) FROM {}", table).as_str(),
+            [],
+            |row| row.get(0),
+        )
+    }
+
+    #[test]
+    fn migrate_up_down() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let migrator = migrator();
+        assert_eq!(
+            migrator.dry_run(&mut conn).unwrap(),
+            r#"-- 1 create_todo
+CREATE TABLE "todo" ("id" INTEGER PRIMARY KEY, "name" TEXT NOT NULL);
+CREATE INDEX "todo_name_idx" ON "todo" ("name");
+
+-- 2 create_tag
+-- tag table
+                    CREATE TABLE tag (id INTEGER PRIMARY KEY, name TEXT NOT NULL DEFAULT 'a;b');
+INSERT INTO tag (name) VALUES ('todo');
+"#
+        );
+        // dry run writes nothing, not even migration table
+        assert!(count(&conn, "qorm_migrations").is_err());
+        assert_eq!(migrator.dry_run_down(&mut conn, 1).unwrap(), "");
+        assert!(count(&conn, "qorm_migrations").is_err());
+        assert_eq!(migrator.pending(&mut conn).unwrap().len(), 2);
+
+        assert_eq!(migrator.up(&mut conn).unwrap(), vec![1, 2]);
+        assert_eq!(migrator.up(&mut conn).unwrap(), Vec::<i64>::new());
+        assert_eq!(count(&conn, "tag").unwrap(), 1);
+        let applied = migrator.applied(&mut conn).unwrap();
+        assert_eq!(
+            applied
+                .iter()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["create_todo", "create_tag"]
+        );
+        assert_eq!(
+            applied[1].checksum,
+            migrator.migrations()[1].checksum(Dialect::Sqlite).unwrap()
+        );
+
+        assert_eq!(
+            migrator.dry_run_down(&mut conn, 1).unwrap(),
+            "-- 2 create_tag\nDROP TABLE tag;\n"
+        );
+        assert_eq!(migrator.down(&mut conn, 5).unwrap(), vec![2, 1]);
+        assert!(count(&conn, "tag").is_err());
+        assert!(count(&conn, "todo").is_err());
+        assert!(migrator.applied(&mut conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn migrate_failed_rollback() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let mut migrator = migrator();
+        migrator.migration(&Migration::sql(
+            3,
+            "broken",
+            "CREATE TABLE broken (id INTEGER); INSERT INTO missing (id) VALUES (1);",
+        ));
+        assert!(migrator.up(&mut conn).is_err());
+        // earlier migrations stay applied, failed one is rolled back
+        assert_eq!(migrator.applied(&mut conn).unwrap().len(), 2);
+        assert!(count(&conn, "broken").is_err());
+    }
+
+    #[test]
+    fn migrate_trigger_body() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let mut migrator = migrator();
+        migrator.migration(&Migration::sql(
+            3,
+            "tag_trigger",
+            "CREATE TRIGGER tag_name AFTER INSERT ON tag BEGIN
+                UPDATE tag SET name = CASE WHEN NEW.name = '' THEN 'x;y' ELSE NEW.name END WHERE id = NEW.id;
+                INSERT INTO todo (name) VALUES (NEW.name);
+            END;
+            INSERT INTO tag (name) VALUES ('');",
+        ));
+        assert_eq!(migrator.up(&mut conn).unwrap(), vec![1, 2, 3]);
+        assert_eq!(count(&conn, "todo").unwrap(), 1);
+        let name: String = conn
+            .query_row("SELECT name FROM tag WHERE id = 2", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(name, "x;y");
+    }
+
+    #[test]
+    fn migrate_dollar_quote_and_escape() {
+        let mut conn = Recorder::default();
+        let mut migrator = Migrator::new(Dialect::Postgres);
+        migrator.migration(&Migration::sql(
+            1,
+            "touch_function",
+            "CREATE FUNCTION touch() RETURNS trigger AS $body$ BEGIN NEW.name := 'a;b'; RETURN NEW; END; $body$ LANGUAGE plpgsql;
+            INSERT INTO note (body) VALUES (E'it\\'s;'), ($1);",
+        ));
+        assert_eq!(
+            migrator.dry_run(&mut conn).unwrap(),
+            "-- 1 touch_function
+CREATE FUNCTION touch() RETURNS trigger AS $body$ BEGIN NEW.name := 'a;b'; RETURN NEW; END; $body$ LANGUAGE plpgsql;
+INSERT INTO note (body) VALUES (E'it\\'s;'), ($1);
+"
+        );
+        // only migration table lookup is run
+        assert_eq!(conn.sql.len(), 1);
+        assert!(conn.sql[0].contains("information_schema.tables"));
+    }
+
+    #[test]
+    fn migrate_mysql_trigger_block() {
+        let mut conn = Recorder::default();
+        let mut migrator = Migrator::new(Dialect::MySql);
+        migrator.migration(&Migration::sql(
+            1,
+            "todo_trigger",
+            "CREATE TRIGGER todo_point BEFORE INSERT ON todo FOR EACH ROW BEGIN
+                IF NEW.point < 0 THEN
+                    SET NEW.point = 0;
+                ELSEIF NEW.point > 100 THEN
+                    SET NEW.point = 100;
+                END IF;
+                CASE NEW.status WHEN 'x' THEN SET NEW.status = 'y'; ELSE SET NEW.note = 'a;b'; END CASE;
+                WHILE NEW.point > 10 DO SET NEW.point = NEW.point - 10; END WHILE;
+            END;
+            INSERT INTO todo (point) VALUES (1);",
+        ));
+        // trigger is kept whole, `END IF` and `END WHILE` don't close it
+        assert_eq!(
+            migrator.dry_run(&mut conn).unwrap(),
+            "-- 1 todo_trigger
+CREATE TRIGGER todo_point BEFORE INSERT ON todo FOR EACH ROW BEGIN
+                IF NEW.point < 0 THEN
+                    SET NEW.point = 0;
+                ELSEIF NEW.point > 100 THEN
+                    SET NEW.point = 100;
+                END IF;
+                CASE NEW.status WHEN 'x' THEN SET NEW.status = 'y'; ELSE SET NEW.note = 'a;b'; END CASE;
+                WHILE NEW.point > 10 DO SET NEW.point = NEW.point - 10; END WHILE;
+            END;
+INSERT INTO todo (point) VALUES (1);
+"
+        );
+    }
+
+    #[test]
+    fn migrate_no_transaction() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let mut migrator = migrator();
+        migrator.migration(
+            Migration::sql(
+                3,
+                "broken",
+                "CREATE TABLE broken (id INTEGER); INSERT INTO missing (id) VALUES (1);",
+            )
+            .no_transaction(),
+        );
+        assert!(migrator.up(&mut conn).is_err());
+        // statements before failure stay applied, migration is not recorded
+        assert_eq!(migrator.applied(&mut conn).unwrap().len(), 2);
+        assert_eq!(count(&conn, "broken").unwrap(), 0);
+
+        let migration = Migration::from_file(
+            "0004_todo_index.sql",
+            "-- qorm:no-transaction\nCREATE INDEX CONCURRENTLY todo_name_idx ON todo (name);",
+        )
+        .unwrap();
+        let mut migrator = Migrator::new(Dialect::Sqlite);
+        migrator.migration(&migration);
+        assert!(migrator.up(&mut conn).is_err());
+        assert!(conn.is_autocommit());
+    }
+
+    #[test]
+    fn migrate_edited_migration() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrator().up(&mut conn).unwrap();
+
+        let mut migrator = Migrator::new(Dialect::Sqlite);
+        migrator
+            .migration(&Migration::new(1, "create_todo", create_todo))
+            .migration(&Migration::sql(
+                2,
+                "create_tag",
+                "CREATE TABLE tag (id INTEGER PRIMARY KEY);",
+            ));
+        assert_eq!(
+            migrator.up(&mut conn),
+            Err(Error::Checksum {
+                version: 2,
+                name: "create_tag".to_string(),
+            })
+        );
+
+        let mut migrator = Migrator::new(Dialect::Sqlite);
+        migrator.migration(&Migration::new(1, "create_todo", create_todo));
+        assert_eq!(
+            migrator.pending(&mut conn).err(),
+            Some(Error::Invalid(
+                "applied migration 2 create_tag is not registered".to_string()
+            ))
+        );
+        assert_eq!(
+            migrator.down(&mut conn, 1),
+            Err(Error::Invalid(
+                "applied migration 2 create_tag is not registered".to_string()
+            ))
+        );
+
+        // down requires down migration on every target
+        let mut migrator = Migrator::new(Dialect::Sqlite);
+        migrator
+            .migration(&Migration::new(1, "create_todo", create_todo))
+            .migration(&Migration::sql(2, "create_tag", ""));
+        assert_eq!(
+            migrator.down(&mut conn, 1),
+            Err(Error::Invalid(
+                "migration 2 create_tag has no down migration".to_string()
+            ))
+        );
+        assert_eq!(migrator.applied(&mut conn).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn migration_from_file() {
+        let migration = Migration::from_file("migrations/20240101_create_user.sql", "").unwrap();
+        assert_eq!(migration.version, 20240101);
+        assert_eq!(migration.name, "create_user");
+        assert_eq!(
+            Migration::from_file("create_user.sql", "").err(),
+            Some(Error::Invalid(
+                "migration file create_user.sql must be named <version>_<name>.sql".to_string()
+            ))
+        );
+
+        let mut migrator = Migrator::new(Dialect::Postgres);
+        migrator.migration(&Migration::new(1, "create_todo", || {
+            let mut index = CreateIndex::new("todo_name_idx", "todo");
+            index.using(IndexMethod::Gin).column("name");
+            vec![Box::new(index)]
+        }));
+        assert_eq!(
+            migrator.migrations()[0]
+                .checksum(Dialect::Postgres)
+                .unwrap()
+                .len(),
+            16
+        );
+    }
+}
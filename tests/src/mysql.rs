@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        expr::Expr,
+        mysql::{
+            mysql_common::{params::Params, value::Value},
+            query,
+        },
+        Bind, Insert, Select,
+    };
+
+    #[test]
+    fn mysql_statement() {
+        let mut builder = Select::new("todo", None, None);
+        builder
+            .select_as(Expr::count_all(), "total")
+            .wheres("todo.name", "=", Bind::from("Foo"))
+            .wheres("todo.is_done", "=", Bind::Bool(true));
+        let statement = query(&mut builder).unwrap();
+        assert_eq!(
+            statement.sql(),
+            "SELECT COUNT(*) AS `total` FROM todo todo WHERE todo.name = ? AND todo.is_done = ?"
+        );
+        match statement.params() {
+            Params::Positional(values) => {
+                assert_eq!(values, vec![Value::Bytes(b"Foo".to_vec()), Value::Int(1)])
+            }
+            _ => panic!("expected positional params"),
+        }
+    }
+
+    #[test]
+    fn mysql_value() {
+        let mut builder = Insert::new("todo", None);
+        builder.values(vec![
+            ("note", Bind::Null),
+            ("point", Bind::Float(1.5)),
+            ("total", Bind::BigInt(10)),
+            ("payload", Bind::Bytes(vec![0, 1])),
+        ]);
+        let statement = query(&mut builder).unwrap();
+        assert_eq!(
+            statement.sql(),
+            "INSERT INTO todo (note,point,total,payload) VALUES (?,?,?,?)"
+        );
+        match statement.params() {
+            Params::Positional(values) => assert_eq!(
+                values,
+                vec![
+                    Value::NULL,
+                    Value::Double(1.5),
+                    Value::Int(10),
+                    Value::Bytes(vec![0, 1])
+                ]
+            ),
+            _ => panic!("expected positional params"),
+        }
+
+        let mut builder = Select::new("todo", None, None);
+        assert!(matches!(
+            query(&mut builder).unwrap().params(),
+            Params::Empty
+        ));
+    }
+}
@@ -0,0 +1,338 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        expr::Expr,
+        parse::{parse, parse_select},
+        query::Query,
+        sqlite::rusqlite::Connection,
+        Bind, Dialect, Error, Executor, FromRow,
+    };
+
+    fn render(sql: &str, from: Dialect, to: Dialect) -> (String, Vec<Bind>) {
+        let mut statement = parse(sql, from).unwrap();
+        statement.dialect(to);
+        statement.to_sql_with_bind()
+    }
+
+    fn error(sql: &str) -> String {
+        match parse(sql, Dialect::Postgres) {
+            Err(Error::Invalid(message)) => message,
+            Err(err) => panic!("unexpected error {}", err),
+            Ok(_) => panic!("{} should not parse", sql),
+        }
+    }
+
+    #[test]
+    fn parse_select_query() {
+        assert_eq!(
+            render(
+                "select distinct u.id, u.name as title, count(DISTINCT t.id) total, coalesce(u.nick, 'anon') nick
+                from `user` as u
+                left outer join todo t on t.user_id = u.id and t.is_done = 0
+                join role r on r.id = u.role_id
+                where u.is_active = true and (u.point >= -10 or u.name like 'a%') and u.deleted_at is null
+                order by u.id desc, t.id
+                limit 20, 10
+                for update of u skip locked;",
+                Dialect::MySql,
+                Dialect::Postgres,
+            ),
+            (
+                r#"SELECT DISTINCT u.id, u.name AS "title", count(DISTINCT t.id) AS "total", coalesce(u.nick, $1) AS "nick" FROM user u LEFT OUTER JOIN todo t ON t.user_id = u.id and t.is_done = 0 JOIN role r ON r.id = u.role_id WHERE u.is_active = $2 AND u.deleted_at IS NULL AND ( u.point >= $3 OR u.name LIKE $4) ORDER BY u.id desc, t.id LIMIT 10 OFFSET 20 FOR UPDATE OF u SKIP LOCKED"#
+                    .to_string(),
+                vec![
+                    Bind::String("anon".to_string()),
+                    Bind::Bool(true),
+                    Bind::Int(-10),
+                    Bind::String("a%".to_string()),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn parse_select_ast() {
+        let builder = parse_select(
+            r#"SELECT "t"."id", SUM(t.point) AS "total", 'x' AS label FROM todo t GROUP BY t.id"#,
+            Dialect::Postgres,
+        )
+        .unwrap();
+        let ast = builder.ast();
+        assert_eq!(ast.dialect, Some(Dialect::Postgres));
+        let select = ast.select.clone().unwrap();
+        assert_eq!(select[0].expr, Expr::Raw("t.id".to_string()));
+        assert_eq!(
+            select[1].expr,
+            Expr::Function {
+                name: "SUM".to_string(),
+                distinct: false,
+                args: vec![Expr::Raw("t.point".to_string())],
+            }
+        );
+        assert_eq!(select[1].alias, Some("total".to_string()));
+        assert_eq!(select[2].expr, Expr::Value(Bind::String("x".to_string())));
+        assert_eq!(ast.group_by, Some(vec!["t.id".to_string()]));
+
+        let builder = parse_select("SELECT * FROM todo", Dialect::Sqlite).unwrap();
+        assert!(builder.ast().select.is_none());
+    }
+
+    #[test]
+    fn parse_select_literals() {
+        assert_eq!(
+            render(
+                r#"SELECT * FROM t WHERE a = 'it''s' AND b = "x" AND c = 3000000000 AND d = 1.5 AND e <> FALSE AND f IS NOT NULL -- trailing
+                /* block */"#,
+                Dialect::MySql,
+                Dialect::Sqlite,
+            ),
+            (
+                "SELECT * FROM t t WHERE a = ? AND b = ? AND c = ? AND d = ? AND e <> ? AND f IS NOT NULL"
+                    .to_string(),
+                vec![
+                    Bind::String("it's".to_string()),
+                    Bind::String("x".to_string()),
+                    Bind::BigInt(3000000000),
+                    Bind::Float(1.5),
+                    Bind::Bool(false),
+                ]
+            )
+        );
+        // double quote is identifier outside mysql
+        assert_eq!(
+            render(
+                r#"SELECT * FROM t WHERE "b" = 'x'"#,
+                Dialect::Postgres,
+                Dialect::Postgres
+            ),
+            (
+                "SELECT * FROM t t WHERE b = $1".to_string(),
+                vec![Bind::String("x".to_string())]
+            )
+        );
+    }
+
+    #[test]
+    fn parse_or_group() {
+        assert_eq!(
+            render(
+                "SELECT * FROM t WHERE a = 1 OR (b = 2) OR c NOT LIKE 'x'",
+                Dialect::Sqlite,
+                Dialect::Sqlite
+            )
+            .0,
+            "SELECT * FROM t t WHERE ( a = ? OR b = ? OR c NOT LIKE ?)"
+        );
+        assert_eq!(
+            render(
+                "SELECT * FROM t WHERE ((a = 1 AND b = 2)) AND (c = 3 OR d = 4) AND (e = 5 OR f = 6)",
+                Dialect::Sqlite,
+                Dialect::Sqlite
+            )
+            .0,
+            "SELECT * FROM t t WHERE a = ? AND b = ? AND ( c = ? OR d = ?) AND ( e = ? OR f = ?)"
+        );
+    }
+
+    #[test]
+    fn parse_insert_update_delete() {
+        assert_eq!(
+            render(
+                "INSERT INTO todo (name, point, note) VALUES ('a', -1, NULL)",
+                Dialect::Sqlite,
+                Dialect::Postgres
+            ),
+            (
+                "INSERT INTO todo (name,point,note) VALUES ($1,$2,$3)".to_string(),
+                vec![Bind::String("a".to_string()), Bind::Int(-1), Bind::Null]
+            )
+        );
+        assert_eq!(
+            render(
+                "UPDATE todo SET name = 'b', is_done = TRUE WHERE id = 1 AND (point > 2 OR point < 0)",
+                Dialect::Sqlite,
+                Dialect::Postgres
+            ),
+            (
+                "UPDATE todo SET name = $1,is_done = $2 WHERE id = $3 AND ( point > $4 OR point < $5)"
+                    .to_string(),
+                vec![
+                    Bind::String("b".to_string()),
+                    Bind::Bool(true),
+                    Bind::Int(1),
+                    Bind::Int(2),
+                    Bind::Int(0)
+                ]
+            )
+        );
+        assert_eq!(
+            render(
+                "DELETE FROM todo WHERE id = 1",
+                Dialect::Sqlite,
+                Dialect::MySql
+            ),
+            (
+                "DELETE FROM todo WHERE id = ?".to_string(),
+                vec![Bind::Int(1)]
+            )
+        );
+    }
+
+    #[test]
+    fn parse_unsupported() {
+        assert_eq!(error(""), "empty sql");
+        assert_eq!(
+            error("WITH a AS (SELECT 1) SELECT * FROM a"),
+            "unsupported statement starting with WITH"
+        );
+        assert_eq!(
+            error("SELECT * FROM t WHERE id = ?"),
+            "placeholder ? is not supported, write values as literals"
+        );
+        assert_eq!(
+            error("SELECT * FROM t WHERE id = $1"),
+            "placeholder $1 is not supported, write values as literals"
+        );
+        assert_eq!(
+            error("SELECT * FROM t WHERE id IN (1, 2)"),
+            "unsupported condition id IN (1, 2), expected expression compared with literal"
+        );
+        assert_eq!(
+            error("SELECT * FROM t WHERE t.a = t.b"),
+            "unsupported condition t.a = t.b, expected expression compared with literal"
+        );
+        assert_eq!(
+            error("SELECT * FROM t WHERE a = 1 OR (b = 1 AND c = 1)"),
+            "unsupported condition b = 1 AND c = 1, expected expression compared with literal"
+        );
+        assert_eq!(
+            error("SELECT * FROM t WHERE id = (SELECT 1)"),
+            "subquery is not supported"
+        );
+        assert_eq!(
+            error("SELECT * FROM (SELECT 1) x"),
+            "subquery is not supported"
+        );
+        assert_eq!(
+            error("SELECT * FROM a, b"),
+            "multiple FROM tables are not supported, use JOIN"
+        );
+        assert_eq!(
+            error("SELECT a FROM t GROUP BY a HAVING COUNT(*) > 1"),
+            "HAVING is not supported"
+        );
+        assert_eq!(
+            error("SELECT a FROM t UNION SELECT b FROM u"),
+            "unexpected UNION"
+        );
+        assert_eq!(
+            error("SELECT * FROM t CROSS JOIN u"),
+            "join u requires ON condition"
+        );
+        assert_eq!(
+            error("SELECT * FROM t WHERE name = 'a"),
+            "unterminated quote '"
+        );
+        assert_eq!(
+            error("SELECT * FROM t JOIN u ON u.id = t.id AND u.name = 'a'"),
+            "string literal 'a' is only supported as condition, insert, update or select function value"
+        );
+        assert_eq!(
+            error("SELECT a FROM t ORDER BY CASE WHEN a = 1 THEN 'x' END"),
+            "string literal 'x' is only supported as condition, insert, update or select function value"
+        );
+        assert!(matches!(
+            parse(
+                r"SELECT CONCAT(name, 'a\\b') || 'c\\d' FROM t",
+                Dialect::MySql
+            ),
+            Err(Error::Invalid(_))
+        ));
+        assert_eq!(error("SELECT *"), "expected FROM, found end of sql");
+        assert_eq!(
+            error("INSERT INTO t (a, b) VALUES (1)"),
+            "INSERT INTO t has 2 columns but 1 values"
+        );
+        assert_eq!(
+            error("INSERT INTO t (a) VALUES (1), (2)"),
+            "INSERT with multiple rows is not supported"
+        );
+        assert_eq!(
+            error("INSERT INTO t (a) VALUES (now())"),
+            "unsupported value now(), expected literal"
+        );
+        assert_eq!(
+            error("UPDATE t SET a = a + 1"),
+            "unsupported value a + 1, expected literal"
+        );
+        assert_eq!(error("SELECT * FROM t; SELECT 1"), "unexpected SELECT");
+        assert!(parse_select("DELETE FROM t", Dialect::Sqlite).is_err());
+    }
+
+    #[test]
+    fn parse_quoted_identifier() {
+        let sql = "SELECT `order id`, t.`Name` FROM `my table` t WHERE t.`Name` = 'a'";
+        let mut builder = parse_select(sql, Dialect::MySql).unwrap();
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT `order id`, t.`Name` FROM `my table` t WHERE t.`Name` = ?"
+        );
+        builder.dialect(Dialect::Postgres);
+        assert_eq!(
+            builder.to_sql(),
+            r#"SELECT "order id", t."Name" FROM "my table" t WHERE t."Name" = $1"#
+        );
+
+        let sql = r#"SELECT "UserId", "order", "todo"."id" FROM "todo" WHERE "UserId" = 1"#;
+        assert_eq!(
+            render(sql, Dialect::Postgres, Dialect::Postgres).0,
+            r#"SELECT "UserId", "order", todo.id FROM todo todo WHERE "UserId" = $1"#
+        );
+        assert_eq!(
+            render(sql, Dialect::Postgres, Dialect::MySql).0,
+            "SELECT `UserId`, `order`, todo.id FROM todo todo WHERE `UserId` = ?"
+        );
+        assert_eq!(
+            render(
+                r#"UPDATE "Todo" SET "IsDone" = TRUE WHERE "Id" = 1"#,
+                Dialect::Postgres,
+                Dialect::Postgres
+            )
+            .0,
+            r#"UPDATE "Todo" SET "IsDone" = $1 WHERE "Id" = $2"#
+        );
+    }
+
+    #[derive(Debug, FromRow, PartialEq)]
+    struct Todo {
+        id: i64,
+        name: String,
+    }
+
+    #[test]
+    fn parse_execute() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE todo (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+            .unwrap();
+        for sql in [
+            "INSERT INTO todo (id, name) VALUES (1, 'a')",
+            "INSERT INTO todo (id, name) VALUES (2, 'b')",
+            "UPDATE todo SET name = 'c' WHERE id = 2",
+            "DELETE FROM todo WHERE id = 1",
+        ] {
+            let mut statement = parse(sql, Dialect::Postgres).unwrap();
+            let (sql, binds) = statement.build().unwrap();
+            Executor::execute(&mut conn, &mut (sql, binds)).unwrap();
+        }
+        let mut builder =
+            parse_select("SELECT id, name FROM todo ORDER BY id", Dialect::Postgres).unwrap();
+        let rows: Vec<Todo> = conn.fetch_all(&mut builder).unwrap();
+        assert_eq!(
+            rows,
+            vec![Todo {
+                id: 2,
+                name: "c".to_string()
+            }]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use qorm::{
+        postgres::{
+            postgres_types::{IsNull, ToSql, Type, WrongType},
+            query,
+        },
+        where_item::Or,
+        Bind, Select,
+    };
+
+    #[test]
+    fn postgres_statement() {
+        let mut builder = Select::new("todo", None, None);
+        builder
+            .select("todo.id")
+            .wheres("todo.name", "=", Bind::from("Foo"))
+            .where_or(vec![
+                Or {
+                    column: "todo.id",
+                    operator: "=",
+                    value: Bind::Int(1),
+                },
+                Or {
+                    column: "todo.is_done",
+                    operator: "=",
+                    value: Bind::Bool(true),
+                },
+            ]);
+        let statement = query(&mut builder).unwrap();
+        assert_eq!(
+            statement.sql(),
+            "SELECT todo.id FROM todo todo WHERE todo.name = $1 AND ( todo.id = $2 OR todo.is_done = $3)"
+        );
+        assert_eq!(
+            statement.binds(),
+            &[Bind::from("Foo"), Bind::Int(1), Bind::Bool(true)]
+        );
+        assert_eq!(statement.params().len(), 3);
+        // builder keeps its own dialect
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT todo.id FROM todo todo WHERE todo.name = ? AND ( todo.id = ? OR todo.is_done = ?)"
+        );
+    }
+
+    #[test]
+    fn postgres_to_sql() {
+        let mut out = BytesMut::new();
+        let result = Bind::Int(7).to_sql(&Type::INT8, &mut out).unwrap();
+        assert!(matches!(result, IsNull::No));
+        assert_eq!(out.as_ref(), 7i64.to_be_bytes());
+
+        let mut out = BytesMut::new();
+        Bind::BigInt(7).to_sql(&Type::INT2, &mut out).unwrap();
+        assert_eq!(out.as_ref(), 7i16.to_be_bytes());
+
+        let mut out = BytesMut::new();
+        assert!(Bind::BigInt(i64::MAX)
+            .to_sql(&Type::INT4, &mut out)
+            .is_err());
+
+        let mut out = BytesMut::new();
+        Bind::from("Foo").to_sql(&Type::TEXT, &mut out).unwrap();
+        assert_eq!(out.as_ref(), b"Foo");
+
+        let mut out = BytesMut::new();
+        let result = Bind::Null.to_sql(&Type::TEXT, &mut out).unwrap();
+        assert!(matches!(result, IsNull::Yes));
+
+        let mut out = BytesMut::new();
+        Bind::Int(3).to_sql(&Type::FLOAT8, &mut out).unwrap();
+        assert_eq!(out.as_ref(), 3f64.to_be_bytes());
+    }
+
+    #[test]
+    fn postgres_to_sql_mismatch() {
+        let cases = [
+            (Bind::from("1"), Type::INT4),
+            (Bind::Int(1), Type::TEXT),
+            (Bind::BigInt(1), Type::NUMERIC),
+            (Bind::Float(1.5), Type::NUMERIC),
+            (Bind::Float(1.5), Type::INT8),
+            (Bind::Bool(true), Type::INT4),
+            (Bind::Bytes(vec![1]), Type::TEXT),
+            (Bind::from("{}"), Type::JSONB),
+        ];
+        for (bind, ty) in cases {
+            let mut out = BytesMut::new();
+            match bind.to_sql_checked(&ty, &mut out) {
+                Err(err) => assert!(err.is::<WrongType>(), "{:?} on {}", bind, ty),
+                Ok(_) => panic!("{:?} on {} is encoded", bind, ty),
+            }
+            assert!(out.is_empty());
+        }
+
+        let mut out = BytesMut::new();
+        assert!(Bind::BigInt(1 << 60)
+            .to_sql_checked(&Type::FLOAT8, &mut out)
+            .is_err());
+
+        let mut out = BytesMut::new();
+        let result = Bind::Null.to_sql_checked(&Type::JSONB, &mut out).unwrap();
+        assert!(matches!(result, IsNull::Yes));
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        row::{FromRow, Row},
+        sqlite::rusqlite::Connection,
+        Bind, Error, FromRow, Select,
+    };
+
+    #[derive(FromRow, Debug, PartialEq)]
+    struct User {
+        id: i64,
+        #[qorm(column = "username")]
+        name: String,
+        nickname: Option<String>,
+        is_active: bool,
+        #[qorm(skip)]
+        cache: Option<String>,
+    }
+
+    #[derive(FromRow, Debug, PartialEq)]
+    struct UserCount {
+        #[qorm(index = 0)]
+        role_id: i32,
+        #[qorm(index = 1)]
+        total: i64,
+    }
+
+    #[test]
+    fn from_row_columns() {
+        assert_eq!(User::COLUMNS, &["id", "username", "nickname", "is_active"]);
+        let mut builder = Select::new("user", Some("u"), None);
+        builder.select_row::<User>();
+        assert_eq!(
+            builder.to_sql(),
+            r#"SELECT u.id, u.username, u.nickname, u.is_active FROM user u"#
+        );
+    }
+
+    #[test]
+    fn from_row_vec() {
+        let row = vec![
+            ("is_active", Bind::Bool(true)),
+            ("username", Bind::String("foo".to_string())),
+            ("id", Bind::Int(1)),
+            ("nickname", Bind::Null),
+        ];
+        assert_eq!(
+            User::from_row(&row).unwrap(),
+            User {
+                id: 1,
+                name: "foo".to_string(),
+                nickname: None,
+                is_active: true,
+                cache: None,
+            }
+        );
+
+        let row = vec![("id", Bind::String("1".to_string()))];
+        assert!(matches!(User::from_row(&row), Err(Error::Decode(_))));
+        assert!(matches!(row.get::<i32>("missing"), Err(Error::Decode(_))));
+    }
+
+    #[test]
+    fn from_row_sqlite() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE user (id INTEGER PRIMARY KEY, username TEXT NOT NULL, nickname TEXT, is_active BOOLEAN NOT NULL, role_id INTEGER NOT NULL);
+            INSERT INTO user (username, nickname, is_active, role_id) VALUES ('foo', NULL, 1, 1), ('bar', 'b', 0, 1), ('baz', NULL, 1, 2);",
+        )
+        .unwrap();
+
+        let mut builder = Select::new("user", Some("u"), None);
+        builder.select_row::<User>();
+        builder.wheres("u.nickname", "IS NOT", Bind::Null);
+        let sql = builder.to_sql();
+        let mut stmt = conn.prepare(&sql).unwrap();
+        let users = stmt
+            .query_map([], |row| Ok(User::from_row(row).unwrap()))
+            .unwrap()
+            .collect::<Result<Vec<User>, _>>()
+            .unwrap();
+        assert_eq!(
+            users,
+            vec![User {
+                id: 2,
+                name: "bar".to_string(),
+                nickname: Some("b".to_string()),
+                is_active: false,
+                cache: None,
+            }]
+        );
+
+        let mut builder = Select::new("user", None, None);
+        builder.select("user.role_id").select("COUNT(*)");
+        builder.group_by(vec!["user.role_id"]);
+        let sql = builder.to_sql();
+        let mut stmt = conn.prepare(&sql).unwrap();
+        let counts = stmt
+            .query_map([], |row| Ok(UserCount::from_row(row).unwrap()))
+            .unwrap()
+            .collect::<Result<Vec<UserCount>, _>>()
+            .unwrap();
+        assert_eq!(
+            counts,
+            vec![
+                UserCount {
+                    role_id: 1,
+                    total: 2
+                },
+                UserCount {
+                    role_id: 2,
+                    total: 1
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        create_table_item::{ColumnDef, ColumnType},
+        schema::SchemaDiff,
+        schema_item::{ColumnInfo, SchemaChange, TableDef, TableInfo},
+        sqlite::rusqlite::Connection,
+        table::TableSchema,
+        Dialect, Error, Executor, Table,
+    };
+
+    #[allow(dead_code)]
+    #[derive(Table)]
+    #[qorm(schema)]
+    struct Todo {
+        #[qorm(primary_key, generated)]
+        id: i64,
+        #[qorm(indexed)]
+        name: String,
+        #[qorm(unique, column_type = "VARCHAR(64)")]
+        code: String,
+        point: Option<i32>,
+        #[qorm(default = "FALSE")]
+        is_done: bool,
+        #[qorm(skip)]
+        cache: Option<String>,
+    }
+
+    fn column(name: &str, data_type: &str, nullable: bool) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable,
+        }
+    }
+
+    fn to_sql(changes: Vec<SchemaChange>) -> Vec<String> {
+        changes.iter().map(|f| f.to_sql()).collect()
+    }
+
+    #[test]
+    fn derive_table_column_defs() {
+        let columns = Todo::column_defs();
+        assert_eq!(columns.len(), 5);
+        assert_eq!(columns[3].name(), "point");
+        assert_eq!(columns[3].column_type(), &ColumnType::Integer);
+        assert_eq!(
+            Todo::indexes()
+                .iter()
+                .map(|f| f.to_sql())
+                .collect::<Vec<String>>(),
+            vec!["CREATE INDEX todo_name_idx ON todo (name)"]
+        );
+        assert_eq!(
+            TableDef::from_table::<Todo>()
+                .to_create_table()
+                .dialect(Dialect::Postgres)
+                .to_sql(),
+            r#"CREATE TABLE "todo" ("id" BIGSERIAL PRIMARY KEY, "name" TEXT NOT NULL, "code" VARCHAR(64) NOT NULL UNIQUE, "point" INTEGER, "is_done" BOOLEAN NOT NULL DEFAULT FALSE)"#
+        );
+    }
+
+    #[test]
+    fn schema_diff_create() {
+        let mut diff = SchemaDiff::new(Dialect::MySql);
+        diff.table::<Todo>();
+        assert_eq!(
+            to_sql(diff.diff(&[]).unwrap()),
+            vec![
+                "CREATE TABLE `todo` (`id` BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY, `name` TEXT NOT NULL, `code` VARCHAR(64) NOT NULL UNIQUE, `point` INT, `is_done` BOOLEAN NOT NULL DEFAULT FALSE)",
+                "CREATE INDEX `todo_name_idx` ON `todo` (`name`)",
+            ]
+        );
+    }
+
+    #[test]
+    fn schema_diff_mysql() {
+        let current = vec![TableInfo {
+            name: "todo".to_string(),
+            columns: vec![
+                column("id", "bigint", false),
+                column("name", "text", false),
+                column("code", "varchar", false),
+                column("point", "int", false),
+                column("is_done", "tinyint", false),
+                column("note", "text", true),
+            ],
+            indexes: vec!["PRIMARY".to_string(), "todo_name_idx".to_string()],
+        }];
+        let mut diff = SchemaDiff::new(Dialect::MySql);
+        diff.table::<Todo>();
+        assert_eq!(
+            to_sql(diff.diff(&current).unwrap()),
+            vec!["ALTER TABLE `todo` MODIFY COLUMN `point` INT"]
+        );
+        assert_eq!(
+            diff.drops(&current),
+            vec![("todo".to_string(), "note".to_string())]
+        );
+        diff.allow_drop(true);
+        assert_eq!(
+            to_sql(diff.diff(&current).unwrap()),
+            vec!["ALTER TABLE `todo` MODIFY COLUMN `point` INT, DROP COLUMN `note`"]
+        );
+    }
+
+    #[test]
+    fn schema_diff_sqlite() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let mut old = TableDef::new("todo");
+        old.column(ColumnDef::new("id", ColumnType::Integer).primary_key())
+            .column(ColumnDef::new("name", ColumnType::Text).not_null())
+            .column(&ColumnDef::new("note", ColumnType::Text));
+        Executor::execute(&mut conn, &mut old.to_create_table()).unwrap();
+
+        let mut table = TableDef::new("todo");
+        table
+            .column(ColumnDef::new("id", ColumnType::BigInt).primary_key())
+            .column(ColumnDef::new("name", ColumnType::Varchar(255)).not_null())
+            .column(&ColumnDef::new("point", ColumnType::Integer));
+        let mut diff = SchemaDiff::new(Dialect::Sqlite);
+        diff.table_def(&table);
+        assert_eq!(
+            diff.read(&mut conn).unwrap(),
+            vec![TableInfo {
+                name: "todo".to_string(),
+                columns: vec![
+                    column("id", "INTEGER", true),
+                    column("name", "TEXT", false),
+                    column("note", "TEXT", true),
+                ],
+                indexes: vec![],
+            }]
+        );
+        // column missing from definition is kept without allow_drop
+        assert_eq!(
+            to_sql(diff.generate(&mut conn).unwrap()),
+            vec![r#"ALTER TABLE "todo" ADD COLUMN "point" INTEGER"#]
+        );
+        diff.allow_drop(true);
+        let changes = diff.generate(&mut conn).unwrap();
+        assert_eq!(
+            to_sql(changes.clone()),
+            vec![
+                r#"ALTER TABLE "todo" ADD COLUMN "point" INTEGER"#,
+                r#"ALTER TABLE "todo" DROP COLUMN "note""#,
+            ]
+        );
+        for mut change in changes {
+            Executor::execute(&mut conn, &mut change).unwrap();
+        }
+        assert!(diff.generate(&mut conn).unwrap().is_empty());
+        assert!(diff.drops(&diff.read(&mut conn).unwrap()).is_empty());
+
+        let mut table = TableDef::new("todo");
+        table
+            .column(ColumnDef::new("id", ColumnType::Integer).primary_key())
+            .column(&ColumnDef::new("name", ColumnType::Text))
+            .column(&ColumnDef::new("point", ColumnType::Integer));
+        let mut diff = SchemaDiff::new(Dialect::Sqlite);
+        diff.table_def(&table);
+        assert_eq!(
+            diff.generate(&mut conn).err(),
+            Some(Error::Unsupported {
+                feature: "DROP NOT NULL".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+
+        let mut diff = SchemaDiff::new(Dialect::Sqlite);
+        diff.table_def(&TableDef::new("tag"));
+        assert_eq!(
+            diff.generate(&mut conn).err(),
+            Some(Error::Invalid(
+                "table tag has no column definitions".to_string()
+            ))
+        );
+    }
+}
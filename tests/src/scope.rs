@@ -0,0 +1,329 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        parse::parse, scope::Scope, sqlite::rusqlite::Connection, Bind, Delete, Dialect, Error,
+        Executor, FromRow, Select, Update,
+    };
+
+    fn tenant(tenant_id: i32) -> Scope {
+        let mut scope = Scope::new();
+        for table in ["todo", "user", "project"] {
+            scope.filter(table, "tenant_id", "=", Bind::Int(tenant_id));
+        }
+        scope
+    }
+
+    #[test]
+    fn scope_select() {
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .dialect(Dialect::Postgres)
+            .select("t.id")
+            .join(
+                Some("LEFT"),
+                "user AS u",
+                "u.id = t.created_by OR u.id = t.updated_by",
+            )
+            .join(None, "project", "project.id = t.project_id")
+            .join(None, "tag", "tag.id = t.tag_id")
+            .wheres("t.is_done", "=", Bind::Bool(false))
+            .scope(&tenant(7));
+        assert_eq!(
+            builder.to_sql_with_bind(),
+            (
+                "SELECT t.id FROM todo t LEFT JOIN user AS u ON (u.id = t.created_by OR u.id = t.updated_by) AND u.tenant_id = $1 JOIN project ON (project.id = t.project_id) AND project.tenant_id = $2 JOIN tag ON tag.id = t.tag_id WHERE t.tenant_id = $3 AND t.is_done = $4"
+                    .to_string(),
+                vec![Bind::Int(7), Bind::Int(7), Bind::Int(7), Bind::Bool(false)]
+            )
+        );
+        // scope is not part of query
+        assert_eq!(builder.get_where().len(), 1);
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT t.id FROM todo t LEFT JOIN user AS u ON (u.id = t.created_by OR u.id = t.updated_by) AND u.tenant_id = $1 JOIN project ON (project.id = t.project_id) AND project.tenant_id = $2 JOIN tag ON tag.id = t.tag_id WHERE t.tenant_id = $3 AND t.is_done = $4"
+        );
+
+        // table without filter
+        let mut builder = Select::new("tag", None, None);
+        builder.scope(&tenant(7));
+        assert_eq!(builder.to_sql(), "SELECT * FROM tag tag");
+    }
+
+    #[test]
+    fn scope_select_count_and_or() {
+        let mut scope = tenant(7);
+        scope.filter("todo", "deleted_at", "IS", Bind::Null);
+
+        let mut builder = Select::new("todo", None, None);
+        builder
+            .select("todo.created_by")
+            .where_or(vec![qorm::where_item::Or {
+                column: "todo.point",
+                operator: ">",
+                value: Bind::Int(1),
+            }])
+            .group_by(vec!["todo.created_by"])
+            .scope(&scope);
+        assert_eq!(
+            builder.to_count().to_sql_with_bind(),
+            (
+                "SELECT COUNT(*) FROM (SELECT todo.created_by FROM todo todo WHERE todo.tenant_id = ? AND todo.deleted_at IS NULL AND ( todo.point > ?) GROUP BY todo.created_by) count_query"
+                    .to_string(),
+                vec![Bind::Int(7), Bind::Int(1)]
+            )
+        );
+    }
+
+    #[test]
+    fn scope_update_delete() {
+        let mut builder = Update::new("todo", None);
+        builder
+            .dialect(Dialect::Postgres)
+            .set(vec![("is_done", Bind::Bool(true))])
+            .wheres("id", "=", Bind::Int(1))
+            .scope(&tenant(7));
+        assert_eq!(
+            builder.to_sql_with_bind(),
+            (
+                "UPDATE todo SET is_done = $1 WHERE tenant_id = $2 AND id = $3".to_string(),
+                vec![Bind::Bool(true), Bind::Int(7), Bind::Int(1)]
+            )
+        );
+
+        let mut builder = Delete::new("user", None);
+        builder.scope(&tenant(7));
+        assert_eq!(
+            builder.to_sql_with_bind(),
+            (
+                "DELETE FROM user WHERE tenant_id = ?".to_string(),
+                vec![Bind::Int(7)]
+            )
+        );
+    }
+
+    #[test]
+    fn scope_unscoped() {
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder.scope(&tenant(7)).unscoped();
+        assert_eq!(builder.to_sql(), "SELECT * FROM todo t");
+        builder.scope(&tenant(8));
+        assert_eq!(builder.to_sql(), "SELECT * FROM todo t");
+
+        let mut builder = Update::new("todo", None);
+        builder
+            .set(vec![("is_done", Bind::Bool(true))])
+            .unscoped()
+            .scope(&tenant(7));
+        assert_eq!(builder.to_sql(), "UPDATE todo SET is_done = ?");
+    }
+
+    #[test]
+    fn scope_parsed_statement() {
+        let mut statement = parse(
+            "SELECT t.id FROM todo t JOIN user u ON u.id = t.created_by WHERE t.is_done = FALSE",
+            Dialect::Sqlite,
+        )
+        .unwrap();
+        statement.scope(&tenant(7));
+        assert_eq!(
+            statement.to_sql_with_bind(),
+            (
+                "SELECT t.id FROM todo t JOIN user u ON (u.id = t.created_by) AND u.tenant_id = ? WHERE t.tenant_id = ? AND t.is_done = ?"
+                    .to_string(),
+                vec![Bind::Int(7), Bind::Int(7), Bind::Bool(false)]
+            )
+        );
+
+        let mut statement = parse("INSERT INTO todo (name) VALUES ('a')", Dialect::Sqlite).unwrap();
+        statement.scope(&tenant(7));
+        assert_eq!(
+            statement.to_sql_with_bind().0,
+            "INSERT INTO todo (name) VALUES (?)"
+        );
+    }
+
+    #[test]
+    fn scope_raw_subquery() {
+        let subquery = |builder: &mut Select| {
+            builder.scope(&tenant(7));
+            assert!(matches!(builder.validate(), Err(Error::Invalid(_))));
+            assert!(matches!(builder.try_to_sql(), Err(Error::Invalid(_))));
+            // unvalidated render matches no row
+            let (sql, binds) = builder.to_sql_with_bind();
+            assert!(sql.contains(" WHERE 1 = ? AND t.tenant_id = ?"), "{}", sql);
+            assert!(binds.windows(2).any(|f| f == [Bind::Int(0), Bind::Int(7)]));
+        };
+
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder.select("(SELECT COUNT(*) FROM tag g WHERE g.todo_id = t.id) AS tags");
+        subquery(&mut builder);
+
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder.where_or(vec![qorm::where_item::Or {
+            column: "t.project_id IN (select id from project) OR t.id",
+            operator: "=",
+            value: Bind::Int(1),
+        }]);
+        subquery(&mut builder);
+
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder.order_by(vec!["(SELECT MAX(id) FROM tag) DESC"]);
+        subquery(&mut builder);
+
+        // derived table join
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder.join(
+            None,
+            "(SELECT created_by, COUNT(*) AS total FROM todo GROUP BY created_by) c",
+            "c.created_by = t.created_by",
+        );
+        subquery(&mut builder);
+
+        let mut builder = Update::new("todo", None);
+        builder
+            .set(vec![("is_done", Bind::Bool(true))])
+            .wheres(
+                "EXISTS (SELECT 1 FROM project WHERE project.id = project_id)",
+                "=",
+                Bind::Bool(true),
+            )
+            .scope(&tenant(7));
+        assert!(matches!(builder.try_to_sql(), Err(Error::Invalid(_))));
+        assert!(builder.to_sql().contains(" WHERE 1 = ? AND tenant_id = ?"));
+
+        let mut builder = Delete::new("todo", None);
+        builder
+            .wheres(
+                "EXISTS (SELECT 1 FROM project WHERE project.id = project_id)",
+                "=",
+                Bind::Bool(true),
+            )
+            .scope(&tenant(7));
+        assert!(matches!(builder.try_to_sql(), Err(Error::Invalid(_))));
+        assert!(builder.to_sql().contains(" WHERE 1 = ? AND tenant_id = ?"));
+
+        // `select` inside string or identifier is not subquery
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .select("t.\"select\"")
+            .wheres(
+                "t.note || 'select'",
+                "<>",
+                Bind::String("select".to_string()),
+            )
+            .scope(&tenant(7));
+        assert!(builder.validate().is_ok());
+
+        // unscoped builder keeps raw subquery, filters are written by hand
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .wheres(
+                "EXISTS (SELECT 1 FROM project p WHERE p.id = t.project_id AND p.tenant_id = 7)",
+                "=",
+                Bind::Bool(true),
+            )
+            .unscoped()
+            .scope(&tenant(7));
+        assert!(builder.try_to_sql().is_ok());
+    }
+
+    #[test]
+    fn scope_table_name() {
+        let mut builder = Select::new(r#""todo""#, Some("t"), None);
+        builder
+            .join(None, "public.user u", "u.id = t.created_by")
+            .join(Some("LEFT"), "`Project` p", "p.id = t.project_id")
+            .scope(&tenant(7));
+        assert_eq!(
+            builder.to_sql(),
+            r#"SELECT * FROM "todo" t JOIN public.user u ON (u.id = t.created_by) AND u.tenant_id = ? LEFT JOIN `Project` p ON (p.id = t.project_id) AND p.tenant_id = ? WHERE t.tenant_id = ?"#
+        );
+
+        let mut builder = Delete::new("public.todo", None);
+        builder.scope(&tenant(7));
+        assert_eq!(
+            builder.to_sql(),
+            "DELETE FROM public.todo WHERE tenant_id = ?"
+        );
+
+        let mut statement = parse(
+            r#"UPDATE "public"."Todo" SET name = 'a' WHERE id = 1"#,
+            Dialect::Postgres,
+        )
+        .unwrap();
+        statement.scope(&tenant(7));
+        assert_eq!(
+            statement.to_sql_with_bind().0,
+            r#"UPDATE public."Todo" SET name = $1 WHERE tenant_id = $2 AND id = $3"#
+        );
+    }
+
+    #[test]
+    fn scope_raw_subquery_execute() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE todo (id INTEGER PRIMARY KEY, tenant_id INTEGER NOT NULL, name TEXT NOT NULL);",
+        )
+        .unwrap();
+        let mut builder = Select::new("todo", None, None);
+        builder
+            .select("todo.id")
+            .select("todo.name")
+            .wheres("EXISTS (SELECT 1 FROM todo)", "=", Bind::Bool(true))
+            .scope(&tenant(7));
+        assert!(matches!(
+            conn.fetch_all::<Todo, _>(&mut builder),
+            Err(Error::Invalid(_))
+        ));
+    }
+
+    #[derive(Debug, FromRow, PartialEq)]
+    struct Todo {
+        id: i64,
+        name: String,
+    }
+
+    #[test]
+    fn scope_execute() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE todo (id INTEGER PRIMARY KEY, tenant_id INTEGER NOT NULL, name TEXT NOT NULL);
+            INSERT INTO todo VALUES (1, 7, 'a'), (2, 8, 'b'), (3, 7, 'c');",
+        )
+        .unwrap();
+
+        let mut builder = Delete::new("todo", None);
+        builder.wheres("id", "<", Bind::Int(3)).scope(&tenant(8));
+        Executor::execute(&mut conn, &mut builder).unwrap();
+
+        let mut builder = Select::new("todo", None, None);
+        builder
+            .select("todo.id")
+            .select("todo.name")
+            .order_by(vec!["todo.id"])
+            .scope(&tenant(7));
+        let rows: Vec<Todo> = conn.fetch_all(&mut builder).unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                Todo {
+                    id: 1,
+                    name: "a".to_string()
+                },
+                Todo {
+                    id: 3,
+                    name: "c".to_string()
+                }
+            ]
+        );
+
+        let mut builder = Select::new("todo", None, None);
+        builder
+            .select("todo.id")
+            .select("todo.name")
+            .unscoped()
+            .scope(&tenant(7));
+        let rows: Vec<Todo> = conn.fetch_all(&mut builder).unwrap();
+        assert_eq!(rows.len(), 2);
+    }
+}
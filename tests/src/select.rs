@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use qorm::{select_item::SelectConfig, where_item::Or, Bind, Select};
+    use qorm::{
+        expr::Expr, select_item::SelectConfig, table::Table, where_item::Or, Bind, Dialect, Error,
+        Select,
+    };
 
     #[test]
     fn init_select() {
@@ -224,7 +227,7 @@ mod tests {
         let (sql, binds) = builder.to_sql_with_bind();
         assert_eq!(
             sql,
-            r#"SELECT user.id, user.name, user.is_done FROM user user JOIN role ON role.id = user.role_id LEFT JOIN location ON location.id = user.location_id WHERE user.username = ? AND user.id = ? AND user.is_done IS NOT NULL AND ( user.id = ? OR user.is_active = ?) AND ( user.is_active = ?) ORDER BY user.id ASC GROUP BY user.id LIMIT 5 OFFSET 10"#
+            r#"SELECT user.id, user.name, user.is_done FROM user user JOIN role ON role.id = user.role_id LEFT JOIN location ON location.id = user.location_id WHERE user.username = ? AND user.id = ? AND user.is_done IS NOT NULL AND ( user.id = ? OR user.is_active = ?) AND ( user.is_active = ?) GROUP BY user.id ORDER BY user.id ASC LIMIT 5 OFFSET 10"#
         );
         let answer = [
             Bind::String("Foo".to_string()),
@@ -238,4 +241,462 @@ mod tests {
             assert_eq!(answer[idx], binds[idx]);
         }
     }
+
+    #[test]
+    fn count_query() {
+        let mut builder = Select::new(
+            "user",
+            Some("u"),
+            Some(SelectConfig {
+                placeholder: "$%d".to_string(),
+                start: Some(1),
+            }),
+        );
+        builder.select("u.id").select("u.name");
+        builder.join(Some("LEFT"), "role r", "r.id = u.role_id");
+        builder.wheres("u.is_active", "=", Bind::Bool(true));
+        builder.where_or(vec![
+            Or {
+                column: "r.name",
+                operator: "=",
+                value: Bind::String("admin".to_string()),
+            },
+            Or {
+                column: "u.id",
+                operator: "=",
+                value: Bind::Int(1),
+            },
+        ]);
+        builder.order_by(vec!["u.id ASC"]);
+        builder.limit(5);
+        builder.offset(10);
+        let (sql, _) = builder.to_sql_with_bind();
+        assert_eq!(
+            sql,
+            r#"SELECT u.id, u.name FROM user u LEFT JOIN role r ON r.id = u.role_id WHERE u.is_active = $1 AND ( r.name = $2 OR u.id = $3) ORDER BY u.id ASC LIMIT 5 OFFSET 10"#
+        );
+        let (sql, binds) = builder.to_count().to_sql_with_bind();
+        assert_eq!(
+            sql,
+            r#"SELECT COUNT(*) FROM user u LEFT JOIN role r ON r.id = u.role_id WHERE u.is_active = $1 AND ( r.name = $2 OR u.id = $3)"#
+        );
+        let answer = [
+            Bind::Bool(true),
+            Bind::String("admin".to_string()),
+            Bind::Int(1),
+        ];
+        assert_eq!(binds.len(), answer.len());
+        for idx in 0..binds.len() {
+            assert_eq!(answer[idx], binds[idx]);
+        }
+    }
+
+    #[test]
+    fn count_query_group_by() {
+        let mut builder = Select::new("user", None, None);
+        builder.select("user.role_id");
+        builder.wheres("user.is_active", "=", Bind::Bool(true));
+        builder.group_by(vec!["user.role_id"]);
+        builder.order_by(vec!["user.role_id ASC"]);
+        builder.limit(5);
+        let (sql, binds) = builder.to_count().to_sql_with_bind();
+        assert_eq!(
+            sql,
+            r#"SELECT COUNT(*) FROM (SELECT user.role_id FROM user user WHERE user.is_active = ? GROUP BY user.role_id) count_query"#
+        );
+        assert_eq!(binds, vec![Bind::Bool(true)]);
+    }
+
+    #[test]
+    fn lock_query() {
+        let mut builder = Select::new(
+            "job",
+            Some("j"),
+            Some(SelectConfig {
+                placeholder: "$%d".to_string(),
+                start: Some(1),
+            }),
+        );
+        builder.dialect(Dialect::Postgres);
+        builder.join(None, "worker w", "w.id = j.worker_id");
+        builder.wheres("j.status", "=", Bind::String("pending".to_string()));
+        builder.order_by(vec!["j.id ASC"]);
+        builder.limit(10);
+        builder.for_no_key_update().of(vec!["j"]).skip_locked();
+        assert_eq!(
+            builder.to_sql(),
+            r#"SELECT * FROM job j JOIN worker w ON w.id = j.worker_id WHERE j.status = $1 ORDER BY j.id ASC LIMIT 10 FOR NO KEY UPDATE OF j SKIP LOCKED"#
+        );
+        assert_eq!(
+            builder.to_count().to_sql(),
+            r#"SELECT COUNT(*) FROM job j JOIN worker w ON w.id = j.worker_id WHERE j.status = $1"#
+        );
+    }
+
+    #[test]
+    fn lock_query_mysql() {
+        let mut builder = Select::new("job", None, None);
+        builder.dialect(Dialect::MySql).for_update().nowait();
+        assert_eq!(builder.to_sql(), "SELECT * FROM job job FOR UPDATE NOWAIT");
+        builder.for_no_key_update();
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "FOR NO KEY UPDATE".to_string(),
+                dialect: Dialect::MySql,
+            })
+        );
+    }
+
+    #[test]
+    fn lock_query_invalid() {
+        let mut builder = Select::new("job", None, None);
+        builder.skip_locked();
+        assert!(matches!(builder.validate(), Err(Error::Invalid(_))));
+        builder.for_share();
+        assert_eq!(builder.validate(), Ok(()));
+    }
+
+    #[test]
+    fn lock_query_sqlite() {
+        let mut builder = Select::new("job", None, None);
+        builder.dialect(Dialect::Sqlite).for_update();
+        let err = builder.try_to_sql().unwrap_err();
+        assert!(matches!(err, Error::Unsupported { .. }));
+        assert_eq!(err.to_string(), "FOR UPDATE is not supported on Sqlite");
+    }
+
+    #[test]
+    fn distinct_query() {
+        let mut builder = Select::new("user", Some("u"), None);
+        builder.distinct().select("u.role_id");
+        builder.wheres("u.is_active", "=", Bind::Bool(true));
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT DISTINCT u.role_id FROM user u WHERE u.is_active = ?"
+        );
+        assert_eq!(
+            builder.to_count().to_sql(),
+            "SELECT COUNT(*) FROM (SELECT DISTINCT u.role_id FROM user u WHERE u.is_active = ?) count_query"
+        );
+    }
+
+    #[test]
+    fn distinct_on_query() {
+        let mut builder = Select::new("user", Some("u"), None);
+        builder.dialect(Dialect::Postgres);
+        builder
+            .distinct_on(vec!["u.role_id"])
+            .distinct_on(vec!["u.location_id"])
+            .select("u.role_id")
+            .select("u.location_id")
+            .select("u.name");
+        builder.order_by(vec!["u.role_id", "u.location_id", "u.created_at DESC"]);
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT DISTINCT ON (u.role_id, u.location_id) u.role_id, u.location_id, u.name FROM user u ORDER BY u.role_id, u.location_id, u.created_at DESC"
+        );
+        builder.dialect(Dialect::Sqlite);
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Unsupported {
+                feature: "DISTINCT ON".to_string(),
+                dialect: Dialect::Sqlite,
+            })
+        );
+    }
+
+    #[test]
+    fn select_expr_query() {
+        let mut builder = Select::new(
+            "user",
+            Some("u"),
+            Some(SelectConfig {
+                placeholder: "$%d".to_string(),
+                start: Some(1),
+            }),
+        );
+        builder.dialect(Dialect::Postgres);
+        builder.select_many(vec!["u.role_id", "u.*"]);
+        builder.select_as(Expr::count_all(), "total");
+        builder.select_as(Expr::avg(Expr::column("u.age")), "average_age");
+        builder.select_as(Expr::min(Expr::column("u.created_at")), "first");
+        builder.select_as(Expr::max(Expr::column("u.created_at")), "last");
+        builder.select_as(
+            Expr::coalesce(vec![
+                Expr::max(Expr::column("u.score")),
+                Expr::value(Bind::Int(0)),
+            ]),
+            "score",
+        );
+        builder.select_as(Expr::count(Expr::column(r#"u.na"me"#)), r#"x" FROM"#);
+        builder.wheres("u.is_active", "=", Bind::Bool(true));
+        builder.group_by(vec!["u.role_id"]);
+        let (sql, binds) = builder.to_sql_with_bind();
+        assert_eq!(
+            sql,
+            r#"SELECT "u"."role_id", "u".*, COUNT(*) AS "total", AVG("u"."age") AS "average_age", MIN("u"."created_at") AS "first", MAX("u"."created_at") AS "last", COALESCE(MAX("u"."score"), $1) AS "score", COUNT("u"."na""me") AS "x"" FROM" FROM user u WHERE u.is_active = $2 GROUP BY u.role_id"#
+        );
+        assert_eq!(binds, vec![Bind::Int(0), Bind::Bool(true)]);
+    }
+
+    #[test]
+    fn select_expr_mysql_query() {
+        let mut builder = Select::new("user", None, None);
+        builder.dialect(Dialect::MySql);
+        builder.select("user.id");
+        builder.select_as(Expr::count_distinct(Expr::column("user.role_id")), "roles");
+        builder.select_as(Expr::value(Bind::Null), "nothing");
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT user.id, COUNT(DISTINCT `user`.`role_id`) AS `roles`, NULL AS `nothing` FROM user user"
+        );
+    }
+
+    #[test]
+    fn debug_query() {
+        let mut builder = Select::new("user", None, None);
+        builder.dialect(Dialect::MySql);
+        builder.select_as(Expr::value(Bind::Float(1.5)), "weight");
+        builder.wheres("user.name", "=", Bind::from(r"it's \ me"));
+        builder.where_or(vec![
+            Or {
+                column: "user.avatar",
+                operator: "=",
+                value: Bind::Bytes(vec![0, 255]),
+            },
+            Or {
+                column: "user.id",
+                operator: "=",
+                value: Bind::BigInt(10),
+            },
+        ]);
+        assert_eq!(
+            builder.to_debug_sql(),
+            r"-- debug sql with inlined binds, not for execution
+SELECT 1.5 AS `weight` FROM user user WHERE user.name = 'it''s \\ me' AND ( user.avatar = X'00ff' OR user.id = 10)"
+        );
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT ? AS `weight` FROM user user WHERE user.name = ? AND ( user.avatar = ? OR user.id = ?)"
+        );
+    }
+
+    #[test]
+    fn introspect_query() {
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .select("t.id")
+            .select_as(Expr::column("u.name"), "owner")
+            .join(Some("LEFT"), "user AS u", "u.id = t.created_by")
+            .join(None, "project", "project.id = t.project_id")
+            .wheres("t.is_done", "=", Bind::Bool(false))
+            .where_or(vec![Or {
+                column: "t.point",
+                operator: ">",
+                value: Bind::Int(10),
+            }])
+            .group_by(vec!["t.id"])
+            .order_by(vec!["t.id DESC"])
+            .limit(10)
+            .offset(20);
+        assert_eq!(builder.get_table().name, "todo");
+        assert_eq!(builder.get_select().len(), 2);
+        assert_eq!(builder.get_select()[1].alias, Some("owner".to_string()));
+        assert_eq!(builder.get_join().len(), 2);
+        assert_eq!(builder.get_where().len(), 1);
+        assert_eq!(builder.get_where_or().len(), 1);
+        assert_eq!(builder.get_order_by(), ["t.id DESC".to_string()]);
+        assert_eq!(builder.get_group_by(), ["t.id".to_string()]);
+        assert_eq!(builder.get_limit(), Some(10));
+        assert_eq!(builder.get_offset(), Some(20));
+        assert_eq!(
+            builder
+                .conditions()
+                .map(|f| f.value.clone())
+                .collect::<Vec<Bind>>(),
+            vec![Bind::Bool(false), Bind::Int(10)]
+        );
+        assert_eq!(
+            builder.tables(),
+            vec![
+                Table {
+                    name: "todo".to_string(),
+                    alias: Some("t".to_string()),
+                },
+                Table {
+                    name: "user".to_string(),
+                    alias: Some("u".to_string()),
+                },
+                Table {
+                    name: "project".to_string(),
+                    alias: None,
+                },
+            ]
+        );
+
+        let empty = Select::new("todo", None, None);
+        assert!(empty.get_select().is_empty());
+        assert!(empty.get_join().is_empty());
+        assert_eq!(empty.conditions().count(), 0);
+        assert_eq!(empty.get_limit(), None);
+    }
+
+    #[test]
+    fn rewrite_query() {
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .dialect(Dialect::Postgres)
+            .select("t.id")
+            .wheres("t.is_done", "=", Bind::Bool(false))
+            .wheres("t.user_id", "=", Bind::Int(1))
+            .where_or(vec![
+                Or {
+                    column: "t.user_id",
+                    operator: "=",
+                    value: Bind::Int(2),
+                },
+                Or {
+                    column: "t.is_public",
+                    operator: "=",
+                    value: Bind::Bool(true),
+                },
+            ])
+            .order_by(vec!["t.id DESC"])
+            .limit(10)
+            .offset(20);
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT t.id FROM todo t WHERE t.is_done = $1 AND t.user_id = $2 AND ( t.user_id = $3 OR t.is_public = $4) ORDER BY t.id DESC LIMIT 10 OFFSET 20"
+        );
+
+        // count total rows of paginated query
+        builder
+            .replace_select(vec!["COUNT(*)"])
+            .clear_order_by()
+            .remove_limit()
+            .remove_offset();
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT COUNT(*) FROM todo t WHERE t.is_done = $1 AND t.user_id = $2 AND ( t.user_id = $3 OR t.is_public = $4)"
+        );
+
+        for condition in builder.conditions_mut() {
+            if condition.column == "t.user_id" {
+                condition.column = "t.owner_id".to_string();
+            }
+        }
+        builder.retain_conditions(|f| f.column != "t.is_public");
+        assert_eq!(
+            builder.to_sql_with_bind(),
+            (
+                "SELECT COUNT(*) FROM todo t WHERE t.is_done = $1 AND t.owner_id = $2 AND ( t.owner_id = $3)"
+                    .to_string(),
+                vec![Bind::Bool(false), Bind::Int(1), Bind::Int(2)]
+            )
+        );
+
+        builder.retain_conditions(|f| f.column == "t.is_done");
+        assert!(builder.get_where_or().is_empty());
+        builder.clear_where().clear_select();
+        assert_eq!(builder.to_sql(), "SELECT * FROM todo t");
+
+        // cleared builder keeps accepting clauses
+        builder.wheres("t.id", "=", Bind::Int(3)).limit(1);
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT * FROM todo t WHERE t.id = $1 LIMIT 1"
+        );
+    }
+
+    #[test]
+    fn render_twice_restarts_placeholder() {
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .dialect(Dialect::Postgres)
+            .wheres("t.is_done", "=", Bind::Bool(false))
+            .wheres("t.user_id", "=", Bind::Int(1))
+            .limit(10);
+        let sql = "SELECT * FROM todo t WHERE t.is_done = $1 AND t.user_id = $2 LIMIT 10";
+        assert_eq!(builder.to_sql(), sql);
+        // numbering used to continue from previous render, `$3` and `$4`
+        assert_eq!(builder.to_sql(), sql);
+
+        // count query cloned from rendered builder starts from `$1`
+        assert_eq!(
+            builder.to_count().to_sql(),
+            "SELECT COUNT(*) FROM todo t WHERE t.is_done = $1 AND t.user_id = $2"
+        );
+    }
+
+    #[test]
+    fn table_name_field() {
+        let mut builder = Select::new("todo", Some("t"), None);
+        assert_eq!(builder.table_name.name, "todo");
+        assert_eq!(builder.table_name.alias, Some("t".to_string()));
+
+        builder.table_name = Table {
+            name: "task".to_string(),
+            alias: None,
+        };
+        assert_eq!(builder.to_sql(), "SELECT * FROM task task");
+        assert_eq!(builder.ast().table, builder.table_name);
+    }
+
+    #[test]
+    fn dialect_keeps_custom_config() {
+        let mut builder = Select::new(
+            "todo",
+            Some("t"),
+            Some(SelectConfig {
+                placeholder: ":p%d".to_string(),
+                start: Some(10),
+            }),
+        );
+        builder
+            .dialect(Dialect::Postgres)
+            .wheres("t.id", "=", Bind::Int(1));
+        assert_eq!(builder.to_sql(), "SELECT * FROM todo t WHERE t.id = :p10");
+
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .dialect(Dialect::Postgres)
+            .wheres("t.id", "=", Bind::Int(1));
+        assert_eq!(builder.to_sql(), "SELECT * FROM todo t WHERE t.id = $1");
+        builder.dialect(Dialect::MySql);
+        assert_eq!(builder.to_sql(), "SELECT * FROM todo t WHERE t.id = ?");
+    }
+
+    #[test]
+    fn unquoted_without_dialect() {
+        // ansi `"` would be read as string literal by mysql, identifiers stay unquoted until dialect is set
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder
+            .select_many(vec!["t.id"])
+            .select_as(Expr::count(Expr::column("t.user_id")), "total");
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT t.id, COUNT(t.user_id) AS total FROM todo t"
+        );
+        builder.dialect(Dialect::MySql);
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT `t`.`id`, COUNT(`t`.`user_id`) AS `total` FROM todo t"
+        );
+
+        // anything but plain name is still quoted, so user input can't escape identifier
+        let mut builder = Select::new("todo", None, None);
+        builder
+            .select_many(vec!["name; DROP TABLE x --", "todo.*"])
+            .select_as(Expr::column("todo.name"), r#"a" FROM user --"#);
+        assert_eq!(
+            builder.to_sql(),
+            r#"SELECT "name; DROP TABLE x --", todo.*, todo.name AS "a"" FROM user --" FROM todo todo"#
+        );
+        builder.dialect(Dialect::MySql);
+        assert_eq!(
+            builder.to_sql(),
+            r#"SELECT `name; DROP TABLE x --`, `todo`.*, `todo`.`name` AS `a" FROM user --` FROM todo todo"#
+        );
+    }
 }
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        sqlite::rusqlite::Connection, where_item::Or, Bind, Delete, Dialect, Error, Executor,
+        FromRow, Insert, Select, Update,
+    };
+
+    #[derive(FromRow, Debug, PartialEq)]
+    struct Todo {
+        id: i64,
+        name: String,
+        note: Option<String>,
+        point: f64,
+        is_done: bool,
+        payload: Vec<u8>,
+    }
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE todo (id INTEGER PRIMARY KEY, name TEXT NOT NULL, note TEXT, point REAL NOT NULL, is_done BOOLEAN NOT NULL, payload BLOB NOT NULL)",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn insert(conn: &mut Connection, name: &str, note: Option<&str>, point: f64) {
+        let mut builder = Insert::new("todo", None);
+        builder.values(vec![
+            ("name", Bind::from(name)),
+            ("note", Bind::from(note)),
+            ("point", Bind::Float(point)),
+            ("is_done", Bind::Bool(false)),
+            ("payload", Bind::Bytes(vec![0, 1, 2])),
+        ]);
+        assert_eq!(Executor::execute(conn, &mut builder).unwrap(), 1);
+    }
+
+    #[test]
+    fn sqlite_round_trip() {
+        let mut conn = connection();
+        insert(&mut conn, "foo", None, 1.5);
+        insert(&mut conn, "bar", Some("note"), 2.0);
+        insert(&mut conn, "baz", None, 3.0);
+
+        let mut builder = Update::new("todo", None);
+        builder.set(vec![("is_done", Bind::Bool(true))]);
+        builder.where_or(vec![
+            Or {
+                column: "name",
+                operator: "=",
+                value: Bind::from("foo"),
+            },
+            Or {
+                column: "name",
+                operator: "=",
+                value: Bind::from("bar"),
+            },
+        ]);
+        assert_eq!(Executor::execute(&mut conn, &mut builder).unwrap(), 2);
+        // executing renders on sqlite without switching builder dialect
+        builder.dialect(Dialect::Postgres);
+        assert_eq!(Executor::execute(&mut conn, &mut builder).unwrap(), 2);
+        assert_eq!(
+            builder.to_sql(),
+            "UPDATE todo SET is_done = $1 WHERE ( name = $2 OR name = $3)"
+        );
+
+        let mut builder = Delete::new("todo", None);
+        builder.wheres("name", "=", Bind::from("baz"));
+        assert_eq!(Executor::execute(&mut conn, &mut builder).unwrap(), 1);
+
+        let mut builder = Select::new("todo", Some("t"), None);
+        builder.select_row::<Todo>();
+        builder.wheres("t.is_done", "=", Bind::Bool(true));
+        builder.order_by(vec!["t.id ASC"]);
+        let todos: Vec<Todo> = conn.fetch_all(&mut builder).unwrap();
+        assert_eq!(
+            todos,
+            vec![
+                Todo {
+                    id: 1,
+                    name: "foo".to_string(),
+                    note: None,
+                    point: 1.5,
+                    is_done: true,
+                    payload: vec![0, 1, 2],
+                },
+                Todo {
+                    id: 2,
+                    name: "bar".to_string(),
+                    note: Some("note".to_string()),
+                    point: 2.0,
+                    is_done: true,
+                    payload: vec![0, 1, 2],
+                },
+            ]
+        );
+
+        let count: Count = conn.fetch_one(&mut builder.to_count()).unwrap();
+        assert_eq!(count.total, 2);
+    }
+
+    #[derive(FromRow)]
+    struct Count {
+        #[qorm(index = 0)]
+        total: i64,
+    }
+
+    #[test]
+    fn sqlite_fetch_optional() {
+        let mut conn = connection();
+        insert(&mut conn, "foo", None, 1.0);
+
+        let mut builder = Select::new("todo", None, None);
+        builder.select_row::<Todo>();
+        builder.wheres("todo.name", "=", Bind::from("foo"));
+        let todo: Option<Todo> = conn.fetch_optional(&mut builder).unwrap();
+        assert_eq!(todo.map(|f| f.id), Some(1));
+
+        let mut builder = Select::new("todo", None, None);
+        builder.select_row::<Todo>();
+        builder.wheres("todo.name", "=", Bind::from("missing"));
+        let todo: Option<Todo> = conn.fetch_optional(&mut builder).unwrap();
+        assert!(todo.is_none());
+        assert_eq!(
+            conn.fetch_one::<Todo, _>(&mut builder).err(),
+            Some(Error::RowNotFound)
+        );
+    }
+
+    #[test]
+    fn sqlite_error() {
+        let mut conn = connection();
+        let mut builder = Select::new("missing", None, None);
+        assert!(matches!(
+            conn.fetch_all::<Todo, _>(&mut builder),
+            Err(Error::Database(_))
+        ));
+    }
+
+    #[test]
+    fn sqlite_unsupported() {
+        let mut conn = connection();
+        let mut builder = Select::new("todo", None, None);
+        builder.for_update();
+        assert!(matches!(
+            conn.fetch_all::<Todo, _>(&mut builder),
+            Err(Error::Unsupported { .. })
+        ));
+    }
+}
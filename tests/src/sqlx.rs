@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{sqlx::query, Bind, Insert, Select, Update};
+    use sqlx::{sqlite::SqlitePoolOptions, Execute, Postgres, Row, Sqlite};
+
+    #[test]
+    fn sqlx_postgres_placeholder() {
+        let mut builder = Update::new("todo", None);
+        builder
+            .set(vec![("name", Bind::from("Foo")), ("note", Bind::Null)])
+            .wheres("todo.id", "=", Bind::Int(1));
+        let query = query::<Postgres, _>(&mut builder).unwrap();
+        assert_eq!(
+            query.sql(),
+            "UPDATE todo SET name = $1,note = $2 WHERE todo.id = $3"
+        );
+        assert_eq!(
+            builder.to_sql(),
+            "UPDATE todo SET name = ?,note = ? WHERE todo.id = ?"
+        );
+    }
+
+    #[tokio::test]
+    async fn sqlx_sqlite_round_trip() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE todo (id INTEGER PRIMARY KEY, name TEXT NOT NULL, note TEXT, point REAL NOT NULL, is_done BOOLEAN NOT NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let mut builder = Insert::new("todo", None);
+        builder.values(vec![
+            ("name", Bind::from("Foo")),
+            ("note", Bind::Null),
+            ("point", Bind::Float(1.5)),
+            ("is_done", Bind::Bool(true)),
+        ]);
+        let result = query::<Sqlite, _>(&mut builder)
+            .unwrap()
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(result.rows_affected(), 1);
+
+        let mut builder = Select::new("todo", None, None);
+        builder
+            .select("todo.name")
+            .select("todo.note")
+            .select("todo.point")
+            .wheres("todo.is_done", "=", Bind::Bool(true));
+        let row = query::<Sqlite, _>(&mut builder)
+            .unwrap()
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(row.get::<String, _>("name"), "Foo");
+        assert_eq!(row.get::<Option<String>, _>("note"), None);
+        assert_eq!(row.get::<f64, _>("point"), 1.5);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        table::{Column, TableSchema},
+        Bind, Delete, Insert, Select, Table, Update,
+    };
+
+    #[allow(dead_code)]
+    #[derive(Table)]
+    struct UserProfile {
+        id: i32,
+        #[qorm(column = "display_name")]
+        name: String,
+        r#type: String,
+        #[qorm(skip)]
+        cache: Option<String>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Table)]
+    #[qorm(table = "users")]
+    struct User {
+        id: i32,
+        username: String,
+    }
+
+    #[allow(dead_code)]
+    struct Money(i64);
+
+    #[allow(dead_code)]
+    #[derive(Table)]
+    struct Invoice {
+        id: i64,
+        total: Money,
+    }
+
+    #[test]
+    fn derive_table() {
+        assert_eq!(UserProfile::TABLE, "user_profile");
+        assert_eq!(UserProfile::ID, Column::new("user_profile", "id"));
+        assert_eq!(UserProfile::NAME.name, "display_name");
+        assert_eq!(UserProfile::TYPE.qualified(), "user_profile.type");
+        assert_eq!(
+            UserProfile::COLUMNS,
+            &[UserProfile::ID, UserProfile::NAME, UserProfile::TYPE]
+        );
+        assert_eq!(User::TABLE, "users");
+        assert_eq!(User::COLUMNS.len(), 2);
+
+        // field without `SqlType` compiles, column definitions need `#[qorm(schema)]`
+        assert_eq!(Invoice::COLUMNS, &[Invoice::ID, Invoice::TOTAL]);
+        assert!(Invoice::column_defs().is_empty());
+    }
+
+    #[test]
+    fn derive_table_builder() {
+        let mut builder = Select::from_table::<User>(Some("u"), None);
+        builder.select(&User::USERNAME.qualified_with("u"));
+        builder.join(
+            None,
+            "user_profile up",
+            format!(
+                "{} = {}",
+                UserProfile::ID.qualified_with("up"),
+                User::ID.qualified_with("u")
+            )
+            .as_str(),
+        );
+        builder.wheres(&User::ID.qualified_with("u"), "=", Bind::Int(1));
+        assert_eq!(
+            builder.to_sql(),
+            "SELECT u.username FROM users u JOIN user_profile up ON up.id = u.id WHERE u.id = ?"
+        );
+
+        let mut builder = Insert::from_table::<User>(None);
+        builder.values(vec![(&User::USERNAME, Bind::String("foo".to_string()))]);
+        assert_eq!(builder.to_sql(), "INSERT INTO users (username) VALUES (?)");
+
+        let mut builder = Update::from_table::<User>(None);
+        builder.set(vec![(&User::USERNAME, Bind::String("foo".to_string()))]);
+        builder.wheres(&User::ID, "=", Bind::Int(1));
+        assert_eq!(
+            builder.to_sql(),
+            "UPDATE users SET username = ? WHERE id = ?"
+        );
+
+        let mut builder = Delete::from_table::<User>(None);
+        builder.wheres(&User::ID, "=", Bind::Int(1));
+        assert_eq!(builder.to_sql(), "DELETE FROM users WHERE id = ?");
+    }
+}
@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        sqlite::rusqlite::Connection,
+        transaction_item::{TransactionState, TransactionStatement},
+        Bind, Error, Executor, FromRow, Insert, Select,
+    };
+    use sqlx::{sqlite::SqlitePoolOptions, Row, Sqlite};
+
+    #[derive(FromRow, Debug, PartialEq)]
+    struct Todo {
+        name: String,
+    }
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE todo (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+            .unwrap();
+        conn
+    }
+
+    fn insert<E: Executor>(executor: &mut E, name: &str) {
+        let mut builder = Insert::new("todo", None);
+        builder.values(vec![("name", Bind::from(name))]);
+        Executor::execute(executor, &mut builder).unwrap();
+    }
+
+    fn names(conn: &mut Connection) -> Vec<String> {
+        let mut builder = Select::new("todo", None, None);
+        builder.select_row::<Todo>().order_by(vec!["todo.id"]);
+        let rows: Vec<Todo> = conn.fetch_all(&mut builder).unwrap();
+        rows.into_iter().map(|f| f.name).collect()
+    }
+
+    #[test]
+    fn transaction_commit_rollback() {
+        let mut conn = connection();
+        let mut tx = conn.begin().unwrap();
+        assert_eq!(tx.savepoint(), None);
+        insert(&mut tx, "Foo");
+        tx.commit().unwrap();
+
+        let mut tx = conn.begin().unwrap();
+        insert(&mut tx, "Bar");
+        tx.rollback().unwrap();
+
+        {
+            let mut tx = conn.begin().unwrap();
+            insert(&mut tx, "Baz");
+        }
+        assert_eq!(names(&mut conn), vec!["Foo"]);
+    }
+
+    #[test]
+    fn transaction_commit_failed() {
+        let mut conn = connection();
+        conn.execute_batch(
+            "PRAGMA foreign_keys = ON; CREATE TABLE tag (id INTEGER PRIMARY KEY, todo_id INTEGER REFERENCES todo (id) DEFERRABLE INITIALLY DEFERRED)",
+        )
+        .unwrap();
+        let mut tx = conn.begin().unwrap();
+        insert(&mut tx, "Foo");
+        let mut builder = Insert::new("tag", None);
+        builder.values(vec![("todo_id", Bind::Int(10))]);
+        Executor::execute(&mut tx, &mut builder).unwrap();
+        // deferred foreign key is checked on commit, failed transaction is rolled back on drop
+        assert!(matches!(tx.commit(), Err(Error::Database(_))));
+        assert!(conn.is_autocommit());
+        assert!(names(&mut conn).is_empty());
+    }
+
+    #[test]
+    fn transaction_savepoint() {
+        let mut conn = connection();
+        let mut tx = conn.begin().unwrap();
+        insert(&mut tx, "Foo");
+
+        let mut savepoint = tx.begin().unwrap();
+        assert_eq!(savepoint.savepoint(), Some("qorm_savepoint_1".to_string()));
+        insert(&mut savepoint, "Bar");
+        let mut nested = savepoint.begin().unwrap();
+        assert_eq!(nested.savepoint(), Some("qorm_savepoint_2".to_string()));
+        insert(&mut nested, "Baz");
+        nested.commit().unwrap();
+        savepoint.rollback().unwrap();
+        // rolled back savepoint is released
+        let mut release = ("RELEASE SAVEPOINT qorm_savepoint_1".to_string(), vec![]);
+        assert!(Executor::execute(&mut tx, &mut release).is_err());
+
+        let mut savepoint = tx.begin().unwrap();
+        insert(&mut savepoint, "Qux");
+        savepoint.commit().unwrap();
+        tx.commit().unwrap();
+
+        assert_eq!(names(&mut conn), vec!["Foo", "Qux"]);
+    }
+
+    #[test]
+    fn transaction_closure() {
+        let mut conn = connection();
+        let total = conn
+            .run_transaction(|tx| {
+                insert(tx, "Foo");
+                let result: Result<(), Error> = tx.run_transaction(|savepoint| {
+                    insert(savepoint, "Bar");
+                    Err(Error::Invalid("abort".to_string()))
+                });
+                assert!(result.is_err());
+                let mut builder = Select::new("todo", None, None);
+                builder.select_row::<Todo>();
+                let rows: Vec<Todo> = tx.fetch_all(&mut builder)?;
+                Ok(rows.len())
+            })
+            .unwrap();
+        assert_eq!(total, 1);
+
+        let result: Result<(), Error> = conn.run_transaction(|tx| {
+            insert(tx, "Baz");
+            Err(Error::RowNotFound)
+        });
+        assert!(matches!(result, Err(Error::RowNotFound)));
+        assert_eq!(names(&mut conn), vec!["Foo"]);
+    }
+
+    #[test]
+    fn transaction_state_query() {
+        let mut state = TransactionState::new();
+        let statement = qorm::postgres::query(&mut state.begin()).unwrap();
+        assert_eq!(statement.sql(), "BEGIN");
+        let statement = qorm::mysql::query(&mut state.begin()).unwrap();
+        assert_eq!(statement.sql(), "SAVEPOINT qorm_savepoint_1");
+        assert_eq!(state.depth(), 2);
+        let sql = state
+            .rollback()
+            .unwrap()
+            .iter()
+            .map(|f| f.to_sql())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            sql,
+            vec![
+                "ROLLBACK TO SAVEPOINT qorm_savepoint_1",
+                "RELEASE SAVEPOINT qorm_savepoint_1"
+            ]
+        );
+        assert_eq!(
+            state.rollback().unwrap(),
+            vec![TransactionStatement::Rollback]
+        );
+        assert!(matches!(state.commit(), Err(Error::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn transaction_state_sqlx() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let mut conn = pool.acquire().await.unwrap();
+        sqlx::query("CREATE TABLE todo (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+
+        let mut state = TransactionState::new();
+        let mut foo = Insert::new("todo", None);
+        foo.values(vec![("name", Bind::from("Foo"))]);
+        let mut bar = Insert::new("todo", None);
+        bar.values(vec![("name", Bind::from("Bar"))]);
+        let mut statements = vec![
+            (
+                "BEGIN",
+                qorm::sqlx::query::<Sqlite, _>(&mut state.begin()).unwrap(),
+            ),
+            ("insert", qorm::sqlx::query::<Sqlite, _>(&mut foo).unwrap()),
+            (
+                "SAVEPOINT",
+                qorm::sqlx::query::<Sqlite, _>(&mut state.begin()).unwrap(),
+            ),
+            ("insert", qorm::sqlx::query::<Sqlite, _>(&mut bar).unwrap()),
+        ];
+        for mut statement in state.rollback().unwrap() {
+            statements.push((
+                "rollback",
+                qorm::sqlx::query::<Sqlite, _>(&mut statement).unwrap(),
+            ));
+        }
+        statements.push((
+            "COMMIT",
+            qorm::sqlx::query::<Sqlite, _>(&mut state.commit().unwrap()).unwrap(),
+        ));
+        for (name, statement) in statements {
+            statement
+                .execute(&mut *conn)
+                .await
+                .unwrap_or_else(|err| panic!("{} failed: {}", name, err));
+        }
+
+        let rows = sqlx::query("SELECT name FROM todo")
+            .fetch_all(&mut *conn)
+            .await
+            .unwrap();
+        let names = rows
+            .iter()
+            .map(|f| f.get::<String, _>("name"))
+            .collect::<Vec<String>>();
+        assert_eq!(names, vec!["Foo"]);
+    }
+}
@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use qorm::{update_item::UpdateConfig, where_item::Or, Bind, Update};
+    use qorm::{
+        update_item::{Changeset, UpdateConfig},
+        where_item::Or,
+        Bind, Changeset, Update,
+    };
 
     #[test]
     fn update_query() {
@@ -63,4 +67,36 @@ mod tests {
             assert_eq!(binds[idx], answer[idx]);
         }
     }
+
+    #[derive(Changeset)]
+    #[qorm(skip_none)]
+    struct UserChangeset {
+        #[qorm(primary_key)]
+        id: i32,
+        username: Option<String>,
+        is_active: Option<bool>,
+        #[qorm(column = "role_id")]
+        role: i32,
+    }
+
+    #[test]
+    fn update_derive_query() {
+        let user = UserChangeset {
+            id: 1,
+            username: None,
+            is_active: Some(false),
+            role: 2,
+        };
+        let mut builder = Update::new("user", None);
+        builder.set(user.changeset());
+        for (column, value) in user.primary_key() {
+            builder.wheres(column, "=", value);
+        }
+        let (sql, binds) = builder.to_sql_with_bind();
+        assert_eq!(
+            sql,
+            "UPDATE user SET is_active = ?,role_id = ? WHERE id = ?"
+        );
+        assert_eq!(binds, vec![Bind::Bool(false), Bind::Int(2), Bind::Int(1)]);
+    }
 }
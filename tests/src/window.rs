@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use qorm::{
+        window::{Window, WindowFunction},
+        window_item::{Frame, FrameBound, FrameUnit},
+        Dialect, Select,
+    };
+
+    #[test]
+    fn window_frame() {
+        let mut window = Window::new();
+        window
+            .partition_by(vec!["p.user_id"])
+            .order_by(vec!["p.paid_at ASC"])
+            .frame(Frame {
+                unit: FrameUnit::Range,
+                start: FrameBound::Preceding(3),
+                end: Some(FrameBound::Following(1)),
+            });
+        assert_eq!(
+            window.to_sql(),
+            "PARTITION BY p.user_id ORDER BY p.paid_at ASC RANGE BETWEEN 3 PRECEDING AND 1 FOLLOWING"
+        );
+        window.frame(Frame {
+            unit: FrameUnit::Rows,
+            start: FrameBound::UnboundedPreceding,
+            end: None,
+        });
+        assert_eq!(
+            window.to_sql(),
+            "PARTITION BY p.user_id ORDER BY p.paid_at ASC ROWS UNBOUNDED PRECEDING"
+        );
+    }
+
+    #[test]
+    fn window_function() {
+        assert_eq!(WindowFunction::rank().to_sql(), "RANK() OVER ()");
+        assert_eq!(
+            WindowFunction::dense_rank()
+                .over(Window::new().order_by(vec!["score DESC"]))
+                .to_sql(),
+            "DENSE_RANK() OVER (ORDER BY score DESC)"
+        );
+        assert_eq!(
+            WindowFunction::lead("p.amount", Some(1))
+                .over_window("w")
+                .to_sql(),
+            "LEAD(p.amount, 1) OVER w"
+        );
+        assert_eq!(
+            WindowFunction::ntile(4).over_window("w").to_sql(),
+            "NTILE(4) OVER w"
+        );
+    }
+
+    #[test]
+    fn select_window() {
+        let mut builder = Select::new("payment", Some("p"), None);
+        builder.select("p.id");
+        builder.select_window(
+            WindowFunction::row_number().over(
+                Window::new()
+                    .partition_by(vec!["p.user_id"])
+                    .order_by(vec!["p.amount DESC"]),
+            ),
+            "rn",
+        );
+        builder.select_window(WindowFunction::sum("p.amount").over_window("w"), "total");
+        builder.select_window(WindowFunction::avg("p.amount").over_window("w2"), "average");
+        builder.window("w", Window::new().partition_by(vec!["p.user_id"]));
+        builder.window(
+            "w2",
+            Window::new().order_by(vec!["p.paid_at"]).frame(Frame {
+                unit: FrameUnit::Rows,
+                start: FrameBound::Preceding(6),
+                end: Some(FrameBound::CurrentRow),
+            }),
+        );
+        builder.order_by(vec!["p.id ASC"]);
+        assert_eq!(
+            builder.to_sql(),
+            r#"SELECT p.id, ROW_NUMBER() OVER (PARTITION BY p.user_id ORDER BY p.amount DESC) AS rn, SUM(p.amount) OVER w AS total, AVG(p.amount) OVER w2 AS average FROM payment p WINDOW w AS (PARTITION BY p.user_id), w2 AS (ORDER BY p.paid_at ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) ORDER BY p.id ASC"#
+        );
+    }
+
+    #[test]
+    fn named_window_clause_position() {
+        let mut builder = Select::new("payment", Some("p"), None);
+        builder
+            .dialect(Dialect::Postgres)
+            .select("p.user_id")
+            .select_window(WindowFunction::rank().over_window("w"), "rank")
+            .window("w", Window::new().order_by(vec!["SUM(p.amount) DESC"]))
+            .group_by(vec!["p.user_id"])
+            .order_by(vec!["p.user_id"])
+            .limit(10);
+        assert_eq!(
+            builder.to_sql(),
+            r#"SELECT p.user_id, RANK() OVER w AS "rank" FROM payment p GROUP BY p.user_id WINDOW w AS (ORDER BY SUM(p.amount) DESC) ORDER BY p.user_id LIMIT 10"#
+        );
+    }
+}